@@ -347,15 +347,28 @@ fn apply_component_filters(
 }
 
 fn validate_config(config: &Config) -> Result<(), String> {
+    Validator::validate_namespace(&config.namespace)
+        .map_err(|e| format!("Validation error: {}", e))?;
+    Validator::validate_qualified_name(&config.base_model, "Base model")
+        .map_err(|e| format!("Validation error: {}", e))?;
+    Validator::validate_seed_count(config.seed_count, "The config")
+        .map_err(|e| format!("Validation error: {}", e))?;
     for model in &config.models {
         Validator::validate_model(model)
             .map_err(|e| format!("Validation error: {}", e))?;
+        Validator::validate_foreign_keys(model, config.auto_foreign_ids)
+            .map_err(|e| format!("Validation error: {}", e))?;
+        Validator::validate_pivot_tables(model, &config.models)
+            .map_err(|e| format!("Validation error: {}", e))?;
     }
+    Validator::validate_migration_order(&config.models)
+        .map_err(|e| format!("Validation error: {}", e))?;
     Ok(())
 }
 
-
 fn generate_all(config: &Config) -> Result<String, String> {
+    // The server outlives a single call, so each call timestamps its migrations afresh
+    let config = &config.for_migration_run();
     let mut output = String::new();
     let mut written = 0;
     let mut skipped = 0;
@@ -409,6 +422,7 @@ fn generate_all(config: &Config) -> Result<String, String> {
 }
 
 fn check_drifts_all(config: &Config) -> Result<String, String> {
+    let config = &config.for_migration_run();
     let mut output = String::new();
     let mut intact = 0;
     let mut drifted = 0;
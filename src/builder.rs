@@ -0,0 +1,253 @@
+//! Builders for assembling configs in Rust instead of schema files
+//!
+//! ```
+//! use schemly::builder::{ConfigBuilder, ModelBuilder};
+//! use schemly::types::FieldType;
+//!
+//! let config = ConfigBuilder::new()
+//!     .model(ModelBuilder::new("User")
+//!         .field("name", FieldType::String).length(100)
+//!         .field("nickname", FieldType::String).nullable()
+//!         .timestamps(true)
+//!         .build())
+//!     .build();
+//! assert_eq!(config.models[0].table, "users");
+//! ```
+
+use crate::generators::shared::NameHelper;
+use crate::types::{
+    ColumnDefault, Config, EnumValue, Field, FieldType, FillableGuarded, ModelDefinition, Relationship,
+};
+
+/// Builds a [`ModelDefinition`], leaving every unset option at its schema default
+///
+/// Field modifiers such as [`nullable`](Self::nullable) apply to the field added last.
+#[derive(Debug, Clone)]
+pub struct ModelBuilder {
+    model: ModelDefinition,
+}
+
+impl ModelBuilder {
+    /// Starts a model whose table is the snake-case plural of its name (`BlogPost` -> `blog_posts`)
+    pub fn new(name: &str) -> Self {
+        ModelBuilder {
+            model: ModelDefinition {
+                name: name.to_string(),
+                table: NameHelper::pluralize(&NameHelper::snake_case(name)),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn table(mut self, table: &str) -> Self {
+        self.model.table = table.to_string();
+        self
+    }
+
+    /// Adds a non-null column with no default
+    pub fn field(mut self, name: &str, field_type: FieldType) -> Self {
+        self.model.fields.push(Field::new(name, field_type));
+        self
+    }
+
+    pub fn nullable(self) -> Self {
+        self.with_last_field(|field| field.nullable = true)
+    }
+
+    pub fn unique(self) -> Self {
+        self.with_last_field(|field| field.unique = true)
+    }
+
+    pub fn index(self) -> Self {
+        self.with_last_field(|field| field.index = true)
+    }
+
+    pub fn unsigned(self) -> Self {
+        self.with_last_field(|field| field.unsigned = true)
+    }
+
+    /// Marks the last field as the primary key, auto-incrementing when it is an integer column
+    pub fn primary(self) -> Self {
+        self.with_last_field(|field| {
+            field.primary = true;
+            field.auto_increment = field.field_type.is_integer();
+        })
+    }
+
+    /// Stores the last field as a hash (`'hashed'` cast)
+    pub fn hashed(self) -> Self {
+        self.with_last_field(|field| field.hashed = true)
+    }
+
+    /// Encrypts the last field at rest (`'encrypted'` cast)
+    pub fn encrypted(self) -> Self {
+        self.with_last_field(|field| field.encrypted = true)
+    }
+
+    pub fn default(self, value: &str) -> Self {
+        self.with_last_field(|field| field.default = Some(ColumnDefault::Value(value.to_string())))
+    }
+
+    pub fn length(self, length: u32) -> Self {
+        self.with_last_field(|field| field.length = Some(length))
+    }
+
+    pub fn comment(self, comment: &str) -> Self {
+        self.with_last_field(|field| field.comment = Some(comment.to_string()))
+    }
+
+    /// Places the last field after another column (`->after('email')`)
+    pub fn after(self, column: &str) -> Self {
+        self.with_last_field(|field| field.after = Some(column.to_string()))
+    }
+
+    /// Allowed values of an enum field
+    pub fn values(self, values: &[&str]) -> Self {
+        self.with_last_field(|field| {
+            field.enum_values = values.iter()
+                .map(|value| EnumValue { value: value.to_string(), label: None })
+                .collect();
+        })
+    }
+
+    pub fn timestamps(mut self, enabled: bool) -> Self {
+        self.model.timestamps = Some(enabled);
+        self
+    }
+
+    pub fn soft_deletes(mut self, enabled: bool) -> Self {
+        self.model.soft_deletes = Some(enabled);
+        self
+    }
+
+    pub fn relationship(mut self, relationship: Relationship) -> Self {
+        self.model.relationships.push(relationship);
+        self
+    }
+
+    pub fn fillable(mut self, fields: &[&str]) -> Self {
+        self.model.fillable_guarded = FillableGuarded::Fillable(fields.iter().map(|f| f.to_string()).collect());
+        self
+    }
+
+    pub fn build(self) -> ModelDefinition {
+        self.model
+    }
+
+    /// # Panics
+    ///
+    /// Panics when no field has been added yet.
+    fn with_last_field(mut self, modify: impl FnOnce(&mut Field)) -> Self {
+        let field = self.model.fields.last_mut()
+            .expect("add a field with ModelBuilder::field before setting its modifiers");
+        modify(field);
+        self
+    }
+}
+
+/// Builds a [`Config`] around a set of models, starting from [`Config::default`]
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn model(mut self, model: ModelDefinition) -> Self {
+        self.config.models.push(model);
+        self
+    }
+
+    pub fn output_dir(mut self, output_dir: &str) -> Self {
+        self.config.output_dir = output_dir.to_string();
+        self
+    }
+
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.config.namespace = namespace.to_string();
+        self
+    }
+
+    /// Adjusts any other config option (`|config| config.generate_dto = true`)
+    pub fn configure(mut self, configure: impl FnOnce(&mut Config)) -> Self {
+        configure(&mut self.config);
+        self
+    }
+
+    pub fn build(self) -> Config {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_builder_matches_literal() {
+        let built = ModelBuilder::new("BlogPost")
+            .field("id", FieldType::BigInteger).primary()
+            .field("title", FieldType::String).length(120).unique()
+            .field("status", FieldType::Enum).values(&["draft", "published"]).default("draft")
+            .field("summary", FieldType::Text).nullable().comment("Teaser")
+            .timestamps(true)
+            .build();
+
+        let field = Field::new;
+        let literal = ModelDefinition {
+            name: "BlogPost".to_string(),
+            table: "blog_posts".to_string(),
+            fields: vec![
+                Field { primary: true, auto_increment: true, ..field("id", FieldType::BigInteger) },
+                Field { length: Some(120), unique: true, ..field("title", FieldType::String) },
+                Field {
+                    enum_values: ["draft", "published"].iter()
+                        .map(|value| EnumValue { value: value.to_string(), label: None })
+                        .collect(),
+                    default: Some(ColumnDefault::Value("draft".to_string())),
+                    ..field("status", FieldType::Enum)
+                },
+                Field { nullable: true, comment: Some("Teaser".to_string()), ..field("summary", FieldType::Text) },
+            ],
+            timestamps: Some(true),
+            ..Default::default()
+        };
+
+        assert_eq!(serde_json::to_value(&built).unwrap(), serde_json::to_value(&literal).unwrap());
+    }
+
+    #[test]
+    fn test_uuid_primary_key_does_not_auto_increment() {
+        let model = ModelBuilder::new("Order")
+            .field("id", FieldType::Uuid).primary()
+            .field("total", FieldType::Integer)
+            .build();
+
+        assert!(model.fields[0].primary);
+        assert!(!model.fields[0].auto_increment);
+        assert!(crate::validation::Validator::validate_model(&model).is_ok());
+    }
+
+    #[test]
+    fn test_config_builder() {
+        let config = ConfigBuilder::new()
+            .model(ModelBuilder::new("User").field("name", FieldType::String).build())
+            .output_dir("/tmp/app")
+            .configure(|config| config.generate_dto = true)
+            .build();
+
+        assert_eq!(config.models[0].table, "users");
+        assert_eq!(config.output_dir, "/tmp/app");
+        assert!(config.generate_dto);
+        assert_eq!(config.namespace, Config::default().namespace);
+    }
+
+    #[test]
+    #[should_panic(expected = "add a field")]
+    fn test_field_modifier_without_field_panics() {
+        ModelBuilder::new("User").nullable();
+    }
+}
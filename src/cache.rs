@@ -0,0 +1,125 @@
+use crate::error::{GeneratorError, Result};
+use crate::generators::shared::PathResolver;
+use crate::types::{Config, ModelDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Cache file written to the output directory by `--only-changed`
+pub const CACHE_FILE: &str = ".schemly-cache.json";
+
+/// Hashes of each model's definition from the last `--only-changed` run
+///
+/// A model whose hash still matches is skipped. The whole cache is void when the
+/// schemly version or any config-wide setting changes, since either can change
+/// every generated file.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerationCache {
+    version: String,
+    settings: String,
+    models: BTreeMap<String, String>,
+}
+
+impl GenerationCache {
+    /// Path of the cache file for a config's output directory
+    pub fn path(config: &Config) -> String {
+        PathResolver::join(&config.output_dir, &[CACHE_FILE])
+    }
+
+    /// Reads the cache, starting empty when it is missing, unreadable or stale
+    pub fn load(config: &Config) -> Self {
+        let empty = Self::empty(config);
+        let Ok(content) = fs::read_to_string(Self::path(config)) else {
+            return empty;
+        };
+        match serde_json::from_str::<Self>(&content) {
+            Ok(cache) if cache.version == empty.version && cache.settings == empty.settings => cache,
+            Ok(_) => {
+                log::info!("Ignoring {}: written by another schemly version or config", CACHE_FILE);
+                empty
+            }
+            Err(e) => {
+                log::warn!("Ignoring unreadable {}: {}", CACHE_FILE, e);
+                empty
+            }
+        }
+    }
+
+    pub fn save(&self, config: &Config) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| GeneratorError::Configuration(format!("Failed to serialize cache: {}", e)))?;
+        fs::write(Self::path(config), json)?;
+        Ok(())
+    }
+
+    /// Whether the model is defined exactly as in the last cached run
+    pub fn is_unchanged(&self, model: &ModelDefinition) -> bool {
+        self.models.get(&model.name).is_some_and(|hash| *hash == fingerprint(model))
+    }
+
+    /// Records the model's current definition
+    pub fn insert(&mut self, model: &ModelDefinition) {
+        self.models.insert(model.name.clone(), fingerprint(model));
+    }
+
+    /// Forgets a model so it is regenerated next time
+    pub fn remove(&mut self, model_name: &str) {
+        self.models.remove(model_name);
+    }
+
+    fn empty(config: &Config) -> Self {
+        // Per-run flags don't change what gets rendered
+        let settings = Config {
+            models: Vec::new(),
+            force_overwrite: false,
+            update_generated: false,
+            ..config.clone()
+        };
+        GenerationCache {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            settings: fingerprint(&settings),
+            models: BTreeMap::new(),
+        }
+    }
+}
+
+/// FNV-1a hash of a value's JSON form, stable across runs and Rust versions
+fn fingerprint(value: &impl Serialize) -> String {
+    let json = serde_json::to_vec(value).unwrap_or_default();
+    let hash = json.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cache_void_after_version_or_settings_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = Config::from_json(r#"{
+            "models": [{ "name": "User", "table": "users", "fields": [{ "name": "name", "type": "string" }] }]
+        }"#, false).unwrap();
+        config.output_dir = temp_dir.path().to_str().unwrap().to_string();
+
+        let mut cache = GenerationCache::load(&config);
+        cache.insert(&config.models[0]);
+        cache.save(&config).unwrap();
+        assert!(GenerationCache::load(&config).is_unchanged(&config.models[0]));
+
+        // Per-run flags keep the cache
+        config.force_overwrite = true;
+        assert!(GenerationCache::load(&config).is_unchanged(&config.models[0]));
+
+        config.generate_dto = true;
+        assert!(!GenerationCache::load(&config).is_unchanged(&config.models[0]));
+        config.generate_dto = false;
+
+        cache.version = "0.0.0".to_string();
+        cache.save(&config).unwrap();
+        assert!(!GenerationCache::load(&config).is_unchanged(&config.models[0]));
+    }
+}
@@ -0,0 +1,158 @@
+/// Lines of unchanged context printed around each change
+const CONTEXT_LINES: usize = 3;
+
+/// One line of a line-by-line comparison
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Renders a unified diff between two texts, or an empty string when they match
+///
+/// Lines are compared through their longest common subsequence, which is plenty
+/// for generated source files of a few hundred lines.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let lines = diff_lines(&old_lines, &new_lines);
+
+    let changes: Vec<usize> = lines.iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(index, _)| index)
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    // Line numbers in the old and new text before each entry
+    let mut positions = Vec::with_capacity(lines.len() + 1);
+    let (mut old_line, mut new_line) = (0, 0);
+    for line in &lines {
+        positions.push((old_line, new_line));
+        match line {
+            DiffLine::Same(_) => { old_line += 1; new_line += 1; },
+            DiffLine::Removed(_) => old_line += 1,
+            DiffLine::Added(_) => new_line += 1,
+        }
+    }
+    positions.push((old_line, new_line));
+
+    let mut output = format!("--- {}\n+++ {}\n", old_label, new_label);
+    let mut group_start = 0;
+    while group_start < changes.len() {
+        // Changes separated by less than two contexts' worth of lines share a hunk
+        let mut group_end = group_start;
+        while group_end + 1 < changes.len() && changes[group_end + 1] - changes[group_end] <= 2 * CONTEXT_LINES + 1 {
+            group_end += 1;
+        }
+
+        let start = changes[group_start].saturating_sub(CONTEXT_LINES);
+        let end = (changes[group_end] + 1 + CONTEXT_LINES).min(lines.len());
+        let (old_start, new_start) = positions[start];
+        let (old_end, new_end) = positions[end];
+
+        output.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            format_range(old_start, old_end - old_start),
+            format_range(new_start, new_end - new_start)
+        ));
+        for line in &lines[start..end] {
+            match line {
+                DiffLine::Same(text) => output.push_str(&format!(" {}\n", text)),
+                DiffLine::Removed(text) => output.push_str(&format!("-{}\n", text)),
+                DiffLine::Added(text) => output.push_str(&format!("+{}\n", text)),
+            }
+        }
+
+        group_start = group_end + 1;
+    }
+
+    output
+}
+
+/// Hunk range in unified diff notation (`12,4`, `12` for one line, `11,0` for none)
+fn format_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffLine<'a>> {
+    // common[i][j] = length of the longest common subsequence of old[i..] and new[j..]
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            lines.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|line| DiffLine::Removed(line)));
+    lines.extend(new[j..].iter().map(|line| DiffLine::Added(line)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unchanged_file_has_empty_diff() {
+        let content = "<?php\n\nclass User\n{\n}\n";
+        assert_eq!(unified_diff(content, content, "a", "b"), "");
+    }
+
+    #[test]
+    fn test_changed_file_has_hunks() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+
+        let diff = unified_diff(old, new, "old/User.php", "new/User.php");
+        assert_eq!(diff, "\
+--- old/User.php
++++ new/User.php
+@@ -1,5 +1,5 @@
+ a
+-b
++B
+ c
+ d
+ e
+@@ -10,3 +10,4 @@
+ j
+ k
+ l
++m
+");
+    }
+
+    #[test]
+    fn test_nearby_changes_share_a_hunk() {
+        let diff = unified_diff("a\nb\nc\nd\n", "a\nX\nc\nY\n", "a", "b");
+        assert_eq!(diff.matches("@@ ").count(), 1);
+        assert!(diff.contains("@@ -1,4 +1,4 @@"));
+    }
+}
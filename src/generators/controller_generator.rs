@@ -1,8 +1,9 @@
 use crate::generators::Generator;
-use crate::generators::shared::NamespaceResolver;
+use crate::generators::shared::{PathResolver, NamespaceResolver, ClassNameResolver, FieldTypeHelper, GeneratedMarker};
 use crate::types::{Config, ModelDefinition};
 use crate::validation::Validator;
-use crate::template::{TemplateContext, TemplateRenderer};
+use crate::template::{CompiledTemplate, TemplateContext};
+use std::sync::OnceLock;
 
 // Type aliases for better readability
 type GeneratorResult<T> = crate::error::Result<T>;
@@ -11,22 +12,43 @@ pub struct ControllerGenerator;
 
 // Template constants
 const TEMPLATE: &str = include_str!("../templates/controller.php.template");
+static COMPILED_TEMPLATE: OnceLock<CompiledTemplate> = OnceLock::new();
 
 // Template variable names
 mod template_vars {
+    pub const PHP_HEADER: &str = "php_header";
     pub const NAMESPACE: &str = "namespace";
+    pub const CONTROLLER_NAMESPACE: &str = "controller_namespace";
+    pub const CONTROLLER_BASE_IMPORT: &str = "controller_base_import";
+    pub const RESOURCE_NAMESPACE: &str = "resource_namespace";
+    pub const RESOURCE_CLASS: &str = "resource_class";
     pub const MODEL_NAME: &str = "model_name";
     pub const MODEL_VAR_NAME: &str = "model_var_name";
-    pub const VALIDATION_RULES: &str = "validation_rules";
-    pub const REQUEST_NAMESPACE: &str = "request_namespace";
-    pub const USE_REQUESTS: &str = "use_requests";
+    pub const REQUEST_IMPORTS: &str = "request_imports";
+    pub const DTO_IMPORT: &str = "dto_import";
+    pub const STORE_REQUEST_CLASS: &str = "store_request_class";
+    pub const UPDATE_REQUEST_CLASS: &str = "update_request_class";
+    pub const VALIDATION: &str = "validation";
+    pub const STORE_RESPONSE: &str = "store_response";
+    pub const ITEM_RESPONSE: &str = "item_response";
 }
 
 const REQUIRED_TEMPLATE_VARS: &[&str] = &[
+    template_vars::PHP_HEADER,
     template_vars::NAMESPACE,
+    template_vars::CONTROLLER_NAMESPACE,
+    template_vars::CONTROLLER_BASE_IMPORT,
+    template_vars::RESOURCE_NAMESPACE,
+    template_vars::RESOURCE_CLASS,
     template_vars::MODEL_NAME,
     template_vars::MODEL_VAR_NAME,
-    template_vars::VALIDATION_RULES,
+    template_vars::REQUEST_IMPORTS,
+    template_vars::DTO_IMPORT,
+    template_vars::STORE_REQUEST_CLASS,
+    template_vars::UPDATE_REQUEST_CLASS,
+    template_vars::VALIDATION,
+    template_vars::STORE_RESPONSE,
+    template_vars::ITEM_RESPONSE,
 ];
 
 impl Generator for ControllerGenerator {
@@ -37,11 +59,7 @@ impl Generator for ControllerGenerator {
     }
 
     fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("{}/app/Domain/{}/Controllers/{}Controller.php", config.output_dir, model.name, model.name)
-        } else {
-            format!("{}/app/Http/Controllers/{}Controller.php", config.output_dir, model.name)
-        }
+        PathResolver::get_controller_path(model, config)
     }
 }
 
@@ -63,34 +81,149 @@ impl ControllerGenerator {
     /// Builds the template context with all required variables
     fn build_template_context(&self, model: &ModelDefinition, config: &Config) -> GeneratorResult<TemplateContext> {
         let namespace = NamespaceResolver::get_model_namespace(model, config);
-        let request_namespace = NamespaceResolver::get_request_namespace(model, config);
+        let controller_namespace = NamespaceResolver::get_controller_namespace(model, config);
+        let resource_namespace = NamespaceResolver::get_resource_namespace(model, config);
+        // Controllers outside App\Http\Controllers must import the base class
+        let controller_base_import = if controller_namespace != "App\\Http\\Controllers" {
+            "\nuse App\\Http\\Controllers\\Controller;"
+        } else {
+            ""
+        };
+        let resource_class = ClassNameResolver::resource_class(model);
         let model_var_name = model.name.to_lowercase();
-        
-        let mut validation_rules = String::new();
-        for field in &model.fields {
-            if field.name != "id" {
-                let rule = if field.nullable { "nullable" } else { "required" };
-                validation_rules.push_str(&format!("'{}' => '{}',\n            ", field.name, rule));
+
+        // Form Requests validate themselves; otherwise validate inline
+        let (request_imports, store_request_class, update_request_class, validation) = if config.generate_requests {
+            let request_namespace = NamespaceResolver::get_request_namespace(model, config);
+            (
+                format!("\nuse {}\\Store{}Request;\nuse {}\\Update{}Request;", request_namespace, model.name, request_namespace, model.name),
+                format!("Store{}Request", model.name),
+                format!("Update{}Request", model.name),
+                "$validated = $request->validated();".to_string(),
+            )
+        } else {
+            let mut validation_rules = String::new();
+            for field in &model.fields {
+                if field.name != "id" {
+                    let mut rule = if field.nullable { "nullable" } else { "required" }.to_string();
+                    // `min` only compares values once the field is known to be numeric
+                    if config.unsigned_min_rule && field.unsigned
+                        && let Some(numeric_rule) = FieldTypeHelper::numeric_rule(&field.field_type) {
+                        rule.push_str(&format!("|{}|min:0", numeric_rule));
+                    }
+                    validation_rules.push_str(&format!("            '{}' => '{}',\n", field.name, rule));
+                }
             }
-        }
+            (
+                String::new(),
+                "Request".to_string(),
+                "Request".to_string(),
+                format!("$validated = $request->validate([\n{}        ]);", validation_rules),
+            )
+        };
+
+        // Single-item responses go through the DTO when enabled, otherwise the API resource
+        let (dto_import, store_response, item_response) = if config.generate_dto && config.dto_responses {
+            let dto_class = ClassNameResolver::dto_class(model);
+            let dto = format!("{}::fromModel(${})", dto_class, model_var_name);
+            (
+                format!("\nuse {}\\{};", NamespaceResolver::get_dto_namespace(model, config), dto_class),
+                format!("return response()->json({}, 201);", dto),
+                format!("return response()->json({});", dto),
+            )
+        } else {
+            let resource = format!("return new {}(${});", resource_class, model_var_name);
+            (String::new(), resource.clone(), resource)
+        };
 
         let context = TemplateContext::new()
+            .with(template_vars::PHP_HEADER, GeneratedMarker::php_header(config))
             .with(template_vars::NAMESPACE, namespace)
+            .with(template_vars::CONTROLLER_NAMESPACE, controller_namespace)
+            .with(template_vars::CONTROLLER_BASE_IMPORT, controller_base_import)
+            .with(template_vars::RESOURCE_NAMESPACE, resource_namespace)
+            .with(template_vars::RESOURCE_CLASS, resource_class)
             .with(template_vars::MODEL_NAME, &model.name)
             .with(template_vars::MODEL_VAR_NAME, model_var_name)
-            .with(template_vars::VALIDATION_RULES, validation_rules.trim_end())
-            .with(template_vars::REQUEST_NAMESPACE, request_namespace)
-            .with(template_vars::USE_REQUESTS, if config.generate_requests { "true" } else { "" });
+            .with(template_vars::REQUEST_IMPORTS, request_imports)
+            .with(template_vars::DTO_IMPORT, dto_import)
+            .with(template_vars::STORE_REQUEST_CLASS, store_request_class)
+            .with(template_vars::UPDATE_REQUEST_CLASS, update_request_class)
+            .with(template_vars::VALIDATION, validation)
+            .with(template_vars::STORE_RESPONSE, store_response)
+            .with(template_vars::ITEM_RESPONSE, item_response);
 
         Ok(context)
     }
 
     /// Renders the template with the provided context
     fn render_template(&self, context: &TemplateContext) -> GeneratorResult<String> {
-        TemplateRenderer::render_with_required_vars(
-            TEMPLATE,
-            context,
-            REQUIRED_TEMPLATE_VARS
-        )
+        CompiledTemplate::cached(&COMPILED_TEMPLATE, TEMPLATE)?
+            .render_with_required_vars(context, REQUIRED_TEMPLATE_VARS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_resource_responses_by_default() {
+        let result = ControllerGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(!result.contains("{%"));
+        assert!(!result.contains("UserDTO"));
+        assert!(result.contains("public function store(Request $request)"));
+        assert!(result.contains("        return new UserResource($user);\n"));
+    }
+
+    #[test]
+    fn test_dto_responses_when_enabled() {
+        let config = Config {
+            generate_dto: true,
+            dto_responses: true,
+            ..Config::default()
+        };
+        let result = ControllerGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\DTOs\\UserDTO;"));
+        assert!(result.contains("return response()->json(UserDTO::fromModel($user), 201);"));
+        assert_eq!(result.matches("return response()->json(UserDTO::fromModel($user));").count(), 2);
+    }
+
+    #[test]
+    fn test_form_requests_when_enabled() {
+        let config = Config {
+            generate_requests: true,
+            ..Config::default()
+        };
+        let result = ControllerGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\Http\\Requests\\StoreUserRequest;"));
+        assert!(result.contains("public function update(UpdateUserRequest $request, User $user)"));
+        assert!(result.contains("$validated = $request->validated();"));
+    }
+
+    #[test]
+    fn test_ddd_imports_resource_from_domain_namespace() {
+        let config = Config {
+            use_ddd_structure: true,
+            ..Config::default()
+        };
+        let result = ControllerGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("namespace App\\Domain\\User\\Controllers;"));
+        assert!(result.contains("use App\\Domain\\User\\Resources\\UserResource;"));
+        assert!(!result.contains("App\\Http\\Resources"));
+    }
+}
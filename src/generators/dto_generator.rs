@@ -1,8 +1,9 @@
 use crate::generators::Generator;
-use crate::generators::shared::{PathResolver, NamespaceResolver, FieldTypeHelper};
-use crate::types::{Config, ModelDefinition, Field};
+use crate::generators::shared::{PathResolver, NamespaceResolver, FieldTypeHelper, ClassNameResolver, GeneratedMarker};
+use crate::types::{Config, ModelDefinition, Field, FieldType};
 use crate::validation::Validator;
-use crate::template::{TemplateContext, TemplateRenderer};
+use crate::template::{CompiledTemplate, TemplateContext};
+use std::sync::OnceLock;
 
 // Type aliases for better readability
 type GeneratorResult<T> = crate::error::Result<T>;
@@ -18,27 +19,45 @@ pub struct DtoGenerator;
 
 // Template constants
 const TEMPLATE: &str = include_str!("../templates/dto.php.template");
+static COMPILED_TEMPLATE: OnceLock<CompiledTemplate> = OnceLock::new();
 
 // Template variable names
 mod template_vars {
+    pub const PHP_HEADER: &str = "php_header";
     pub const NAMESPACE: &str = "namespace";
     pub const DTO_NAME: &str = "dto_name";
+    pub const MODEL_NAME: &str = "model_name";
+    pub const CONSTRUCTOR_DOCBLOCK: &str = "constructor_docblock";
     pub const CONSTRUCTOR_FIELDS: &str = "constructor_fields";
+    pub const MODEL_NAMESPACE: &str = "model_namespace";
     pub const FROM_ARRAY_FIELDS: &str = "from_array_fields";
+    pub const FROM_MODEL_FIELDS: &str = "from_model_fields";
     pub const TO_ARRAY_FIELDS: &str = "to_array_fields";
+    pub const DTO_IMPORTS: &str = "dto_imports";
+    pub const DTO_IMPLEMENTS: &str = "dto_implements";
+    pub const JSON_SERIALIZE_METHOD: &str = "json_serialize_method";
 }
 
 const REQUIRED_TEMPLATE_VARS: &[&str] = &[
+    template_vars::PHP_HEADER,
     template_vars::NAMESPACE,
     template_vars::DTO_NAME,
+    template_vars::MODEL_NAME,
+    template_vars::CONSTRUCTOR_DOCBLOCK,
     template_vars::CONSTRUCTOR_FIELDS,
+    template_vars::MODEL_NAMESPACE,
     template_vars::FROM_ARRAY_FIELDS,
+    template_vars::FROM_MODEL_FIELDS,
     template_vars::TO_ARRAY_FIELDS,
+    template_vars::DTO_IMPORTS,
+    template_vars::DTO_IMPLEMENTS,
+    template_vars::JSON_SERIALIZE_METHOD,
 ];
 
 // Field formatting constants - indentation for different sections
 const CONSTRUCTOR_FIELD_INDENT: &str = "        ";
 const FROM_ARRAY_FIELD_INDENT: &str = "                ";
+const FROM_MODEL_FIELD_INDENT: &str = "            ";
 const TO_ARRAY_FIELD_INDENT: &str = "            ";
 
 // Standard field names
@@ -72,45 +91,95 @@ impl DtoGenerator {
             ));
         }
 
-        Validator::validate_identifier(&model.name, "DTO class name")?;
+        Validator::validate_identifier(&ClassNameResolver::dto_class(model), "DTO class name")?;
         Ok(())
     }
 
     /// Builds the template context with all required variables
     fn build_template_context(&self, model: &ModelDefinition, config: &Config) -> GeneratorResult<TemplateContext> {
         let namespace = NamespaceResolver::get_dto_namespace(model, config);
-        let constructor_fields = self.generate_constructor_fields(model)?;
-        let from_array_fields = self.generate_from_array_fields(model)?;
-        let to_array_fields = self.generate_to_array_fields(model)?;
+        let constructor_fields = self.generate_constructor_fields(model, config)?;
+        let from_array_fields = self.generate_from_array_fields(model, config)?;
+        let from_model_fields = self.generate_from_model_fields(model, config)?;
+        let to_array_fields = self.generate_to_array_fields(model, config)?;
+        let (imports, implements) = self.generate_interfaces(config);
+        let json_serialize_method = if config.dto_json_serializable {
+            "\n    public function jsonSerialize(): array\n    {\n        return $this->toArray();\n    }\n"
+        } else {
+            ""
+        };
 
         let context = TemplateContext::new()
+            .with(template_vars::PHP_HEADER, GeneratedMarker::php_header(config))
             .with(template_vars::NAMESPACE, format!("namespace {};", namespace))
-            .with(template_vars::DTO_NAME, &model.name)
+            .with(template_vars::DTO_NAME, ClassNameResolver::dto_class(model))
+            .with(template_vars::MODEL_NAME, &model.name)
+            .with(template_vars::CONSTRUCTOR_DOCBLOCK, self.generate_constructor_docblock(model))
             .with(template_vars::CONSTRUCTOR_FIELDS, constructor_fields)
+            .with(template_vars::MODEL_NAMESPACE, NamespaceResolver::get_model_namespace(model, config))
             .with(template_vars::FROM_ARRAY_FIELDS, from_array_fields)
-            .with(template_vars::TO_ARRAY_FIELDS, to_array_fields);
+            .with(template_vars::FROM_MODEL_FIELDS, from_model_fields)
+            .with(template_vars::TO_ARRAY_FIELDS, to_array_fields)
+            .with(template_vars::DTO_IMPORTS, imports)
+            .with(template_vars::DTO_IMPLEMENTS, implements)
+            .with(template_vars::JSON_SERIALIZE_METHOD, json_serialize_method);
 
         Ok(context)
     }
 
     /// Renders the DTO template with the provided context
     fn render_template(&self, context: &TemplateContext) -> GeneratorResult<String> {
-        TemplateRenderer::render_with_required_vars(
-            TEMPLATE,
-            context,
-            REQUIRED_TEMPLATE_VARS
-        )
+        CompiledTemplate::cached(&COMPILED_TEMPLATE, TEMPLATE)?
+            .render_with_required_vars(context, REQUIRED_TEMPLATE_VARS)
+    }
+
+    /// Imports and `implements` clause for the interfaces enabled in the config
+    fn generate_interfaces(&self, config: &Config) -> (String, String) {
+        let mut imports = String::new();
+        let mut interfaces = Vec::new();
+        if config.dto_arrayable {
+            imports.push_str("\nuse Illuminate\\Contracts\\Support\\Arrayable;");
+            interfaces.push("Arrayable");
+        }
+        if config.dto_json_serializable {
+            imports.push_str("\nuse JsonSerializable;");
+            interfaces.push("JsonSerializable");
+        }
+
+        if interfaces.is_empty() {
+            return (imports, String::new());
+        }
+        (imports, format!(" implements {}", interfaces.join(", ")))
+    }
+
+    /// Documents the shape of JSON fields that declare their keys (`@param array{street: string} $address`)
+    fn generate_constructor_docblock(&self, model: &ModelDefinition) -> String {
+        let params: FieldList = model.fields.iter()
+            .filter(|field| !field.json_schema.is_empty())
+            .map(|field| {
+                let shape: FieldList = field.json_schema.iter()
+                    .map(|key| format!("{}: {}", key.name, key.php_type))
+                    .collect();
+                let nullable = if field.nullable { "?" } else { "" };
+                format!("     * @param {}array{{{}}} ${}\n", nullable, shape.join(", "), field.name)
+            })
+            .collect();
+
+        if params.is_empty() {
+            return String::new();
+        }
+        format!("    /**\n{}     */\n", params.concat())
     }
 
     /// Generates constructor field declarations
-    fn generate_constructor_fields(&self, model: &ModelDefinition) -> GeneratorResult<String> {
+    fn generate_constructor_fields(&self, model: &ModelDefinition, config: &Config) -> GeneratorResult<String> {
         let mut fields = vec![format!("public int ${}", standard_fields::ID)];
 
         // Add model fields
         fields.extend(self.generate_model_constructor_fields(&model.fields)?);
 
         // Add timestamp fields
-        fields.extend(self.generate_timestamp_constructor_fields(model));
+        fields.extend(self.generate_timestamp_constructor_fields(model, config));
 
         Ok(fields.join(&format!(",\n{}", CONSTRUCTOR_FIELD_INDENT)))
     }
@@ -138,17 +207,17 @@ impl DtoGenerator {
     }
 
     /// Generates timestamp-related constructor fields
-    fn generate_timestamp_constructor_fields(&self, model: &ModelDefinition) -> FieldList {
+    fn generate_timestamp_constructor_fields(&self, model: &ModelDefinition, config: &Config) -> FieldList {
         let mut fields = Vec::new();
 
-        if model.timestamps {
+        if model.has_timestamps(config) {
             fields.extend_from_slice(&[
                 format!("public ?string ${}", standard_fields::CREATED_AT),
                 format!("public ?string ${}", standard_fields::UPDATED_AT),
             ]);
         }
 
-        if model.soft_deletes {
+        if model.has_soft_deletes(config) {
             fields.push(format!("public ?string ${}", standard_fields::DELETED_AT));
         }
 
@@ -156,14 +225,14 @@ impl DtoGenerator {
     }
 
     /// Generates fromArray method field assignments
-    fn generate_from_array_fields(&self, model: &ModelDefinition) -> GeneratorResult<String> {
+    fn generate_from_array_fields(&self, model: &ModelDefinition, config: &Config) -> GeneratorResult<String> {
         let mut fields = vec![format!("$data['{}']", standard_fields::ID)];
 
         // Add model fields
         fields.extend(self.generate_model_from_array_fields(&model.fields)?);
 
         // Add timestamp fields
-        fields.extend(self.generate_timestamp_from_array_fields(model));
+        fields.extend(self.generate_timestamp_from_array_fields(model, config));
 
         Ok(fields.join(&format!(",\n{}", FROM_ARRAY_FIELD_INDENT)))
     }
@@ -183,32 +252,63 @@ impl DtoGenerator {
     }
 
     /// Generates timestamp-related fromArray fields
-    fn generate_timestamp_from_array_fields(&self, model: &ModelDefinition) -> FieldList {
+    fn generate_timestamp_from_array_fields(&self, model: &ModelDefinition, config: &Config) -> FieldList {
         let mut fields = Vec::new();
 
-        if model.timestamps {
+        if model.has_timestamps(config) {
             fields.extend_from_slice(&[
                 format!("$data['{}']", standard_fields::CREATED_AT),
                 format!("$data['{}']", standard_fields::UPDATED_AT),
             ]);
         }
 
-        if model.soft_deletes {
+        if model.has_soft_deletes(config) {
             fields.push(format!("$data['{}']", standard_fields::DELETED_AT));
         }
 
         fields
     }
 
+    /// Generates fromModel method field reads
+    fn generate_from_model_fields(&self, model: &ModelDefinition, config: &Config) -> GeneratorResult<String> {
+        let mut fields = vec![format!("$model->{}", standard_fields::ID)];
+
+        // Add model fields
+        for field in &model.fields {
+            if field.name != standard_fields::ID {
+                Validator::validate_identifier(&field.name, "Field name")?;
+                // Date columns come back as Carbon instances but are typed as strings on the DTO
+                let read = match field.field_type {
+                    FieldType::Date => format!("$model->{}?->toDateString()", field.name),
+                    FieldType::DateTime | FieldType::Timestamp => format!("$model->{}?->toDateTimeString()", field.name),
+                    _ => format!("$model->{}", field.name),
+                };
+                fields.push(read);
+            }
+        }
+
+        // Add timestamp fields
+        let mut timestamps = Vec::new();
+        if model.has_timestamps(config) {
+            timestamps.extend_from_slice(&[standard_fields::CREATED_AT, standard_fields::UPDATED_AT]);
+        }
+        if model.has_soft_deletes(config) {
+            timestamps.push(standard_fields::DELETED_AT);
+        }
+        fields.extend(timestamps.iter().map(|name| format!("$model->{}?->toDateTimeString()", name)));
+
+        Ok(fields.join(&format!(",\n{}", FROM_MODEL_FIELD_INDENT)))
+    }
+
     /// Generates toArray method field mappings
-    fn generate_to_array_fields(&self, model: &ModelDefinition) -> GeneratorResult<String> {
+    fn generate_to_array_fields(&self, model: &ModelDefinition, config: &Config) -> GeneratorResult<String> {
         let mut fields = vec![format!("'{}' => $this->{}", standard_fields::ID, standard_fields::ID)];
 
         // Add model fields
         fields.extend(self.generate_model_to_array_fields(&model.fields)?);
 
         // Add timestamp fields
-        fields.extend(self.generate_timestamp_to_array_fields(model));
+        fields.extend(self.generate_timestamp_to_array_fields(model, config));
 
         Ok(fields.join(&format!(",\n{}", TO_ARRAY_FIELD_INDENT)))
     }
@@ -228,17 +328,17 @@ impl DtoGenerator {
     }
 
     /// Generates timestamp-related toArray fields
-    fn generate_timestamp_to_array_fields(&self, model: &ModelDefinition) -> FieldList {
+    fn generate_timestamp_to_array_fields(&self, model: &ModelDefinition, config: &Config) -> FieldList {
         let mut fields = Vec::new();
 
-        if model.timestamps {
+        if model.has_timestamps(config) {
             fields.extend_from_slice(&[
                 format!("'{}' => $this->{}", standard_fields::CREATED_AT, standard_fields::CREATED_AT),
                 format!("'{}' => $this->{}", standard_fields::UPDATED_AT, standard_fields::UPDATED_AT),
             ]);
         }
 
-        if model.soft_deletes {
+        if model.has_soft_deletes(config) {
             fields.push(format!("'{}' => $this->{}", standard_fields::DELETED_AT, standard_fields::DELETED_AT));
         }
 
@@ -251,94 +351,31 @@ impl DtoGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Field, FieldType, FillableGuarded};
+    use crate::types::{Field, FieldType, JsonKey};
+    use std::path::Path;
 
     fn create_test_model() -> ModelDefinition {
         ModelDefinition {
             name: "User".to_string(),
             table: "users".to_string(),
             fields: vec![
-                Field {
-                    name: "name".to_string(),
-                    field_type: FieldType::String,
-                    nullable: false,
-                    unique: false,
-                    default: None,
-                    length: Some(255),
-                    index: false,
-                    enum_values: vec![],
-                    decimal_precision: None,
-                    unsigned: false,
-                    auto_increment: false,
-                    primary: false,
-                    comment: None,
-                    validation_rules: vec![],
-                    cast_type: None,
-                },
-                Field {
-                    name: "email".to_string(),
-                    field_type: FieldType::String,
-                    nullable: false,
-                    unique: true,
-                    default: None,
-                    length: Some(255),
-                    index: false,
-                    enum_values: vec![],
-                    decimal_precision: None,
-                    unsigned: false,
-                    auto_increment: false,
-                    primary: false,
-                    comment: None,
-                    validation_rules: vec![],
-                    cast_type: None,
-                },
-                Field {
-                    name: "age".to_string(),
-                    field_type: FieldType::Integer,
-                    nullable: true,
-                    unique: false,
-                    default: None,
-                    length: None,
-                    index: false,
-                    enum_values: vec![],
-                    decimal_precision: None,
-                    unsigned: false,
-                    auto_increment: false,
-                    primary: false,
-                    comment: None,
-                    validation_rules: vec![],
-                    cast_type: None,
-                },
+                Field { length: Some(255), ..Field::new("name", FieldType::String) },
+                Field { unique: true, length: Some(255), ..Field::new("email", FieldType::String) },
+                Field { nullable: true, ..Field::new("age", FieldType::Integer) },
             ],
-            timestamps: true,
-            soft_deletes: false,
-            relationships: vec![],
-            pivot_tables: vec![],
-            validation_rules: vec![],
-            traits: vec![],
-            fillable_guarded: FillableGuarded::All,
-            compound_indexes: vec![],
-            compound_uniques: vec![],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
         }
     }
 
     fn create_test_config(use_ddd: bool) -> Config {
         Config {
-            models: vec![],
             output_dir: "/tmp/test".to_string(),
-            namespace: "App\\Models".to_string(),
-            generate_models: true,
-            generate_controllers: true,
-            generate_resources: true,
-            generate_factories: true,
-            generate_migrations: true,
-            generate_pivot_tables: true,
-            generate_validation_rules: true,
             generate_requests: true,
             generate_dto: true,
             use_ddd_structure: use_ddd,
-            database_engine: "mysql".to_string(),
-            force_overwrite: false,
+            ..Config::default()
         }
     }
 
@@ -394,6 +431,33 @@ mod tests {
         assert!(result.contains("class UserDTO {"));
     }
 
+    #[test]
+    fn test_dto_implements_arrayable() {
+        let mut config = create_test_config(false);
+        config.dto_arrayable = true;
+        let result = DtoGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\Models\\User;\nuse Illuminate\\Contracts\\Support\\Arrayable;\n"));
+        assert!(result.contains("class UserDTO implements Arrayable {"));
+        assert!(!result.contains("jsonSerialize"));
+    }
+
+    #[test]
+    fn test_dto_implements_json_serializable() {
+        let mut config = create_test_config(false);
+        config.dto_json_serializable = true;
+        let result = DtoGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\Models\\User;\nuse JsonSerializable;\n"));
+        assert!(result.contains("class UserDTO implements JsonSerializable {"));
+        assert!(result.contains("    public function jsonSerialize(): array\n    {\n        return $this->toArray();\n    }\n\n}"));
+
+        config.dto_arrayable = true;
+        let result = DtoGenerator.generate(&create_test_model(), &config).unwrap();
+        assert!(result.contains("use Illuminate\\Contracts\\Support\\Arrayable;\nuse JsonSerializable;\n"));
+        assert!(result.contains("class UserDTO implements Arrayable, JsonSerializable {"));
+    }
+
     #[test]
     fn test_dto_file_path_traditional() {
         let generator = DtoGenerator;
@@ -401,7 +465,7 @@ mod tests {
         let config = create_test_config(false);
 
         let path = generator.get_file_path(&model, &config);
-        assert_eq!(path, "/tmp/test/app/DTOs/UserDTO.php");
+        assert_eq!(Path::new(&path), Path::new("/tmp/test/app/DTOs/UserDTO.php"));
     }
 
     #[test]
@@ -411,7 +475,20 @@ mod tests {
         let config = create_test_config(true);
 
         let path = generator.get_file_path(&model, &config);
-        assert_eq!(path, "/tmp/test/app/Domain/User/DTOs/UserDTO.php");
+        assert_eq!(Path::new(&path), Path::new("/tmp/test/app/Domain/User/DTOs/UserDTO.php"));
+    }
+
+    #[test]
+    fn test_custom_dto_name() {
+        let mut model = create_test_model();
+        model.dto_name = Some("UserData".to_string());
+        let config = create_test_config(false);
+
+        let result = DtoGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("class UserData {"));
+        assert!(result.contains("public static function fromModel(User $model): self"));
+        assert!(!result.contains("UserDTO"));
+        assert_eq!(Path::new(&DtoGenerator.get_file_path(&model, &config)), Path::new("/tmp/test/app/DTOs/UserData.php"));
     }
 
     #[test]
@@ -419,7 +496,7 @@ mod tests {
         let generator = DtoGenerator;
         let model = create_test_model();
 
-        let result = generator.generate_constructor_fields(&model).unwrap();
+        let result = generator.generate_constructor_fields(&model, &Config::default()).unwrap();
 
         assert!(result.contains("public int $id"));
         assert!(result.contains("public string $name"));
@@ -434,7 +511,7 @@ mod tests {
         let generator = DtoGenerator;
         let model = create_test_model();
 
-        let result = generator.generate_from_array_fields(&model).unwrap();
+        let result = generator.generate_from_array_fields(&model, &Config::default()).unwrap();
 
         assert!(result.contains("$data['id']"));
         assert!(result.contains("$data['name']"));
@@ -449,7 +526,7 @@ mod tests {
         let generator = DtoGenerator;
         let model = create_test_model();
 
-        let result = generator.generate_to_array_fields(&model).unwrap();
+        let result = generator.generate_to_array_fields(&model, &Config::default()).unwrap();
 
         assert!(result.contains("'id' => $this->id"));
         assert!(result.contains("'name' => $this->name"));
@@ -459,15 +536,44 @@ mod tests {
         assert!(result.contains("'updated_at' => $this->updated_at"));
     }
 
+    #[test]
+    fn test_from_model_method() {
+        let result = DtoGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("use App\\Models\\User;"));
+        assert!(result.contains("public static function fromModel(User $model): self"));
+        for read in ["$model->id", "$model->name", "$model->email", "$model->age", "$model->created_at?->toDateTimeString()"] {
+            assert!(result.contains(read), "missing {}", read);
+        }
+    }
+
+    #[test]
+    fn test_json_field_docblock() {
+        let mut model = create_test_model();
+        let mut address = model.fields[0].clone();
+        address.name = "address".to_string();
+        address.field_type = FieldType::Json;
+        address.json_schema = vec![
+            JsonKey { name: "street".to_string(), php_type: "string".to_string() },
+            JsonKey { name: "zip".to_string(), php_type: "?int".to_string() },
+        ];
+        model.fields.push(address);
+
+        let result = DtoGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("    /**\n     * @param array{street: string, zip: ?int} $address\n     */\n    public function __construct"));
+        assert!(result.contains("public array $address"));
+    }
+
     #[test]
     fn test_soft_deletes_support() {
         let generator = DtoGenerator;
         let mut model = create_test_model();
-        model.soft_deletes = true;
+        model.soft_deletes = Some(true);
 
-        let constructor_result = generator.generate_constructor_fields(&model).unwrap();
-        let from_array_result = generator.generate_from_array_fields(&model).unwrap();
-        let to_array_result = generator.generate_to_array_fields(&model).unwrap();
+        let constructor_result = generator.generate_constructor_fields(&model, &Config::default()).unwrap();
+        let from_array_result = generator.generate_from_array_fields(&model, &Config::default()).unwrap();
+        let to_array_result = generator.generate_to_array_fields(&model, &Config::default()).unwrap();
 
         assert!(constructor_result.contains("public ?string $deleted_at"));
         assert!(from_array_result.contains("$data['deleted_at']"));
@@ -478,11 +584,11 @@ mod tests {
     fn test_no_timestamps() {
         let generator = DtoGenerator;
         let mut model = create_test_model();
-        model.timestamps = false;
+        model.timestamps = Some(false);
 
-        let constructor_result = generator.generate_constructor_fields(&model).unwrap();
-        let from_array_result = generator.generate_from_array_fields(&model).unwrap();
-        let to_array_result = generator.generate_to_array_fields(&model).unwrap();
+        let constructor_result = generator.generate_constructor_fields(&model, &Config::default()).unwrap();
+        let from_array_result = generator.generate_from_array_fields(&model, &Config::default()).unwrap();
+        let to_array_result = generator.generate_to_array_fields(&model, &Config::default()).unwrap();
 
         assert!(!constructor_result.contains("created_at"));
         assert!(!constructor_result.contains("updated_at"));
@@ -557,7 +663,7 @@ mod tests {
         let generator = DtoGenerator;
         let mut model = create_test_model();
         model.fields = vec![]; // No fields
-        model.timestamps = false; // No timestamps
+        model.timestamps = Some(false); // No timestamps
         let config = create_test_config(false);
 
         let result = generator.generate(&model, &config);
@@ -0,0 +1,135 @@
+use crate::error::Result;
+use crate::generators::shared::{ClassNameResolver, NameHelper, NamespaceResolver, PathResolver, GeneratedMarker};
+use crate::types::{Config, Field, FieldType, ModelDefinition};
+
+/// Generator for PHP backed enums
+///
+/// Every enum field with declared values gets a `{Model}{Field}` string-backed
+/// enum (e.g. `UserStatus`), which the model then uses as the field's cast.
+pub struct EnumGenerator;
+
+impl EnumGenerator {
+    /// Fields of a model that get an enum class
+    pub fn enum_fields(model: &ModelDefinition) -> impl Iterator<Item = &Field> {
+        model.fields.iter()
+            .filter(|field| matches!(field.field_type, FieldType::Enum) && !field.enum_values.is_empty())
+    }
+
+    /// Renders the backed enum for one enum field
+    pub fn generate_enum(&self, model: &ModelDefinition, field: &Field, config: &Config) -> Result<String> {
+        let mut content = String::new();
+
+        content.push_str(&GeneratedMarker::php_header(config));
+        content.push_str(&format!("namespace {};\n\n", NamespaceResolver::get_enum_namespace(model, config)));
+
+        content.push_str(&format!("enum {}: string\n{{\n", ClassNameResolver::enum_class(model, field)));
+        for enum_value in &field.enum_values {
+            content.push_str(&format!(
+                "    case {} = '{}';\n",
+                Self::case_name(&enum_value.value),
+                enum_value.value.replace('\'', "\\'")
+            ));
+        }
+        if field.enum_values.iter().any(|enum_value| enum_value.label.is_some()) {
+            content.push_str(&Self::label_methods(field));
+        }
+        content.push_str("}\n");
+
+        Ok(content)
+    }
+
+    pub fn get_enum_file_path(&self, model: &ModelDefinition, field: &Field, config: &Config) -> String {
+        PathResolver::get_enum_path(model, &ClassNameResolver::enum_class(model, field), config)
+    }
+
+    /// `label()` and `options()` for select inputs; values without a label show the value itself
+    fn label_methods(field: &Field) -> String {
+        let mut methods = String::from("\n    public function label(): string\n    {\n        return match ($this) {\n");
+        for enum_value in &field.enum_values {
+            let label = enum_value.label.as_deref().unwrap_or(&enum_value.value);
+            methods.push_str(&format!(
+                "            self::{} => '{}',\n",
+                Self::case_name(&enum_value.value),
+                label.replace('\'', "\\'")
+            ));
+        }
+        methods.push_str("        };\n    }\n\n");
+        methods.push_str("    /** @return array<string, string> */\n");
+        methods.push_str("    public static function options(): array\n    {\n");
+        methods.push_str("        return array_combine(\n");
+        methods.push_str("            array_column(self::cases(), 'value'),\n");
+        methods.push_str("            array_map(fn (self $case) => $case->label(), self::cases()),\n");
+        methods.push_str("        );\n    }\n");
+        methods
+    }
+
+    /// PHP case name for a stored value (`in_progress` -> `InProgress`)
+    fn case_name(value: &str) -> String {
+        let words: String = value.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let name = NameHelper::studly_case(&words);
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("Value{}", name)
+        } else {
+            name
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EnumValue;
+    use std::path::Path;
+
+    fn create_status_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            fields: vec![Field {
+                enum_values: ["active", "in-review", "2fa_pending"].iter()
+                    .map(|value| EnumValue { value: value.to_string(), label: None })
+                    .collect(),
+                ..Field::new("status", FieldType::Enum)
+            }],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_backed_enum_cases() {
+        let model = create_status_model();
+        let config = Config::default();
+        let result = EnumGenerator.generate_enum(&model, &model.fields[0], &config).unwrap();
+
+        assert!(result.contains("namespace App\\Enums;"));
+        assert!(result.contains("enum UserStatus: string\n{\n"));
+        assert!(result.contains("    case Active = 'active';\n"));
+        assert!(result.contains("    case InReview = 'in-review';\n"));
+        assert!(result.contains("    case Value2faPending = '2fa_pending';\n"));
+        assert_eq!(Path::new(&EnumGenerator.get_enum_file_path(&model, &model.fields[0], &config)), Path::new("./app/Enums/UserStatus.php"));
+    }
+
+    #[test]
+    fn test_label_and_options_methods() {
+        let mut model = create_status_model();
+        model.fields[0].enum_values[0].label = Some("Active".to_string());
+        model.fields[0].enum_values[1].label = Some("Owner's review".to_string());
+        let result = EnumGenerator.generate_enum(&model, &model.fields[0], &Config::default()).unwrap();
+
+        assert!(result.contains("    public function label(): string\n    {\n        return match ($this) {\n"));
+        assert!(result.contains("            self::Active => 'Active',\n"));
+        assert!(result.contains("            self::InReview => 'Owner\\'s review',\n"));
+        assert!(result.contains("            self::Value2faPending => '2fa_pending',\n"));
+        assert!(result.contains("    public static function options(): array\n"));
+        assert!(result.contains("array_map(fn (self $case) => $case->label(), self::cases())"));
+
+        let unlabeled = create_status_model();
+        let result = EnumGenerator.generate_enum(&unlabeled, &unlabeled.fields[0], &Config::default()).unwrap();
+        assert!(!result.contains("label()"));
+        assert!(!result.contains("options()"));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::generators::Generator;
-use crate::generators::shared::{PathResolver, NamespaceResolver};
-use crate::types::{Config, ModelDefinition, FieldType};
+use crate::generators::shared::{PathResolver, NamespaceResolver, ClassNameResolver, GeneratedMarker};
+use crate::types::{Config, DecimalPrecision, ModelDefinition, FieldType};
 
 pub struct FactoryGenerator;
 
@@ -9,28 +9,53 @@ impl Generator for FactoryGenerator {
     fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
         let mut content = String::new();
 
-        content.push_str("<?php\n\n");
+        content.push_str(&GeneratedMarker::php_header(config));
         let namespace = NamespaceResolver::get_factory_namespace(model, config);
         content.push_str(&format!("namespace {};\n\n", namespace));
         content.push_str("use Illuminate\\Database\\Eloquent\\Factories\\Factory;\n");
+        if model.fields.iter().any(|f| Self::is_spatial(&f.field_type)) {
+            content.push_str("use Illuminate\\Support\\Facades\\DB;\n");
+        }
+        if model.fields.iter().any(|f| f.hashed) {
+            content.push_str("use Illuminate\\Support\\Facades\\Hash;\n");
+        }
         let model_namespace = NamespaceResolver::get_model_namespace(model, config);
         content.push_str(&format!("use {}\\{};\n\n", model_namespace, model.name));
 
-        content.push_str(&format!("class {}Factory extends Factory\n{{\n", model.name));
+        content.push_str(&format!("class {} extends Factory\n{{\n", ClassNameResolver::factory_class(model)));
         content.push_str(&format!("    protected $model = {}::class;\n\n", model.name));
 
         content.push_str("    public function definition(): array\n    {\n");
         content.push_str("        return [\n");
 
         for field in &model.fields {
-            if field.name != "id" {
-                let faker_method = self.get_faker_method(&field.field_type, &field.name);
+            // HasUuids fills in the model's UUID key when the record is created
+            let trait_key = model.has_uuids_key(config).is_some_and(|key| key.name == field.name);
+            if field.name != "id" && !trait_key {
+                // Hashed columns get a known password so tests can log in as the record;
+                // encrypted columns keep a plain value that the model's cast encrypts on save
+                let faker_method = if field.hashed {
+                    "Hash::make('password')".to_string()
+                } else if let Some(precision) = &field.decimal_precision
+                    && matches!(field.field_type, FieldType::Decimal | FieldType::Float) {
+                    Self::decimal_faker(precision)
+                } else {
+                    self.get_faker_method(&field.field_type, &field.name)
+                };
                 content.push_str(&format!("            '{}' => {},\n", field.name, faker_method));
             }
         }
 
         content.push_str("        ];\n");
         content.push_str("    }\n");
+
+        // State for soft-deleted records
+        if model.has_soft_deletes(config) {
+            content.push_str("\n    public function trashed(): static\n    {\n");
+            content.push_str("        return $this->state(fn () => ['deleted_at' => now()]);\n");
+            content.push_str("    }\n");
+        }
+
         content.push_str("}\n");
 
         Ok(content)
@@ -43,6 +68,11 @@ impl Generator for FactoryGenerator {
 
 impl FactoryGenerator {
     fn get_faker_method(&self, field_type: &FieldType, field_name: &str) -> String {
+        // Spatial columns need a geometry value whatever the field is called
+        if Self::is_spatial(field_type) {
+            return self.get_spatial_faker(field_type);
+        }
+
         // Try to infer from field name first
         match field_name {
             name if name.contains("email") => "fake()->email()".to_string(),
@@ -75,8 +105,163 @@ impl FactoryGenerator {
                     FieldType::MediumText => "fake()->text(500)".to_string(),
                     FieldType::Binary => "fake()->sha256()".to_string(),
                     FieldType::Inet => "fake()->ipv4()".to_string(),
+                    FieldType::Point | FieldType::Geometry | FieldType::Polygon => self.get_spatial_faker(field_type),
+                    FieldType::Year => "fake()->year()".to_string(),
+                    FieldType::Time => "fake()->time()".to_string(),
                 }
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Random value that fits the column: decimal(5,2) -> `fake()->randomFloat(2, 0, 999.99)`
+    fn decimal_faker(precision: &DecimalPrecision) -> String {
+        let scale = precision.scale as usize;
+        let integer_digits = precision.precision.saturating_sub(precision.scale) as usize;
+        let integer_part = if integer_digits == 0 { "0".to_string() } else { "9".repeat(integer_digits) };
+        let max = if scale == 0 { integer_part } else { format!("{}.{}", integer_part, "9".repeat(scale)) };
+        format!("fake()->randomFloat({}, 0, {})", scale, max)
+    }
+
+    /// Builds the geometry from WKT so the value works on both MySQL and PostGIS
+    fn get_spatial_faker(&self, field_type: &FieldType) -> String {
+        match field_type {
+            FieldType::Polygon => "DB::raw(\"ST_GeomFromText('POLYGON((0 0, 0 1, 1 1, 1 0, 0 0))')\")".to_string(),
+            _ => "DB::raw(sprintf(\"ST_GeomFromText('POINT(%F %F)')\", fake()->longitude(), fake()->latitude()))".to_string(),
+        }
+    }
+
+    fn is_spatial(field_type: &FieldType) -> bool {
+        matches!(field_type, FieldType::Point | FieldType::Geometry | FieldType::Polygon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::model_generator::ModelGenerator;
+    use crate::types::Field;
+
+    fn create_test_model(soft_deletes: bool) -> ModelDefinition {
+        ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(soft_deletes),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_trashed_state_with_soft_deletes() {
+        let result = FactoryGenerator.generate(&create_test_model(true), &Config::default()).unwrap();
+
+        assert!(result.contains("    public function trashed(): static\n"));
+        assert!(result.contains("return $this->state(fn () => ['deleted_at' => now()]);"));
+    }
+
+    #[test]
+    fn test_no_trashed_state_without_soft_deletes() {
+        let result = FactoryGenerator.generate(&create_test_model(false), &Config::default()).unwrap();
+
+        assert!(!result.contains("trashed()"));
+    }
+
+    #[test]
+    fn test_point_field_uses_coordinates() {
+        let model = ModelDefinition {
+            fields: vec![Field::new("address_location", FieldType::Point)],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("use Illuminate\\Support\\Facades\\DB;\n"));
+        assert!(result.contains("'address_location' => DB::raw(sprintf(\"ST_GeomFromText('POINT(%F %F)')\", fake()->longitude(), fake()->latitude())),"));
+    }
+
+    #[test]
+    fn test_year_and_time_fakers() {
+        let model = ModelDefinition {
+            fields: vec![Field::new("founded", FieldType::Year), Field::new("opens_at", FieldType::Time)],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'founded' => fake()->year(),"));
+        assert!(result.contains("'opens_at' => fake()->time(),"));
+    }
+
+    #[test]
+    fn test_decimal_faker_respects_precision() {
+        let decimal = |name: &str, precision: u8, scale: u8| Field {
+            decimal_precision: Some(DecimalPrecision { precision, scale }),
+            ..Field::new(name, FieldType::Decimal)
+        };
+        let model = ModelDefinition {
+            fields: vec![decimal("price", 5, 2), decimal("rate", 4, 4), decimal("units", 3, 0), Field::new("weight", FieldType::Decimal)],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'price' => fake()->randomFloat(2, 0, 999.99),"));
+        assert!(result.contains("'rate' => fake()->randomFloat(4, 0, 0.9999),"));
+        assert!(result.contains("'units' => fake()->randomFloat(0, 0, 999),"));
+        assert!(result.contains("'weight' => fake()->randomFloat(2, 0, 1000),"));
+    }
+
+    #[test]
+    fn test_encrypted_field_is_not_encrypted_twice() {
+        let model = ModelDefinition {
+            fields: vec![Field { encrypted: true, ..Field::new("ssn", FieldType::String) }],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'ssn' => fake()->"));
+        assert!(!result.contains("Crypt"));
+    }
+
+    #[test]
+    fn test_hashed_field_uses_hash_make() {
+        let model = ModelDefinition {
+            fields: vec![Field { hashed: true, ..Field::new("secret", FieldType::String) }, Field::new("password_hint", FieldType::String)],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("use Illuminate\\Support\\Facades\\Hash;\n"));
+        assert!(result.contains("'secret' => Hash::make('password'),"));
+        assert!(result.contains("'password_hint' => fake()->password(),"));
+    }
+
+    #[test]
+    fn test_uuid_key_left_to_has_uuids() {
+        let key = Field { primary: true, ..Field::new("uuid", FieldType::Uuid) };
+        let model = ModelDefinition {
+            fields: vec![key, Field::new("title", FieldType::String)],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(!result.contains("'uuid' =>"));
+        assert!(result.contains("'title' => fake()->sentence(3),"));
+
+        // HasUuids fills the model's key column, which the model names
+        let generated_model = ModelGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(generated_model.contains("    use HasFactory, HasUuids;\n"));
+        assert!(generated_model.contains("    protected $primaryKey = 'uuid';\n"));
+
+        // A UUID column that isn't the key is not filled in by the trait
+        let model = ModelDefinition {
+            fields: vec![Field::new("uuid", FieldType::Uuid)],
+            ..create_test_model(false)
+        };
+        let result = FactoryGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(result.contains("'uuid' => fake()->uuid(),"));
+
+        let config = Config {
+            has_uuids: false,
+            ..Config::default()
+        };
+        let result = FactoryGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("'uuid' => fake()->uuid(),"));
+    }
+}
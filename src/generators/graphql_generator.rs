@@ -0,0 +1,201 @@
+use crate::error::Result;
+use crate::generators::Generator;
+use crate::generators::shared::{GeneratedMarker, PathResolver, RelationshipHelper};
+use crate::types::{Config, Field, FieldType, ModelDefinition, Relationship};
+
+/// Generator for GraphQL SDL object types (Lighthouse-compatible)
+///
+/// Each model becomes a `type` definition; all types are written to a single
+/// combined `graphql/schema.graphql` file.
+pub struct GraphQlGenerator;
+
+impl Generator for GraphQlGenerator {
+    fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let mut content = String::new();
+
+        content.push_str(&format!("type {} {{\n", model.name));
+
+        // Always include ID
+        content.push_str("    id: ID!\n");
+
+        for field in &model.fields {
+            if field.name != "id" {
+                content.push_str(&format!("    {}: {}\n", field.name, self.field_type_for(field)));
+            }
+        }
+
+        // Include timestamps if enabled
+        if model.has_timestamps(config) {
+            content.push_str("    created_at: DateTime!\n");
+            content.push_str("    updated_at: DateTime!\n");
+        }
+
+        // Include soft delete timestamp if enabled
+        if model.has_soft_deletes(config) {
+            content.push_str("    deleted_at: DateTime\n");
+        }
+
+        for relationship in &model.relationships {
+            if let Some(line) = self.build_relationship_field(relationship) {
+                content.push_str(&line);
+            }
+        }
+
+        content.push_str("}\n");
+        Ok(content)
+    }
+
+    fn get_file_path(&self, _model: &ModelDefinition, config: &Config) -> String {
+        PathResolver::get_graphql_schema_path(config)
+    }
+}
+
+impl GraphQlGenerator {
+    /// Renders every model into one combined schema, declaring the custom scalars it uses
+    pub fn generate_schema(&self, models: &[ModelDefinition], config: &Config) -> Result<String> {
+        let mut types = Vec::new();
+        for model in models {
+            types.push(self.generate(model, config)?);
+        }
+
+        let used_scalars: Vec<&str> = models.iter()
+            .flat_map(|model| {
+                let timestamps = (model.has_timestamps(config) || model.has_soft_deletes(config)).then_some("DateTime");
+                model.fields.iter()
+                    .map(|field| Self::to_graphql_scalar(&field.field_type))
+                    .chain(timestamps)
+            })
+            .collect();
+
+        let mut content = GeneratedMarker::comment("#", config);
+        let mut scalars = String::new();
+        for (scalar, class) in [
+            ("Date", "Nuwave\\\\Lighthouse\\\\Schema\\\\Types\\\\Scalars\\\\Date"),
+            ("DateTime", "Nuwave\\\\Lighthouse\\\\Schema\\\\Types\\\\Scalars\\\\DateTime"),
+            ("JSON", "MLL\\\\GraphQLScalars\\\\JSON"),
+        ] {
+            if used_scalars.contains(&scalar) {
+                scalars.push_str(&format!("scalar {} @scalar(class: \"{}\")\n", scalar, class));
+            }
+        }
+        if !scalars.is_empty() {
+            content.push_str(&scalars);
+            content.push('\n');
+        }
+
+        content.push_str(&types.join("\n"));
+        Ok(content)
+    }
+
+    /// Maps a field to its GraphQL type, appending `!` for non-nullable fields
+    fn field_type_for(&self, field: &Field) -> String {
+        let scalar = Self::to_graphql_scalar(&field.field_type);
+        if field.nullable {
+            scalar.to_string()
+        } else {
+            format!("{}!", scalar)
+        }
+    }
+
+    /// Convert field type to GraphQL scalar
+    pub fn to_graphql_scalar(field_type: &FieldType) -> &'static str {
+        match field_type {
+            FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger |
+            FieldType::SmallInteger | FieldType::MediumInteger | FieldType::Year => "Int",
+            FieldType::Float | FieldType::Decimal => "Float",
+            FieldType::Boolean => "Boolean",
+            FieldType::Date => "Date",
+            FieldType::DateTime | FieldType::Timestamp => "DateTime",
+            FieldType::Json => "JSON",
+            FieldType::String | FieldType::Text | FieldType::LongText | FieldType::MediumText |
+            FieldType::Uuid | FieldType::Enum | FieldType::Binary | FieldType::Inet |
+            FieldType::Point | FieldType::Geometry | FieldType::Polygon | FieldType::Time => "String",
+        }
+    }
+
+    fn build_relationship_field(&self, relationship: &Relationship) -> Option<String> {
+        let name = RelationshipHelper::method_name(relationship);
+
+        let (field_type, directive) = match relationship {
+            Relationship::BelongsTo(rel) => (rel.model.clone(), "belongsTo"),
+            Relationship::HasOne(rel) => (rel.model.clone(), "hasOne"),
+            Relationship::HasMany(rel) => (format!("[{}!]!", rel.model), "hasMany"),
+            Relationship::BelongsToMany(rel) => (format!("[{}!]!", rel.model), "belongsToMany"),
+            Relationship::MorphOne(rel) => (rel.model.clone(), "morphOne"),
+            Relationship::MorphMany(rel) => (format!("[{}!]!", rel.model), "morphMany"),
+            Relationship::MorphToMany(rel) => (format!("[{}!]!", rel.model), "morphToMany"),
+            // morphedByMany returns a MorphToMany relation, which @morphToMany resolves
+            Relationship::MorphedByMany(rel) => (format!("[{}!]!", rel.model), "morphToMany"),
+            // The target type of a morphTo is only known at runtime and needs a union
+            Relationship::MorphTo(_) => return None,
+        };
+
+        Some(format!("    {}: {} @{}\n", name, field_type, directive))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::StandardRelationship;
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            fields: vec![
+                Field::new("id", FieldType::BigInteger),
+                Field::new("name", FieldType::String),
+                Field { nullable: true, ..Field::new("age", FieldType::Integer) },
+            ],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            relationships: vec![Relationship::HasMany(StandardRelationship {
+                model: "Post".to_string(),
+                foreign_key: None,
+                local_key: None,
+                owner_key: None,
+                pivot_table: None,
+                pivot_fields: vec![],
+                on_delete: None,
+                on_update: None,
+                with_timestamps: false,
+                nullable: false,
+                method_name: None,
+            })],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scalar_mapping() {
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::String), "String");
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::BigInteger), "Int");
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::Decimal), "Float");
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::Boolean), "Boolean");
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::Timestamp), "DateTime");
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::Json), "JSON");
+        assert_eq!(GraphQlGenerator::to_graphql_scalar(&FieldType::Uuid), "String");
+    }
+
+    #[test]
+    fn test_nullable_and_non_null_fields() {
+        let result = GraphQlGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("type User {"));
+        assert!(result.contains("    id: ID!\n"));
+        assert!(result.contains("    name: String!\n"));
+        assert!(result.contains("    age: Int\n"));
+        assert!(result.contains("    posts: [Post!]! @hasMany\n"));
+    }
+
+    #[test]
+    fn test_combined_schema_declares_used_scalars() {
+        let result = GraphQlGenerator.generate_schema(&[create_test_model()], &Config::default()).unwrap();
+
+        assert!(result.starts_with("# @generated by schemly v"));
+        assert!(result.contains("\n\nscalar DateTime"));
+        assert!(!result.contains("scalar JSON"));
+        assert!(result.contains("type User {"));
+    }
+}
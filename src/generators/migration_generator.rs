@@ -1,18 +1,23 @@
 #![allow(clippy::collapsible_if)]
 use crate::error::Result;
 use crate::generators::Generator;
-use crate::types::{Config, ModelDefinition, Relationship};
-use chrono::Utc;
+use crate::generators::shared::{ColumnModifiers, GeneratedMarker, PathResolver, RelationshipHelper};
+use crate::types::{Config, MigrationMode, ModelDefinition, Relationship};
 
 pub struct MigrationGenerator;
 
 // Include the template file at compile time
 const MIGRATION_TEMPLATE: &str = include_str!("../templates/migration.php.template");
+const ALTER_MIGRATION_TEMPLATE: &str = include_str!("../templates/migration_alter.php.template");
 
 impl Generator for MigrationGenerator {
-    fn generate(&self, model: &ModelDefinition, _config: &Config) -> Result<String> {
+    fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        if config.migration_mode == MigrationMode::Alter {
+            return self.generate_alter(model, config);
+        }
+
         // Prepare the template data
-        let table_name = &model.table;
+        let table_name = &model.table_name(config);
 
         // Handle ID field
         let has_custom_primary = model.fields.iter().any(|f| f.primary);
@@ -21,24 +26,54 @@ impl Generator for MigrationGenerator {
         } else {
             "$table->id();\n".to_string()
         };
+        let id_field = format!("{}{}", self.build_table_options(config), id_field);
+
+        // belongsTo foreign-key columns get an index unless already indexed
+        let foreign_key_columns: Vec<&str> = model.relationships.iter()
+            .filter_map(|relationship| match relationship {
+                Relationship::BelongsTo(rel) => rel.foreign_key.as_deref(),
+                _ => None,
+            })
+            .collect();
 
         // Generate field definitions
         let mut fields = String::new();
+        let mut check_constraints = String::new();
+        let primary_key = model.primary_key().map(|key| key.name.as_str());
         for field in &model.fields {
             if field.name != "id" || field.primary {
-                fields.push_str(&self.build_field_definition(field));
+                let auto_index = config.auto_index_foreign_keys
+                    && foreign_key_columns.contains(&field.name.as_str());
+                let primary = primary_key == Some(field.name.as_str());
+                fields.push_str(&self.build_field_definition(table_name, field, primary, auto_index, config));
+                if self.is_enum_fallback(field, config) {
+                    check_constraints.push_str(&self.build_enum_check(table_name, field, config));
+                }
+            }
+        }
+
+        // Polymorphic `{name}_id` / `{name}_type` columns, unless declared as fields
+        for relationship in &model.relationships {
+            if let Relationship::MorphTo(rel) = relationship {
+                let declared = model.fields.iter().any(|f| {
+                    f.name == format!("{}_id", rel.morph_name) || f.name == format!("{}_type", rel.morph_name)
+                });
+                if !declared {
+                    let method = if rel.optional { "nullableMorphs" } else { "morphs" };
+                    fields.push_str(&format!("            $table->{}('{}');\n", method, rel.morph_name));
+                }
             }
         }
 
         // Handle timestamps
-        let timestamps = if model.timestamps {
+        let timestamps = if model.has_timestamps(config) {
             "$table->timestamps();".to_string()
         } else {
             "".to_string()
         };
 
         // Handle soft deletes
-        let soft_deletes = if model.soft_deletes {
+        let soft_deletes = if model.has_soft_deletes(config) {
             "$table->softDeletes();".to_string()
         } else {
             "".to_string()
@@ -46,12 +81,21 @@ impl Generator for MigrationGenerator {
 
         // Generate compound indexes and uniques
         let mut compound_indexes = String::new();
+        if let Some(columns) = model.composite_primary_key() {
+            let fields_str = columns.iter()
+                .map(|f| format!("'{}'", f))
+                .collect::<Vec<_>>()
+                .join(", ");
+            compound_indexes.push_str(&format!("$table->primary([{}]);\n            ", fields_str));
+        }
+
         for index_fields in &model.compound_indexes {
             let fields_str = index_fields.iter()
                 .map(|f| format!("'{}'", f))
                 .collect::<Vec<_>>()
                 .join(", ");
-            compound_indexes.push_str(&format!("$table->index([{}]);\n            ", fields_str));
+            let name = self.named_constraint_argument(table_name, index_fields, "index", config);
+            compound_indexes.push_str(&format!("$table->index([{}]{});\n            ", fields_str, name));
         }
         
         for unique_fields in &model.compound_uniques {
@@ -59,7 +103,27 @@ impl Generator for MigrationGenerator {
                 .map(|f| format!("'{}'", f))
                 .collect::<Vec<_>>()
                 .join(", ");
-            compound_indexes.push_str(&format!("$table->unique([{}]);\n            ", fields_str));
+            let name = self.named_constraint_argument(table_name, unique_fields, "unique", config);
+            compound_indexes.push_str(&format!("$table->unique([{}]{});\n            ", fields_str, name));
+        }
+
+        for fulltext_fields in &model.fulltext_indexes {
+            let fields_str = fulltext_fields.iter()
+                .map(|f| format!("'{}'", f))
+                .collect::<Vec<_>>()
+                .join(", ");
+            if Self::supports_fulltext(config) {
+                compound_indexes.push_str(&format!("$table->fullText([{}]);\n            ", fields_str));
+            } else {
+                log::warn!(
+                    "Skipping fulltext index [{}] on '{}': database engine '{}' has no fulltext indexes",
+                    fulltext_fields.join(", "), table_name, config.database_engine
+                );
+                compound_indexes.push_str(&format!(
+                    "// fullText([{}]) skipped: not supported by {}\n            ",
+                    fields_str, config.database_engine
+                ));
+            }
         }
 
         // Generate foreign key constraints
@@ -67,20 +131,49 @@ impl Generator for MigrationGenerator {
         for relationship in &model.relationships {
             if let Relationship::BelongsTo(rel) = relationship {
                 if let Some(foreign_key) = &rel.foreign_key {
-                    let referenced_table = self.model_name_to_table(&rel.model);
-                    let on_delete = rel.on_delete.as_deref().unwrap_or("restrict");
+                    let referenced_table = RelationshipHelper::related_table(&rel.model, config);
+                    // An optional parent is detached rather than blocking the delete
+                    let default_on_delete = if rel.nullable { "set null" } else { "restrict" };
+                    let on_delete = rel.on_delete.as_deref().unwrap_or(default_on_delete);
                     let on_update = rel.on_update.as_deref().unwrap_or("restrict");
 
-                    foreign_keys.push_str(&format!("Schema::table('{}', function (Blueprint $table) {{\n", model.table));
-                    foreign_keys.push_str(&format!("    $table->foreign('{}')->references('id')->on('{}')->onDelete('{}')->onUpdate('{}');\n",
-                                                foreign_key, referenced_table, on_delete, on_update));
+                    let owner_key = rel.owner_key.as_deref().unwrap_or("id");
+
+                    // Create the missing column together with its constraint
+                    if config.auto_foreign_ids && !model.fields.iter().any(|f| &f.name == foreign_key) {
+                        let nullable = if rel.nullable { "->nullable()" } else { "" };
+                        let constrained = if owner_key == "id" {
+                            format!("'{}'", referenced_table)
+                        } else {
+                            format!("'{}', '{}'", referenced_table, owner_key)
+                        };
+                        fields.push_str(&format!("            $table->foreignId('{}'){}->constrained({})->{}->{};\n",
+                                                 foreign_key, nullable, constrained,
+                                                 self.fluent_action(on_delete, "Delete"), self.fluent_action(on_update, "Update")));
+                        continue;
+                    }
+
+                    foreign_keys.push_str(&format!("Schema::table('{}', function (Blueprint $table) {{\n", table_name));
+                    foreign_keys.push_str(&format!("    $table->foreign('{}')->references('{}')->on('{}')->onDelete('{}')->onUpdate('{}');\n",
+                                                foreign_key, owner_key, referenced_table, on_delete, on_update));
                     foreign_keys.push_str("});\n\n");
                 }
             }
         }
 
+        // Check constraints run through the DB facade, imported only when needed
+        let mut template = MIGRATION_TEMPLATE.to_string();
+        if check_constraints.contains("DB::statement") {
+            template = template.replace(
+                "use Illuminate\\Support\\Facades\\Schema;",
+                "use Illuminate\\Support\\Facades\\DB;\nuse Illuminate\\Support\\Facades\\Schema;",
+            );
+        }
+        let foreign_keys = format!("{}{}", check_constraints, foreign_keys);
+
         // Replace placeholders in the template
-        let content = MIGRATION_TEMPLATE
+        let content = template
+            .replace("{{php_header}}", &GeneratedMarker::php_header(config))
             .replace("{{table_name}}", table_name)
             .replace("{{id_field}}", &id_field)
             .replace("{{fields}}", &fields)
@@ -93,52 +186,151 @@ impl Generator for MigrationGenerator {
     }
 
     fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
-        let timestamp = Utc::now().format("%Y_%m_%d_%H%M%S");
-        format!(
-            "{}/database/migrations/{}_create_{}_table.php",
-            config.output_dir,
-            timestamp,
-            model.table
-        )
+        // Referenced tables get earlier timestamps
+        let sequence = RelationshipHelper::migration_sequence(model, config);
+        if config.migration_mode == MigrationMode::Alter {
+            let columns: Vec<&str> = Self::new_fields(model).map(|field| field.name.as_str()).collect();
+            return PathResolver::get_add_columns_migration_path(&model.table_name(config), &columns, sequence, config);
+        }
+        PathResolver::get_create_migration_path(&model.table_name(config), sequence, config)
     }
 }
 
 impl MigrationGenerator {
-    fn model_name_to_table(&self, model_name: &str) -> String {
-        let snake_case = self.pascal_to_snake_case(model_name);
-        self.pluralize(&snake_case)
-    }
-
-    fn pascal_to_snake_case(&self, input: &str) -> String {
-        input
-            .chars()
-            .enumerate()
-            .map(|(i, c)| {
-                if i > 0 && c.is_uppercase() {
-                    format!("_{}", c.to_lowercase())
-                } else {
-                    c.to_lowercase().to_string()
-                }
-            })
-            .collect::<String>()
+    /// Fields marked `new`, which an alter migration adds to the existing table
+    pub fn new_fields(model: &ModelDefinition) -> impl Iterator<Item = &crate::types::Field> {
+        model.fields.iter().filter(|field| field.is_new)
     }
 
-    fn pluralize(&self, word: &str) -> String {
-        // Very basic pluralization, good enough for most table names
-        if let Some(stripped) = word.strip_suffix('y') {
-            format!("{}ies", stripped)
-        } else if word.ends_with('s') {
-            format!("{}es", word)
-        } else {
-            format!("{}s", word)
+    /// `Schema::table` migration adding the model's new fields, dropped again on rollback
+    fn generate_alter(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let table_name = &model.table_name(config);
+
+        let mut fields = String::new();
+        let mut check_constraints = String::new();
+        let mut columns = Vec::new();
+        for field in Self::new_fields(model) {
+            fields.push_str(&self.build_field_definition(table_name, field, false, false, config));
+            if self.is_enum_fallback(field, config) {
+                check_constraints.push_str(&format!("\n        {}\n", self.build_enum_check(table_name, field, config).trim_end()));
+            }
+            columns.push(format!("'{}'", field.name));
         }
+        if columns.is_empty() {
+            return Err(crate::error::GeneratorError::ModelValidation(
+                format!("Model '{}' has no fields marked new to add in an alter migration", model.name)
+            ));
+        }
+
+        let mut template = ALTER_MIGRATION_TEMPLATE.to_string();
+        if check_constraints.contains("DB::statement") {
+            template = template.replace(
+                "use Illuminate\\Support\\Facades\\Schema;",
+                "use Illuminate\\Support\\Facades\\DB;\nuse Illuminate\\Support\\Facades\\Schema;",
+            );
+        }
+
+        Ok(template
+            .replace("{{php_header}}", &GeneratedMarker::php_header(config))
+            .replace("{{table_name}}", table_name)
+            .replace("{{fields}}", &fields)
+            .replace("{{check_constraints}}", &check_constraints)
+            .replace("{{columns}}", &columns.join(", ")))
+    }
+
+    /// Laravel only builds fulltext indexes on MySQL/MariaDB and PostgreSQL
+    fn supports_fulltext(config: &Config) -> bool {
+        matches!(
+            config.database_engine.to_lowercase().as_str(),
+            "mysql" | "mariadb" | "pgsql" | "postgres" | "postgresql"
+        )
+    }
+
+    /// Postgres and SQLite have no inline enum columns, so enums become strings there
+    fn supports_native_enums(config: &Config) -> bool {
+        !matches!(
+            config.database_engine.to_lowercase().as_str(),
+            "pgsql" | "postgres" | "postgresql" | "sqlite"
+        )
+    }
+
+    /// Whether an enum field is stored as a string column constrained to its values
+    fn is_enum_fallback(&self, field: &crate::types::Field, config: &Config) -> bool {
+        matches!(field.field_type, crate::types::FieldType::Enum)
+            && !field.enum_values.is_empty()
+            && !Self::supports_native_enums(config)
+    }
+
+    /// Check constraint limiting a string-backed enum column to its declared values
+    ///
+    /// Postgres gets an `ALTER TABLE ... ADD CONSTRAINT` statement; SQLite can't add
+    /// constraints to an existing table, so only a comment is left there.
+    fn build_enum_check(&self, table_name: &str, field: &crate::types::Field, config: &Config) -> String {
+        if config.database_engine.eq_ignore_ascii_case("sqlite") {
+            log::warn!(
+                "Enum field '{}' on '{}' is a plain string column: sqlite can't add check constraints to an existing table",
+                field.name, table_name
+            );
+            return format!(
+                "// {}: check constraint skipped, sqlite can't add constraints to an existing table\n\n",
+                field.name
+            );
+        }
+
+        let values = field.enum_values.iter()
+            .map(|enum_value| format!("'{}'", enum_value.value.replace('\'', "''")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let statement = format!(
+            "ALTER TABLE {table} ADD CONSTRAINT {table}_{column}_check CHECK ({column} IN ({values}))",
+            table = table_name, column = field.name, values = values
+        );
+        format!(
+            "DB::statement(\"{}\");\n\n",
+            statement.replace('\\', "\\\\").replace('"', "\\\"").replace('$', "\\$")
+        )
     }
 
-    fn build_field_definition(&self, field: &crate::types::Field) -> String {
+    /// MySQL-only table engine, charset and collation assignments
+    fn build_table_options(&self, config: &Config) -> String {
+        if !config.database_engine.eq_ignore_ascii_case("mysql") {
+            return String::new();
+        }
+
+        let mut options = String::new();
+        for (property, value) in [
+            ("engine", &config.table_engine),
+            ("charset", &config.charset),
+            ("collation", &config.collation),
+        ] {
+            if let Some(value) = value {
+                options.push_str(&format!("$table->{} = '{}';\n            ", property, value));
+            }
+        }
+        options
+    }
+
+    /// Maps a referential action to Laravel's fluent helper (`cascade` -> `cascadeOnDelete()`)
+    fn fluent_action(&self, action: &str, event: &str) -> String {
+        match action.to_lowercase().as_str() {
+            "cascade" => format!("cascadeOn{}()", event),
+            "restrict" => format!("restrictOn{}()", event),
+            "set null" => format!("nullOn{}()", event),
+            "no action" => format!("noActionOn{}()", event),
+            _ => format!("on{}('{}')", event, action),
+        }
+    }
+
+    /// `primary` marks the model's single key column; composite keys are declared with `$table->primary([...])`
+    fn build_field_definition(&self, table_name: &str, field: &crate::types::Field, primary: bool, auto_index: bool, config: &Config) -> String {
         let mut definition = String::new();
 
         // Base field type
         let field_method = match field.field_type {
+            // Ciphertext outgrows a string's length and is no longer valid JSON
+            crate::types::FieldType::String | crate::types::FieldType::Json if field.encrypted => {
+                format!("text('{}')", field.name)
+            },
             crate::types::FieldType::String => {
                 if let Some(length) = field.length {
                     format!("string('{}', {})", field.name, length)
@@ -146,30 +338,604 @@ impl MigrationGenerator {
                     format!("string('{}')", field.name)
                 }
             },
+            crate::types::FieldType::Enum if self.is_enum_fallback(field, config) => {
+                format!("string('{}')", field.name)
+            },
+            crate::types::FieldType::Enum if !field.enum_values.is_empty() => {
+                let values = field.enum_values.iter()
+                    .map(|enum_value| format!("'{}'", enum_value.value.replace('\'', "\\'")))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("enum('{}', [{}])", field.name, values)
+            },
             _ => format!("{}('{}')", field.field_type.to_migration_type(), field.name),
         };
 
         definition.push_str(&format!("            $table->{}", field_method));
+        if primary {
+            definition.push_str(if field.auto_increment { "->autoIncrement()" } else { "->primary()" });
+        }
 
-        // Add modifiers
-        if field.nullable {
-            definition.push_str("->nullable()");
+        // A unique constraint already provides an index
+        let index = field.index || (auto_index && !field.unique);
+        if !config.named_indexes {
+            definition.push_str(&ColumnModifiers::chain(field, field.unique, index));
+            definition.push_str(";\n");
+            return definition;
         }
 
+        definition.push_str(&ColumnModifiers::chain(field, false, false));
+        definition.push_str(";\n");
         if field.unique {
-            definition.push_str("->unique()");
+            definition.push_str(&format!("            $table->unique('{}', '{}');\n",
+                                         field.name, Self::constraint_name(table_name, &field.name, "unique")));
         }
+        if index {
+            definition.push_str(&format!("            $table->index('{}', '{}');\n",
+                                         field.name, Self::constraint_name(table_name, &field.name, "index")));
+        }
+        definition
+    }
 
-        if field.index {
-            definition.push_str("->index()");
+    /// `, 'name'` argument of a compound index when `named_indexes` is enabled
+    fn named_constraint_argument(&self, table_name: &str, columns: &[String], kind: &str, config: &Config) -> String {
+        if config.named_indexes {
+            format!(", '{}'", Self::constraint_name(table_name, &columns.join("_"), kind))
+        } else {
+            String::new()
         }
+    }
 
-        if let Some(default_value) = &field.default {
-            definition.push_str(&format!("->default('{}')", default_value));
+    /// Laravel's `{table}_{column}_{type}` index name, shortened to MySQL's 64-character limit
+    ///
+    /// Long names keep a prefix plus a hash of the full name, so two tables whose names only
+    /// differ past the cut still get distinct constraints.
+    fn constraint_name(table_name: &str, column: &str, kind: &str) -> String {
+        const MAX_LENGTH: usize = 64;
+        let name = format!("{}_{}_{}", table_name, column, kind).to_lowercase().replace(['-', '.'], "_");
+        if name.len() <= MAX_LENGTH {
+            return name;
         }
+        // FNV-1a, stable across runs so regenerated migrations keep the same names
+        let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193));
+        format!("{}_{:08x}", &name[..MAX_LENGTH - 9], hash)
+    }
+}
 
-        definition.push_str(";\n");
-        definition
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ColumnDefault, EnumValue, Field, FieldType, PolymorphicRelationship, StandardRelationship};
+    use crate::generators::model_generator::ModelGenerator;
+    use std::path::Path;
+
+    fn create_field(name: &str, index: bool) -> Field {
+        Field { index, ..Field::new(name, FieldType::BigInteger) }
+    }
+
+    fn create_test_model(fields: Vec<Field>) -> ModelDefinition {
+        ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            fields,
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            relationships: vec![Relationship::BelongsTo(StandardRelationship {
+                model: "User".to_string(),
+                foreign_key: Some("user_id".to_string()),
+                local_key: None,
+                owner_key: None,
+                pivot_table: None,
+                pivot_fields: vec![],
+                on_delete: None,
+                on_update: None,
+                with_timestamps: false,
+                nullable: false,
+                method_name: None,
+            })],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_foreign_key_column_indexed_once() {
+        for index in [false, true] {
+            let model = create_test_model(vec![create_field("user_id", index)]);
+            let result = MigrationGenerator.generate(&model, &Config::default()).unwrap();
+
+            assert!(result.contains("$table->bigInteger('user_id')->index();"));
+            assert_eq!(result.matches("->index()").count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_foreign_key_index_disabled() {
+        let config = Config {
+            auto_index_foreign_keys: false,
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![create_field("user_id", false)]), &config).unwrap();
+
+        assert!(!result.contains("->index()"));
+    }
+
+    #[test]
+    fn test_parent_tables_migrate_first() {
+        let post = create_test_model(vec![create_field("user_id", false)]);
+        let user = ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            relationships: vec![],
+            ..create_test_model(vec![])
+        };
+        // Listed child first, as config order alone would get it wrong
+        let config = Config {
+            models: vec![post.clone(), user.clone()],
+            ..Config::default()
+        };
+        let timestamp = |model: &ModelDefinition| {
+            let path = MigrationGenerator.get_file_path(model, &config);
+            let file_name = Path::new(&path).file_name().unwrap().to_str().unwrap().to_string();
+            file_name[..17].to_string()
+        };
+        assert!(timestamp(&user) < timestamp(&post), "{} !< {}", timestamp(&user), timestamp(&post));
+
+        // A loop of foreign keys has no valid order
+        let team = ModelDefinition {
+            name: "Team".to_string(),
+            table: "teams".to_string(),
+            ..create_test_model(vec![create_field("user_id", false)])
+        };
+        let mut user = user;
+        if let Relationship::BelongsTo(rel) = &post.relationships[0] {
+            user.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+                model: "Team".to_string(),
+                foreign_key: Some("team_id".to_string()),
+                ..rel.clone()
+            })];
+        }
+        let result = crate::validation::Validator::validate_migration_order(&[post, user, team]);
+        assert!(result.unwrap_err().to_string().contains("cycle (User -> Team -> User)"));
+    }
+
+    #[test]
+    fn test_named_indexes() {
+        let config = Config {
+            named_indexes: true,
+            has_uuids: true,
+            ..Config::default()
+        };
+        let mut model = create_test_model(vec![
+            Field { field_type: FieldType::String, unique: true, ..create_field("email", false) },
+            create_field("user_id", false),
+        ]);
+        model.compound_uniques = vec![vec!["email".to_string(), "user_id".to_string()]];
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+
+        assert!(result.contains("$table->string('email');\n            $table->unique('email', 'posts_email_unique');"));
+        assert!(result.contains("$table->index('user_id', 'posts_user_id_index');"));
+        assert!(result.contains("$table->unique(['email', 'user_id'], 'posts_email_user_id_unique');"));
+        assert!(!result.contains("->unique()") && !result.contains("->index()"));
+
+        // Over MySQL's limit the name is cut and suffixed with a hash of the full name
+        let long_name = MigrationGenerator::constraint_name("organization_membership_invitations", "invited_by_email_address", "unique");
+        assert_eq!(long_name.len(), 64);
+        assert!(long_name.starts_with("organization_membership_invitations_invited_by_email_a"));
+        assert_ne!(
+            long_name,
+            MigrationGenerator::constraint_name("organization_membership_invitations", "invited_by_email_address_backup", "unique")
+        );
+    }
+
+    fn generate_auto_foreign_id(on_delete: &str) -> String {
+        let mut model = create_test_model(vec![]);
+        if let Relationship::BelongsTo(rel) = &mut model.relationships[0] {
+            rel.on_delete = Some(on_delete.to_string());
+        }
+        let config = Config {
+            auto_foreign_ids: true,
+            ..Config::default()
+        };
+        MigrationGenerator.generate(&model, &config).unwrap()
+    }
+
+    #[test]
+    fn test_auto_foreign_id_cascade_on_delete() {
+        let result = generate_auto_foreign_id("cascade");
+        assert!(result.contains("$table->foreignId('user_id')->constrained('users')->cascadeOnDelete()->restrictOnUpdate();"));
     }
-    
-}
\ No newline at end of file
+
+    #[test]
+    fn test_auto_foreign_id_null_on_delete() {
+        let result = generate_auto_foreign_id("set null");
+        assert!(result.contains("$table->foreignId('user_id')->constrained('users')->nullOnDelete()->restrictOnUpdate();"));
+    }
+
+    #[test]
+    fn test_auto_foreign_id_nullable() {
+        let mut model = create_test_model(vec![]);
+        if let Relationship::BelongsTo(rel) = &mut model.relationships[0] {
+            rel.nullable = true;
+        }
+        let config = Config {
+            auto_foreign_ids: true,
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+
+        assert!(result.contains("$table->foreignId('user_id')->nullable()->constrained('users')->nullOnDelete()->restrictOnUpdate();"));
+        let relationship = ModelGenerator.generate(&model, &config).unwrap();
+        assert!(relationship.contains("return $this->belongsTo(User::class, 'user_id');"));
+    }
+
+    #[test]
+    fn test_foreign_key_references_owner_key() {
+        let mut model = create_test_model(vec![create_field("user_id", false)]);
+        if let Relationship::BelongsTo(rel) = &mut model.relationships[0] {
+            rel.owner_key = Some("uuid".to_string());
+        }
+        let result = MigrationGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(result.contains("$table->foreign('user_id')->references('uuid')->on('users')"));
+
+        model.fields.clear();
+        let config = Config {
+            auto_foreign_ids: true,
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("$table->foreignId('user_id')->constrained('users', 'uuid')->restrictOnDelete()->restrictOnUpdate();"));
+
+        let relationship = ModelGenerator.generate(&model, &config).unwrap();
+        assert!(relationship.contains("return $this->belongsTo(User::class, 'user_id', 'uuid');"));
+    }
+
+    #[test]
+    fn test_table_prefix_and_suffix() {
+        let mut model = create_test_model(vec![create_field("user_id", false)]);
+        let mut config = Config {
+            table_prefix: "tenant_".to_string(),
+            table_suffix: "_v2".to_string(),
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("Schema::create('tenant_posts_v2', function (Blueprint $table) {"));
+        assert!(result.contains("Schema::dropIfExists('tenant_posts_v2');"));
+        assert!(result.contains("Schema::table('tenant_posts_v2', function (Blueprint $table) {"));
+        assert!(result.contains("$table->foreign('user_id')->references('id')->on('tenant_users_v2')"));
+        assert!(MigrationGenerator.get_file_path(&model, &config).ends_with("_create_tenant_posts_v2_table.php"));
+
+        let generated_model = ModelGenerator.generate(&model, &config).unwrap();
+        assert!(generated_model.contains("protected $table = 'tenant_posts_v2';"));
+
+        model.fields.clear();
+        config.auto_foreign_ids = true;
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("$table->foreignId('user_id')->constrained('tenant_users_v2')->restrictOnDelete()->restrictOnUpdate();"));
+    }
+
+    #[test]
+    fn test_foreign_key_references_declared_table() {
+        let mut model = create_test_model(vec![create_field("user_id", false)]);
+        let mut config = Config {
+            models: vec![ModelDefinition {
+                name: "User".to_string(),
+                table: "members".to_string(),
+                ..Default::default()
+            }],
+            table_prefix: "tenant_".to_string(),
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("$table->foreign('user_id')->references('id')->on('tenant_members')"));
+
+        model.fields.clear();
+        config.auto_foreign_ids = true;
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("$table->foreignId('user_id')->constrained('tenant_members')->restrictOnDelete()->restrictOnUpdate();"));
+    }
+
+    #[test]
+    fn test_model_inherits_config_timestamps_and_soft_deletes() {
+        let config = Config {
+            default_timestamps: true,
+            default_soft_deletes: true,
+            ..Config::default()
+        };
+        let model = ModelDefinition {
+            timestamps: None,
+            soft_deletes: None,
+            ..create_test_model(vec![])
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+
+        assert!(result.contains("$table->timestamps();"));
+        assert!(result.contains("$table->softDeletes();"));
+    }
+
+    #[test]
+    fn test_model_overrides_config_timestamps() {
+        let config = Config {
+            default_timestamps: true,
+            default_soft_deletes: true,
+            ..Config::default()
+        };
+        let model = ModelDefinition {
+            timestamps: Some(false),
+            soft_deletes: Some(false),
+            ..create_test_model(vec![])
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+
+        assert!(!result.contains("$table->timestamps();"));
+        assert!(!result.contains("$table->softDeletes();"));
+    }
+
+    #[test]
+    fn test_mysql_table_options() {
+        let config = Config {
+            table_engine: Some("InnoDB".to_string()),
+            charset: Some("utf8mb4".to_string()),
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![]), &config).unwrap();
+
+        assert!(result.contains("$table->engine = 'InnoDB';"));
+        assert!(result.contains("$table->charset = 'utf8mb4';"));
+        assert!(!result.contains("$table->collation"));
+
+        let config = Config {
+            database_engine: "pgsql".to_string(),
+            ..config
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![]), &config).unwrap();
+
+        assert!(!result.contains("$table->engine"));
+        assert!(!result.contains("$table->charset"));
+    }
+
+    #[test]
+    fn test_single_custom_primary_key() {
+        let uuid = Field { primary: true, ..Field::new("uuid", FieldType::Uuid) };
+        let result = MigrationGenerator.generate(&create_test_model(vec![uuid]), &Config::default()).unwrap();
+        assert!(result.contains("            $table->uuid('uuid')->primary();\n"));
+        assert!(!result.contains("$table->id();"));
+
+        let id = Field { primary: true, auto_increment: true, ..Field::new("id", FieldType::Integer) };
+        let result = MigrationGenerator.generate(&create_test_model(vec![id]), &Config::default()).unwrap();
+        assert!(result.contains("            $table->integer('id')->autoIncrement();\n"));
+    }
+
+    #[test]
+    fn test_encrypted_columns_are_text() {
+        let ssn = Field { encrypted: true, length: Some(11), ..Field::new("ssn", FieldType::String) };
+        let history = Field { encrypted: true, ..Field::new("medical_history", FieldType::Json) };
+        let result = MigrationGenerator.generate(&create_test_model(vec![ssn, history]), &Config::default()).unwrap();
+
+        assert!(result.contains("            $table->text('ssn');\n"));
+        assert!(result.contains("            $table->text('medical_history');\n"));
+        assert!(!result.contains("string('ssn'"));
+        assert!(!result.contains("json('medical_history')"));
+    }
+
+    #[test]
+    fn test_composite_primary_key() {
+        let mut order_id = create_field("order_id", false);
+        order_id.primary = true;
+        let mut product_id = create_field("product_id", false);
+        product_id.primary = true;
+        let result = MigrationGenerator.generate(&create_test_model(vec![order_id, product_id]), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->primary(['order_id', 'product_id']);"));
+        assert!(!result.contains("$table->id();"));
+    }
+
+    #[test]
+    fn test_column_modifier_order() {
+        let votes = Field {
+            unsigned: true,
+            nullable: true,
+            unique: true,
+            default: Some(ColumnDefault::Value("0".to_string())),
+            comment: Some("Vote count".to_string()),
+            ..create_field("votes", true)
+        };
+        let nickname = Field {
+            field_type: FieldType::String,
+            nullable: true,
+            default: Some(ColumnDefault::Null),
+            ..create_field("nickname", false)
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![votes, nickname]), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->bigInteger('votes')->unsigned()->nullable()->default('0')->unique()->index()->comment('Vote count');"));
+        assert!(result.contains("$table->string('nickname')->nullable()->default(null);"));
+    }
+
+    #[test]
+    fn test_column_modifier_values_escaped() {
+        let motto = Field {
+            field_type: FieldType::String,
+            default: Some(ColumnDefault::Value("it's".to_string())),
+            comment: Some("Path like C:\\temp".to_string()),
+            after: Some("o'clock".to_string()),
+            ..create_field("motto", false)
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![motto]), &Config::default()).unwrap();
+
+        assert!(result.contains(r"$table->string('motto')->default('it\'s')->comment('Path like C:\\temp')->after('o\'clock');"));
+    }
+
+    #[test]
+    fn test_field_positioned_after_another() {
+        let nickname = Field {
+            field_type: FieldType::String,
+            after: Some("user_id".to_string()),
+            ..create_field("nickname", false)
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![create_field("user_id", false), nickname]), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->string('nickname')->after('user_id');"));
+    }
+
+    fn create_status_field() -> Field {
+        Field {
+            field_type: FieldType::Enum,
+            enum_values: ["active", "inactive"].iter()
+                .map(|value| EnumValue { value: value.to_string(), label: None })
+                .collect(),
+            ..create_field("status", false)
+        }
+    }
+
+    #[test]
+    fn test_enum_column_on_mysql() {
+        let result = MigrationGenerator.generate(&create_test_model(vec![create_status_field()]), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->enum('status', ['active', 'inactive']);"));
+        assert!(!result.contains("CHECK"));
+        assert!(!result.contains("use Illuminate\\Support\\Facades\\DB;"));
+    }
+
+    #[test]
+    fn test_enum_falls_back_to_check_constraint_on_pgsql() {
+        let config = Config {
+            database_engine: "pgsql".to_string(),
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![create_status_field()]), &config).unwrap();
+
+        assert!(result.contains("$table->string('status');"));
+        assert!(!result.contains("$table->enum("));
+        assert!(result.contains("use Illuminate\\Support\\Facades\\DB;\nuse Illuminate\\Support\\Facades\\Schema;"));
+        assert!(result.contains(
+            "DB::statement(\"ALTER TABLE posts ADD CONSTRAINT posts_status_check CHECK (status IN ('active', 'inactive'))\");"
+        ));
+    }
+
+    #[test]
+    fn test_enum_on_sqlite_is_a_plain_string() {
+        let config = Config {
+            database_engine: "sqlite".to_string(),
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&create_test_model(vec![create_status_field()]), &config).unwrap();
+
+        assert!(result.contains("$table->string('status');"));
+        assert!(result.contains("// status: check constraint skipped"));
+        assert!(!result.contains("DB::statement"));
+    }
+
+    #[test]
+    fn test_alter_migration_adds_only_new_fields() {
+        let nickname = Field {
+            field_type: FieldType::String,
+            nullable: true,
+            is_new: true,
+            hashed: false,
+            json_cast_as: None,
+            encrypted: false,
+            ..create_field("nickname", false)
+        };
+        let model = create_test_model(vec![create_field("user_id", false), nickname]);
+        let config = Config {
+            migration_mode: MigrationMode::Alter,
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+
+        assert!(result.contains("Schema::table('posts', function (Blueprint $table) {"));
+        assert!(result.contains("$table->string('nickname')->nullable();"));
+        assert!(result.contains("$table->dropColumn(['nickname']);"));
+        assert!(!result.contains("Schema::create"));
+        assert!(!result.contains("user_id"));
+        assert!(MigrationGenerator.get_file_path(&model, &config).ends_with("_add_nickname_to_posts_table.php"));
+
+        // Fallback enums get their check constraint after the column exists
+        let config = Config { database_engine: "pgsql".to_string(), ..config };
+        let status = Field { is_new: true, ..create_status_field() };
+        let model = create_test_model(vec![status, Field { is_new: true, ..create_field("views", false) }]);
+        let result = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("use Illuminate\\Support\\Facades\\DB;"));
+        assert!(result.contains("posts_status_check CHECK (status IN ('active', 'inactive'))"));
+        assert!(result.contains("$table->dropColumn(['status', 'views']);"));
+        assert!(MigrationGenerator.get_file_path(&model, &config).ends_with("_add_columns_to_posts_table.php"));
+
+        let result = MigrationGenerator.generate(&create_test_model(vec![create_field("user_id", false)]), &config);
+        assert!(result.is_err());
+    }
+
+    fn create_fulltext_model() -> ModelDefinition {
+        ModelDefinition {
+            fulltext_indexes: vec![vec!["title".to_string(), "body".to_string()]],
+            ..create_test_model(vec![create_field("title", false), create_field("body", false)])
+        }
+    }
+
+    #[test]
+    fn test_fulltext_index_on_mysql() {
+        let result = MigrationGenerator.generate(&create_fulltext_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->fullText(['title', 'body']);"));
+    }
+
+    #[test]
+    fn test_fulltext_index_skipped_on_sqlite() {
+        let config = Config {
+            database_engine: "sqlite".to_string(),
+            ..Config::default()
+        };
+        let result = MigrationGenerator.generate(&create_fulltext_model(), &config).unwrap();
+
+        assert!(!result.contains("$table->fullText"));
+        assert!(result.contains("// fullText(['title', 'body']) skipped: not supported by sqlite"));
+    }
+
+    fn create_morph_model(optional: bool) -> ModelDefinition {
+        ModelDefinition {
+            relationships: vec![Relationship::MorphTo(PolymorphicRelationship {
+                morph_name: "imageable".to_string(),
+                foreign_key: None,
+                local_key: None,
+                optional,
+            })],
+            ..create_test_model(vec![])
+        }
+    }
+
+    #[test]
+    fn test_point_column() {
+        let mut location = create_field("location", false);
+        location.field_type = FieldType::Point;
+        location.nullable = true;
+        let result = MigrationGenerator.generate(&create_test_model(vec![location]), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->point('location')->nullable();"));
+    }
+
+    #[test]
+    fn test_year_and_time_columns() {
+        let mut founded = create_field("founded", false);
+        founded.field_type = FieldType::Year;
+        let mut opens_at = create_field("opens_at", false);
+        opens_at.field_type = FieldType::Time;
+        let result = MigrationGenerator.generate(&create_test_model(vec![founded, opens_at]), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->year('founded');"));
+        assert!(result.contains("$table->time('opens_at');"));
+    }
+
+    #[test]
+    fn test_morphs_columns() {
+        let result = MigrationGenerator.generate(&create_morph_model(false), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->morphs('imageable');"));
+        assert!(!result.contains("nullableMorphs"));
+    }
+
+    #[test]
+    fn test_nullable_morphs_columns() {
+        let result = MigrationGenerator.generate(&create_morph_model(true), &Config::default()).unwrap();
+
+        assert!(result.contains("$table->nullableMorphs('imageable');"));
+    }
+}
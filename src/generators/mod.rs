@@ -6,7 +6,16 @@ pub mod factory_generator;
 pub mod pivot_table_generator;
 pub mod dto_generator;
 pub mod request_generator;
+pub mod graphql_generator;
+pub mod repository_generator;
+pub mod service_generator;
+pub mod seeder_generator;
+pub mod route_generator;
+pub mod rule_generator;
+pub mod enum_generator;
+pub mod sql_generator;
 pub mod shared;
+pub mod render;
 
 use crate::error::Result;
 use crate::types::{Config, ModelDefinition};
@@ -16,4 +25,156 @@ pub trait Generator {
     fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String;
 }
 
+/// Per-model components rendered through a `Generator`, in generation order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneratorKind {
+    Model,
+    Migration,
+    Controller,
+    Resource,
+    Factory,
+    Dto,
+    /// The store request; `RequestGenerator::generate_action` renders the update one
+    Request,
+    /// The repository class; `RepositoryGenerator::generate_interface` renders its interface
+    Repository,
+    Service,
+    Seeder,
+}
+
+impl GeneratorKind {
+    pub const ALL: [GeneratorKind; 10] = [
+        GeneratorKind::Model,
+        GeneratorKind::Migration,
+        GeneratorKind::Controller,
+        GeneratorKind::Resource,
+        GeneratorKind::Factory,
+        GeneratorKind::Dto,
+        GeneratorKind::Request,
+        GeneratorKind::Repository,
+        GeneratorKind::Service,
+        GeneratorKind::Seeder,
+    ];
+
+    /// Component name as accepted by `--only` (e.g. `models`)
+    pub fn component(self) -> &'static str {
+        match self {
+            GeneratorKind::Model => "models",
+            GeneratorKind::Migration => "migrations",
+            GeneratorKind::Controller => "controllers",
+            GeneratorKind::Resource => "resources",
+            GeneratorKind::Factory => "factories",
+            GeneratorKind::Dto => "dtos",
+            GeneratorKind::Request => "requests",
+            GeneratorKind::Repository => "repositories",
+            GeneratorKind::Service => "services",
+            GeneratorKind::Seeder => "seeders",
+        }
+    }
+
+    /// Whether the config's `generate_*` flag turns this component on
+    pub fn is_enabled(self, config: &Config) -> bool {
+        match self {
+            GeneratorKind::Model => config.generate_models,
+            GeneratorKind::Migration => config.generate_migrations,
+            GeneratorKind::Controller => config.generate_controllers,
+            GeneratorKind::Resource => config.generate_resources,
+            GeneratorKind::Factory => config.generate_factories,
+            GeneratorKind::Dto => config.generate_dto,
+            GeneratorKind::Request => config.generate_requests,
+            GeneratorKind::Repository => config.generate_repositories,
+            GeneratorKind::Service => config.generate_services,
+            GeneratorKind::Seeder => config.generate_seeders,
+        }
+    }
+
+    pub fn generator(self) -> Box<dyn Generator + Send + Sync> {
+        match self {
+            GeneratorKind::Model => Box::new(model_generator::ModelGenerator),
+            GeneratorKind::Migration => Box::new(migration_generator::MigrationGenerator),
+            GeneratorKind::Controller => Box::new(controller_generator::ControllerGenerator),
+            GeneratorKind::Resource => Box::new(resource_generator::ResourceGenerator),
+            GeneratorKind::Factory => Box::new(factory_generator::FactoryGenerator),
+            GeneratorKind::Dto => Box::new(dto_generator::DtoGenerator),
+            GeneratorKind::Request => Box::new(request_generator::RequestGenerator),
+            GeneratorKind::Repository => Box::new(repository_generator::RepositoryGenerator),
+            GeneratorKind::Service => Box::new(service_generator::ServiceGenerator),
+            GeneratorKind::Seeder => Box::new(seeder_generator::SeederGenerator),
+        }
+    }
+}
+
+/// Every per-model generator, keyed by kind
+pub fn registry() -> Vec<(GeneratorKind, Box<dyn Generator + Send + Sync>)> {
+    GeneratorKind::ALL.iter()
+        .map(|&kind| (kind, kind.generator()))
+        .collect()
+}
+
+/// A file rendered by `run_generators`
+#[allow(dead_code)] // library API; the CLI renders through `render::render_with`
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    pub kind: GeneratorKind,
+    pub path: String,
+    pub content: String,
+}
+
+/// Runs the selected generators over one model, in `GeneratorKind::ALL` order
+#[allow(dead_code)] // library API; the CLI renders through `render::render_with`
+pub fn run_generators(kinds: &[GeneratorKind], model: &ModelDefinition, config: &Config) -> Result<Vec<GeneratedFile>> {
+    let mut files = Vec::new();
+    for (kind, generator) in registry() {
+        if kinds.contains(&kind) {
+            files.push(GeneratedFile {
+                kind,
+                path: generator.get_file_path(model, config),
+                content: generator.generate(model, config)?,
+            });
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Field, FieldType};
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            fields: vec![Field::new("title", FieldType::String)],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_registry_covers_every_kind() {
+        let model = create_test_model();
+        let config = Config::default();
+        let registry = registry();
+
+        assert_eq!(registry.iter().map(|(kind, _)| *kind).collect::<Vec<_>>(), GeneratorKind::ALL);
+        for (kind, generator) in &registry {
+            let content = generator.generate(&model, &config).unwrap();
+            assert!(content.starts_with("<?php"), "{:?} produced no PHP", kind);
+            assert!(!generator.get_file_path(&model, &config).is_empty(), "{:?} has no path", kind);
+        }
+    }
+
+    #[test]
+    fn test_run_selected_generators() {
+        let model = create_test_model();
+        let files = run_generators(&[GeneratorKind::Service, GeneratorKind::Model], &model, &Config::default()).unwrap();
+
+        assert_eq!(files.iter().map(|file| file.kind).collect::<Vec<_>>(), [GeneratorKind::Model, GeneratorKind::Service]);
+        assert!(files[0].content.contains("class Post extends Model"));
+        assert!(files[1].content.contains("class PostService"));
+    }
+}
+
 // Use the Generator trait for both models and pivot tables
\ No newline at end of file
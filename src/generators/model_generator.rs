@@ -1,7 +1,8 @@
 use crate::error::Result;
 use crate::generators::Generator;
-use crate::generators::shared::{PathResolver, NamespaceResolver};
-use crate::types::{Config, ModelDefinition, Relationship};
+use crate::generators::enum_generator::EnumGenerator;
+use crate::generators::shared::{PathResolver, NamespaceResolver, NameHelper, RelationshipHelper, ClassNameResolver, GeneratedMarker};
+use crate::types::{AccessorStyle, CastsStyle, Config, DatetimeCast, FieldType, JsonCast, ModelDefinition, Relationship};
 
 pub struct ModelGenerator;
 
@@ -10,29 +11,82 @@ impl Generator for ModelGenerator {
         let mut content = String::new();
 
         // PHP opening tag and namespace
-        content.push_str("<?php\n\n");
+        content.push_str(&GeneratedMarker::php_header(config));
         let namespace = NamespaceResolver::get_model_namespace(model, config);
         content.push_str(&format!("namespace {};\n\n", namespace));
 
-        // Imports
-        content.push_str("use Illuminate\\Database\\Eloquent\\Model;\n");
-        if model.soft_deletes {
+        // Imports; a base class named like the model or one of its interfaces is aliased
+        let interfaces = model.interfaces();
+        let base_class = config.base_model.rsplit('\\').next().unwrap_or(&config.base_model);
+        let base_clashes = base_class == model.name
+            || interfaces.iter().any(|i| i.rsplit('\\').next() == Some(base_class));
+        let base_model = if base_clashes {
+            let alias = format!("Base{}", base_class);
+            content.push_str(&format!("use {} as {};\n", config.base_model, alias));
+            alias
+        } else {
+            content.push_str(&format!("use {};\n", config.base_model));
+            base_class.to_string()
+        };
+        let has_uuids = model.has_uuids_key(config).is_some();
+        if has_uuids {
+            content.push_str("use Illuminate\\Database\\Eloquent\\Concerns\\HasUuids;\n");
+        }
+        if model.has_soft_deletes(config) {
             content.push_str("use Illuminate\\Database\\Eloquent\\SoftDeletes;\n");
         }
+        // Laravel only discovers `Database\Factories\{Model}Factory` by convention
+        let custom_factory = config.generate_factories && (model.factory_name.is_some() || model.module.is_some());
         if config.generate_factories {
             content.push_str("use Illuminate\\Database\\Eloquent\\Factories\\HasFactory;\n");
         }
+        if custom_factory {
+            content.push_str(&format!(
+                "use {}\\{};\n",
+                NamespaceResolver::get_factory_namespace(model, config), ClassNameResolver::factory_class(model)
+            ));
+        }
+        if !model.appends.is_empty() && config.accessor_style == AccessorStyle::Attribute {
+            content.push_str("use Illuminate\\Database\\Eloquent\\Casts\\Attribute;\n");
+        }
+        if model.fields.iter().any(|f| matches!(f.field_type, FieldType::Json) && f.json_cast_as == Some(JsonCast::Collection) && !f.encrypted) {
+            content.push_str("use Illuminate\\Database\\Eloquent\\Casts\\AsCollection;\n");
+        }
+        if !model.scopes.is_empty() {
+            content.push_str("use Illuminate\\Database\\Eloquent\\Builder;\n");
+        }
+        for interface in interfaces.iter().filter(|i| i.contains('\\')) {
+            content.push_str(&format!("use {};\n", interface));
+        }
+        if config.generate_enums {
+            for field in EnumGenerator::enum_fields(model) {
+                content.push_str(&format!(
+                    "use {}\\{};\n",
+                    NamespaceResolver::get_enum_namespace(model, config), ClassNameResolver::enum_class(model, field)
+                ));
+            }
+        }
         content.push('\n');
 
         // Class declaration
-        content.push_str(&format!("class {} extends Model\n{{\n", model.name));
+        let implements: Vec<&str> = interfaces.iter()
+            .map(|i| i.rsplit('\\').next().unwrap_or(i))
+            .collect();
+        if implements.is_empty() {
+            content.push_str(&format!("class {} extends {}\n{{\n", model.name, base_model));
+        } else {
+            content.push_str(&format!("class {} extends {} implements {}\n{{\n", model.name, base_model, implements.join(", ")));
+        }
 
         // Traits
         let mut traits = Vec::new();
         if config.generate_factories {
             traits.push("HasFactory");
         }
-        if model.soft_deletes {
+        if has_uuids {
+            traits.push("HasUuids");
+        }
+        if model.has_soft_deletes(config) {
             traits.push("SoftDeletes");
         }
         if !traits.is_empty() {
@@ -40,13 +94,38 @@ impl Generator for ModelGenerator {
         }
 
         // Table name
-        content.push_str(&format!("    protected $table = '{}';\n\n", model.table));
+        content.push_str(&format!("    protected $table = '{}';\n\n", model.table_name(config)));
+
+        // Composite primary key
+        if let Some(columns) = model.composite_primary_key() {
+            content.push_str(&format!(
+                "    /**\n     * Composite primary key ({}).\n     *\n     * Eloquent keys models by a single column, so find(), update() and delete()\n     * on an instance don't work; query these rows with where() on every key column.\n     */\n    public $incrementing = false;\n\n",
+                columns.join(", ")
+            ));
+        }
+
+        // Single-column key other than an auto-incrementing `id`
+        if let Some(key) = model.primary_key() {
+            if key.name != "id" {
+                content.push_str(&format!("    protected $primaryKey = '{}';\n\n", key.name));
+            }
+            if !key.field_type.is_integer() {
+                content.push_str("    protected $keyType = 'string';\n\n    public $incrementing = false;\n\n");
+            } else if !key.auto_increment {
+                content.push_str("    public $incrementing = false;\n\n");
+            }
+        }
 
         // Timestamps
-        if !model.timestamps {
+        if !model.has_timestamps(config) {
             content.push_str("    public $timestamps = false;\n\n");
         }
 
+        // Date storage format
+        if let Some(date_format) = &model.date_format {
+            content.push_str(&format!("    protected $dateFormat = '{}';\n\n", date_format));
+        }
+
         // Fillable fields
         let fillable_fields: Vec<String> = model.fields
             .iter()
@@ -60,17 +139,63 @@ impl Generator for ModelGenerator {
             content.push_str("    ];\n\n");
         }
 
+        // Default eager-loaded relationships
+        if !model.with.is_empty() {
+            let with: Vec<String> = model.with.iter().map(|r| format!("'{}'", r)).collect();
+            content.push_str(&format!("    protected $with = [{}];\n\n", with.join(", ")));
+        }
+
+        // Parents whose updated_at follows this model's saves
+        if !model.touches.is_empty() {
+            let touches: Vec<String> = model.touches.iter().map(|r| format!("'{}'", r)).collect();
+            content.push_str(&format!("    protected $touches = [{}];\n\n", touches.join(", ")));
+        }
+
+        // Appended attributes
+        if !model.appends.is_empty() {
+            let appends: Vec<String> = model.appends.iter().map(|a| format!("'{}'", a)).collect();
+            content.push_str(&format!("    protected $appends = [{}];\n\n", appends.join(", ")));
+        }
+
         // Casts
-        let casts = self.build_casts(model);
+        let casts = self.build_casts(model, config);
         if !casts.is_empty() {
-            content.push_str("    protected $casts = [\n");
-            content.push_str(&casts);
-            content.push_str("    ];\n\n");
+            match config.casts_style {
+                CastsStyle::Property => {
+                    content.push_str("    protected $casts = [\n");
+                    content.push_str(&casts);
+                    content.push_str("    ];\n\n");
+                },
+                CastsStyle::Method => {
+                    content.push_str("    protected function casts(): array\n    {\n        return [\n");
+                    for line in casts.lines() {
+                        content.push_str(&format!("    {}\n", line));
+                    }
+                    content.push_str("        ];\n    }\n\n");
+                },
+            }
+        }
+
+        if custom_factory {
+            content.push_str(&format!(
+                "    protected static function newFactory(): {}\n    {{\n        return {}::new();\n    }}\n\n",
+                ClassNameResolver::factory_class(model), ClassNameResolver::factory_class(model)
+            ));
         }
 
         // Relationships
         for relationship in &model.relationships {
-            content.push_str(&self.build_relationship_method(relationship, config));
+            content.push_str(&self.build_relationship_method(model, relationship, config));
+        }
+
+        // Query scope stubs
+        for scope in &model.scopes {
+            content.push_str(&self.build_scope(scope));
+        }
+
+        // Accessor stubs for appended attributes
+        for attribute in &model.appends {
+            content.push_str(&self.build_accessor(attribute, config.accessor_style));
         }
 
         content.push_str("}\n");
@@ -83,61 +208,51 @@ impl Generator for ModelGenerator {
 }
 
 impl ModelGenerator {
-    fn get_relationship_method_name(&self, relationship: &Relationship) -> String {
-        match relationship {
-            Relationship::BelongsTo(rel) => {
-                // Convert StudlyCase to camelCase for the method name
-                let first_char = rel.model.chars().next().unwrap().to_lowercase().to_string();
-                let rest = &rel.model[1..];
-                format!("{}{}", first_char, rest)
-            },
-            Relationship::HasMany(rel) | Relationship::BelongsToMany(rel) => {
-                // Pluralize the model name for the method name
-                self.pluralize_model_name(&rel.model)
-            },
-            Relationship::MorphMany(rel) | Relationship::MorphToMany(rel) => {
-                // Pluralize the model name for the method name
-                self.pluralize_model_name(&rel.model)
-            },
-            Relationship::HasOne(rel) => {
-                // Convert StudlyCase to camelCase for the method name
-                let first_char = rel.model.chars().next().unwrap().to_lowercase().to_string();
-                let rest = &rel.model[1..];
-                format!("{}{}", first_char, rest)
-            },
-            Relationship::MorphOne(rel) => {
-                // Convert StudlyCase to camelCase for the method name
-                let first_char = rel.model.chars().next().unwrap().to_lowercase().to_string();
-                let rest = &rel.model[1..];
-                format!("{}{}", first_char, rest)
+    fn build_accessor(&self, attribute: &str, style: AccessorStyle) -> String {
+        let studly = NameHelper::studly_case(attribute);
+
+        match style {
+            AccessorStyle::Attribute => {
+                format!("    protected function {}(): Attribute\n    {{\n        return Attribute::make(\n            get: fn ($value, array $attributes) => null, // TODO: compute {}\n        );\n    }}\n\n",
+                        NameHelper::camel_case(&studly), attribute)
             },
-            Relationship::MorphTo(rel) => {
-                rel.morph_name.clone()
+            AccessorStyle::Legacy => {
+                format!("    public function get{}Attribute()\n    {{\n        // TODO: compute {}\n        return null;\n    }}\n\n",
+                        studly, attribute)
             },
         }
     }
 
-    fn pluralize_model_name(&self, model_name: &str) -> String {
-        // Convert StudlyCase to camelCase and pluralize
-        let first_char = model_name.chars().next().unwrap().to_lowercase().to_string();
-        let rest = &model_name[1..];
-        let singular = format!("{}{}", first_char, rest);
-
-        if singular.ends_with('y') {
-            format!("{}ies", &singular[..singular.len()-1])
-        } else if singular.ends_with('s') {
-            format!("{}es", singular)
-        } else {
-            format!("{}s", singular)
-        }
+    fn build_scope(&self, scope: &str) -> String {
+        format!("    public function scope{}(Builder $query): void\n    {{\n        // TODO: constrain the query for the '{}' scope\n    }}\n\n",
+                NameHelper::studly_case(scope), scope)
     }
 
-
-    fn build_casts(&self, model: &ModelDefinition) -> String {
+    fn build_casts(&self, model: &ModelDefinition, config: &Config) -> String {
         let mut casts = String::new();
 
         for field in &model.fields {
-            if let Some(cast) = field.field_type.to_cast_type() {
+            // Enum fields cast to their generated backed enum
+            if config.generate_enums && EnumGenerator::enum_fields(model).any(|f| f.name == field.name) {
+                casts.push_str(&format!("        '{}' => {}::class,\n", field.name, ClassNameResolver::enum_class(model, field)));
+                continue;
+            }
+
+            if let (FieldType::Json, Some(JsonCast::Collection), false) = (&field.field_type, field.json_cast_as, field.encrypted) {
+                casts.push_str(&format!("        '{}' => AsCollection::class,\n", field.name));
+                continue;
+            }
+
+            let cast = match (&field.field_type, config.datetime_cast) {
+                (FieldType::DateTime | FieldType::Timestamp, DatetimeCast::ImmutableDatetime) => Some("immutable_datetime".to_string()),
+                (FieldType::Date, DatetimeCast::ImmutableDatetime) => Some("immutable_date".to_string()),
+                _ => field.to_cast_type(),
+            };
+            if let Some(cast) = cast {
+                let cast = match (&field.cast_format, &field.field_type) {
+                    (Some(format), FieldType::Date | FieldType::DateTime | FieldType::Timestamp) => format!("{}:{}", cast, format),
+                    _ => cast,
+                };
                 casts.push_str(&format!("        '{}' => '{}',\n", field.name, cast));
             }
         }
@@ -145,13 +260,15 @@ impl ModelGenerator {
         casts
     }
 
-    fn build_relationship_method(&self, relationship: &crate::types::Relationship, _config: &Config) -> String {
-        let method_name = self.get_relationship_method_name(relationship);
-
+    fn build_relationship_method(&self, model: &ModelDefinition, relationship: &crate::types::Relationship, _config: &Config) -> String {
+        let method_name = RelationshipHelper::method_name(relationship);
 
         match relationship {
             Relationship::BelongsTo(rel) => {
-                if let Some(foreign_key) = &rel.foreign_key {
+                if let (Some(foreign_key), Some(owner_key)) = (&rel.foreign_key, &rel.owner_key) {
+                    format!("    public function {}()\n    {{\n        return $this->belongsTo({}::class, '{}', '{}');\n    }}\n\n",
+                            method_name, rel.model, foreign_key, owner_key)
+                } else if let Some(foreign_key) = &rel.foreign_key {
                     format!("    public function {}()\n    {{\n        return $this->belongsTo({}::class, '{}');\n    }}\n\n",
                             method_name, rel.model, foreign_key)
                 } else {
@@ -178,13 +295,8 @@ impl ModelGenerator {
                 }
             },
             Relationship::BelongsToMany(rel) => {
-                if let Some(pivot_table) = &rel.pivot_table {
-                    format!("    public function {}()\n    {{\n        return $this->belongsToMany({}::class, '{}');\n    }}\n\n",
-                            method_name, rel.model, pivot_table)
-                } else {
-                    format!("    public function {}()\n    {{\n        return $this->belongsToMany({}::class);\n    }}\n\n",
-                            method_name, rel.model)
-                }
+                format!("    public function {}()\n    {{\n        return $this->belongsToMany({}::class, '{}');\n    }}\n\n",
+                        method_name, rel.model, RelationshipHelper::belongs_to_many_pivot(&model.name, rel))
             },
             Relationship::MorphTo(_rel) => {
                 format!("    public function {}()\n    {{\n        return $this->morphTo();\n    }}\n\n", method_name)
@@ -206,7 +318,436 @@ impl ModelGenerator {
                             method_name, rel.model, rel.morph_name)
                 }
             },
+            Relationship::MorphedByMany(rel) => {
+                if let Some(pivot_table) = &rel.pivot_table {
+                    format!("    public function {}()\n    {{\n        return $this->morphedByMany({}::class, '{}', '{}');\n    }}\n\n",
+                            method_name, rel.model, rel.morph_name, pivot_table)
+                } else {
+                    format!("    public function {}()\n    {{\n        return $this->morphedByMany({}::class, '{}');\n    }}\n\n",
+                            method_name, rel.model, rel.morph_name)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DecimalPrecision, EnumValue, Field, PolymorphicWithModelRelationship, StandardRelationship};
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            appends: vec!["full_name".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_touches_property() {
+        let model = ModelDefinition {
+            touches: vec!["post".to_string()],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("    protected $touches = ['post'];\n"));
+        assert!(!ModelGenerator.generate(&create_test_model(), &Config::default()).unwrap().contains("$touches"));
+    }
+
+    #[test]
+    fn test_implements_interfaces() {
+        let model = ModelDefinition {
+            implements: vec![
+                "Spatie\\MediaLibrary\\HasMedia".to_string(),
+                "OwenIt\\Auditing\\Contracts\\Auditable".to_string(),
+                "Spatie\\MediaLibrary\\HasMedia".to_string(),
+            ],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("use Spatie\\MediaLibrary\\HasMedia;\nuse OwenIt\\Auditing\\Contracts\\Auditable;\n"));
+        assert_eq!(result.matches("use Spatie\\MediaLibrary\\HasMedia;").count(), 1);
+        assert!(result.contains("class User extends Model implements HasMedia, Auditable\n{"));
+        assert!(ModelGenerator.generate(&create_test_model(), &Config::default()).unwrap().contains("class User extends Model\n{"));
+    }
+
+    #[test]
+    fn test_custom_base_model() {
+        let config = Config { base_model: "App\\Models\\BaseModel".to_string(), ..Config::default() };
+        let result = ModelGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\Models\\BaseModel;\n"));
+        assert!(!result.contains("use Illuminate\\Database\\Eloquent\\Model;"));
+        assert!(result.contains("class User extends BaseModel\n{"));
+
+        let default = ModelGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+        assert!(default.contains("use Illuminate\\Database\\Eloquent\\Model;\n"));
+    }
+
+    #[test]
+    fn test_base_model_named_like_the_model_is_aliased() {
+        let config = Config { base_model: "Illuminate\\Foundation\\Auth\\User".to_string(), ..Config::default() };
+        let result = ModelGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use Illuminate\\Foundation\\Auth\\User as BaseUser;\n"));
+        assert!(result.contains("class User extends BaseUser\n{"));
+
+        let config = Config { base_model: "App\\Models\\Auditable".to_string(), ..Config::default() };
+        let model = ModelDefinition {
+            implements: vec!["OwenIt\\Auditing\\Contracts\\Auditable".to_string()],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("use App\\Models\\Auditable as BaseAuditable;\n"));
+        assert!(result.contains("class User extends BaseAuditable implements Auditable\n{"));
+    }
+
+    #[test]
+    fn test_appends_with_attribute_accessor() {
+        let result = ModelGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("use Illuminate\\Database\\Eloquent\\Casts\\Attribute;"));
+        assert!(result.contains("protected $appends = ['full_name'];"));
+        assert!(result.contains("protected function fullName(): Attribute"));
+        assert!(result.contains("return Attribute::make("));
+        assert!(!result.contains("getFullNameAttribute"));
+    }
+
+    #[test]
+    fn test_appends_with_legacy_accessor() {
+        let config = Config {
+            accessor_style: AccessorStyle::Legacy,
+            ..Config::default()
+        };
+        let result = ModelGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(!result.contains("Casts\\Attribute"));
+        assert!(result.contains("protected $appends = ['full_name'];"));
+        assert!(result.contains("public function getFullNameAttribute()"));
+    }
+
+    #[test]
+    fn test_scope_stub() {
+        let model = ModelDefinition {
+            scopes: vec!["active".to_string()],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("use Illuminate\\Database\\Eloquent\\Builder;"));
+        assert!(result.contains("    public function scopeActive(Builder $query): void\n"));
+        assert!(result.contains("// TODO: constrain the query for the 'active' scope"));
+    }
+
+    fn create_dated_model() -> ModelDefinition {
+        ModelDefinition {
+            fields: vec![
+                Field::new("published_at", FieldType::Timestamp),
+                Field::new("birthday", FieldType::Date),
+            ],
+            ..create_test_model()
         }
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_datetime_casts() {
+        let result = ModelGenerator.generate(&create_dated_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("'published_at' => 'datetime',"));
+        assert!(result.contains("'birthday' => 'date',"));
+    }
+
+    #[test]
+    fn test_immutable_datetime_casts() {
+        let config = Config {
+            datetime_cast: DatetimeCast::ImmutableDatetime,
+            ..Config::default()
+        };
+        let result = ModelGenerator.generate(&create_dated_model(), &config).unwrap();
+
+        assert!(result.contains("'published_at' => 'immutable_datetime',"));
+        assert!(result.contains("'birthday' => 'immutable_date',"));
+    }
+
+    #[test]
+    fn test_year_and_time_casts() {
+        let model = ModelDefinition {
+            fields: vec![Field::new("founded", FieldType::Year), Field::new("opens_at", FieldType::Time)],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'founded' => 'integer',"));
+        assert!(!result.contains("'opens_at' =>"));
+    }
+
+    #[test]
+    fn test_decimal_cast_keeps_scale() {
+        let mut price = Field::new("price", FieldType::Decimal);
+        price.decimal_precision = Some(DecimalPrecision { precision: 10, scale: 2 });
+        let model = ModelDefinition {
+            fields: vec![price, Field::new("ratio", FieldType::Decimal)],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'price' => 'decimal:2',"));
+        assert!(result.contains("'ratio' => 'float',"));
+    }
+
+    #[test]
+    fn test_hashed_cast() {
+        let password = Field { hashed: true, ..Field::new("password", FieldType::String) };
+        let model = ModelDefinition {
+            fields: vec![password],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'password' => 'hashed',"));
+    }
+
+    #[test]
+    fn test_encrypted_casts() {
+        let model = ModelDefinition {
+            fields: vec![
+                Field { encrypted: true, ..Field::new("ssn", FieldType::String) },
+                Field { encrypted: true, ..Field::new("medical_history", FieldType::Json) },
+                Field { encrypted: true, json_cast_as: Some(JsonCast::Collection), ..Field::new("notes", FieldType::Json) },
+            ],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'ssn' => 'encrypted',"));
+        assert!(result.contains("'medical_history' => 'encrypted:array',"));
+        assert!(result.contains("'notes' => 'encrypted:collection',"));
+        assert!(!result.contains("AsCollection"));
+    }
+
+    #[test]
+    fn test_json_cast_variants() {
+        let json = |name: &str, cast: Option<JsonCast>| Field { json_cast_as: cast, ..Field::new(name, FieldType::Json) };
+        let model = ModelDefinition {
+            fields: vec![
+                json("settings", None),
+                json("options", Some(JsonCast::Array)),
+                json("tags", Some(JsonCast::Collection)),
+                json("meta", Some(JsonCast::Object)),
+            ],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("use Illuminate\\Database\\Eloquent\\Casts\\AsCollection;\n"));
+        assert!(result.contains("'settings' => 'array',"));
+        assert!(result.contains("'options' => 'array',"));
+        assert!(result.contains("'tags' => AsCollection::class,"));
+        assert!(result.contains("'meta' => 'object',"));
+
+        let result = ModelGenerator.generate(&ModelDefinition { fields: vec![json("settings", None)], ..create_test_model() }, &Config::default()).unwrap();
+        assert!(!result.contains("AsCollection"));
+    }
+
+    #[test]
+    fn test_casts_method_style() {
+        let property = ModelGenerator.generate(&create_dated_model(), &Config::default()).unwrap();
+        assert!(property.contains("    protected $casts = [\n        'published_at' => 'datetime',\n        'birthday' => 'date',\n    ];\n"));
+        assert!(!property.contains("function casts()"));
+
+        let config = Config {
+            casts_style: CastsStyle::Method,
+            ..Config::default()
+        };
+        let method = ModelGenerator.generate(&create_dated_model(), &config).unwrap();
+        assert!(method.contains("    protected function casts(): array\n    {\n        return [\n            'published_at' => 'datetime',\n            'birthday' => 'date',\n        ];\n    }\n"));
+        assert!(!method.contains("$casts"));
+    }
+
+    #[test]
+    fn test_formatted_datetime_cast() {
+        let mut published_at = Field::new("published_at", FieldType::DateTime);
+        published_at.cast_format = Some("Y-m-d H:i".to_string());
+        let model = ModelDefinition {
+            fields: vec![published_at],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("'published_at' => 'datetime:Y-m-d H:i',"));
+    }
+
+    #[test]
+    fn test_enum_cast_uses_generated_enum_class() {
+        let mut status = Field::new("status", FieldType::Enum);
+        status.enum_values = vec![EnumValue { value: "active".to_string(), label: None }];
+        let model = ModelDefinition {
+            fields: vec![status],
+            ..create_test_model()
+        };
+
+        let config = Config { generate_enums: true, ..Config::default() };
+        let result = ModelGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("use App\\Enums\\UserStatus;\n"));
+        assert!(result.contains("        'status' => UserStatus::class,\n"));
+
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(!result.contains("UserStatus"));
+    }
+
+    #[test]
+    fn test_date_format_only_when_configured() {
+        let result = ModelGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+        assert!(!result.contains("$dateFormat"));
+
+        let model = ModelDefinition {
+            date_format: Some("Y-m-d H:i:s".to_string()),
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(result.contains("    protected $dateFormat = 'Y-m-d H:i:s';\n"));
+    }
+
+    fn create_self_relationship(method_name: &str) -> StandardRelationship {
+        StandardRelationship {
+            model: "Category".to_string(),
+            foreign_key: Some("parent_id".to_string()),
+            local_key: None,
+            owner_key: None,
+            pivot_table: None,
+            pivot_fields: vec![],
+            on_delete: None,
+            on_update: None,
+            with_timestamps: false,
+            nullable: false,
+            method_name: Some(method_name.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_self_referencing_relationship_method_names() {
+        let model = ModelDefinition {
+            name: "Category".to_string(),
+            table: "categories".to_string(),
+            fields: vec![Field::new("parent_id", FieldType::BigInteger)],
+            relationships: vec![
+                Relationship::BelongsTo(create_self_relationship("parent")),
+                Relationship::HasMany(create_self_relationship("children")),
+            ],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("    public function parent()\n    {\n        return $this->belongsTo(Category::class, 'parent_id');"));
+        assert!(result.contains("    public function children()\n    {\n        return $this->hasMany(Category::class, 'parent_id');"));
+        assert!(!result.contains("function category()"));
+        assert!(!result.contains("function categories()"));
+    }
+
+    #[test]
+    fn test_belongs_to_many_uses_conventional_pivot_table() {
+        let mut roles = create_self_relationship("roles");
+        roles.model = "Role".to_string();
+        roles.foreign_key = None;
+        let model = ModelDefinition {
+            relationships: vec![Relationship::BelongsToMany(roles)],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("        return $this->belongsToMany(Role::class, 'role_user');"));
+    }
+
+    #[test]
+    fn test_uuid_primary_key_uses_has_uuids() {
+        let id = Field { primary: true, ..Field::new("id", FieldType::Uuid) };
+        let model = ModelDefinition {
+            fields: vec![id],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("use Illuminate\\Database\\Eloquent\\Concerns\\HasUuids;\n"));
+        assert!(result.contains("    use HasFactory, HasUuids;\n"));
+        assert!(result.contains("    protected $keyType = 'string';\n\n    public $incrementing = false;\n"));
+
+        let config = Config {
+            has_uuids: false,
+            ..Config::default()
+        };
+        let result = ModelGenerator.generate(&model, &config).unwrap();
+        assert!(!result.contains("HasUuids"));
+        assert!(result.contains("    protected $keyType = 'string';\n"));
+        assert!(!result.contains("$primaryKey"));
+    }
+
+    #[test]
+    fn test_custom_primary_key_column() {
+        let uuid = Field { primary: true, ..Field::new("uuid", FieldType::Uuid) };
+        let model = ModelDefinition { fields: vec![uuid], ..create_test_model() };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(result.contains("    protected $primaryKey = 'uuid';\n\n    protected $keyType = 'string';\n"));
+
+        let code = Field { primary: true, ..Field::new("code", FieldType::String) };
+        let model = ModelDefinition { fields: vec![code], ..create_test_model() };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(result.contains("    protected $primaryKey = 'code';\n\n    protected $keyType = 'string';\n\n    public $incrementing = false;\n"));
+        assert!(!result.contains("HasUuids"));
+
+        let number = Field { primary: true, auto_increment: true, ..Field::new("number", FieldType::BigInteger) };
+        let model = ModelDefinition { fields: vec![number], ..create_test_model() };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+        assert!(result.contains("    protected $primaryKey = 'number';\n"));
+        assert!(!result.contains("$keyType"));
+        assert!(!result.contains("$incrementing"));
+    }
+
+    #[test]
+    fn test_composite_primary_key_disables_incrementing() {
+        let mut order_id = Field::new("order_id", FieldType::BigInteger);
+        order_id.primary = true;
+        let mut product_id = Field::new("product_id", FieldType::BigInteger);
+        product_id.primary = true;
+        let model = ModelDefinition {
+            fields: vec![order_id, product_id],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("     * Composite primary key (order_id, product_id).\n"));
+        assert!(result.contains("    public $incrementing = false;\n"));
+
+        let result = ModelGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+        assert!(!result.contains("$incrementing"));
+    }
+
+    #[test]
+    fn test_morphed_by_many_relationship() {
+        let model = ModelDefinition {
+            name: "Tag".to_string(),
+            table: "tags".to_string(),
+            relationships: vec![Relationship::MorphedByMany(PolymorphicWithModelRelationship {
+                model: "Post".to_string(),
+                morph_name: "taggable".to_string(),
+                foreign_key: None,
+                local_key: None,
+                pivot_table: None,
+                pivot_fields: vec![],
+                on_delete: None,
+                on_update: None,
+                with_timestamps: false,
+                method_name: None,
+            })],
+            ..create_test_model()
+        };
+        let result = ModelGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("    public function posts()\n    {\n        return $this->morphedByMany(Post::class, 'taggable');"));
+    }
+}
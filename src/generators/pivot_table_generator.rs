@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::generators::Generator;
+use crate::generators::shared::{ColumnModifiers, GeneratedMarker, PathResolver, RelationshipHelper};
 use crate::types::{Config, PivotTable, Field, ModelDefinition};
-use chrono::Utc;
 
 pub struct PivotTableGenerator;
 
@@ -17,24 +17,20 @@ impl Generator for PivotTableGenerator {
     }
 
     fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
-        let timestamp = Utc::now().format("%Y_%m_%d_%H%M%S");
-        format!(
-            "{}/database/migrations/{}_create_{}_table.php",
-            config.output_dir,
-            timestamp,
-            model.name
-        )
+        // A model stands in for the first pivot table it declares
+        let name = model.pivot_tables.first().map_or(&model.table, |pivot_table| &pivot_table.name);
+        Self::migration_path(name, config)
     }
 }
 
 impl PivotTableGenerator {
-    pub fn generate_pivot_table(&self, pivot_table: &PivotTable, _config: &Config) -> Result<String> {
+    pub fn generate_pivot_table(&self, pivot_table: &PivotTable, config: &Config) -> Result<String> {
         // Prepare template data
         let table_name = &pivot_table.name;
         let foreign_key1 = &pivot_table.foreign_key1;
         let foreign_key2 = &pivot_table.foreign_key2;
-        let table1 = self.model_name_to_table(&pivot_table.model1);
-        let table2 = self.model_name_to_table(&pivot_table.model2);
+        let table1 = RelationshipHelper::related_table(&pivot_table.model1, config);
+        let table2 = RelationshipHelper::related_table(&pivot_table.model2, config);
 
         // Generate additional fields
         let mut additional_fields = String::new();
@@ -51,6 +47,7 @@ impl PivotTableGenerator {
 
         // Replace placeholders in the template
         let content = PIVOT_TABLE_TEMPLATE
+            .replace("{{php_header}}", &GeneratedMarker::php_header(config))
             .replace("{{table_name}}", table_name)
             .replace("{{foreign_key1}}", foreign_key1)
             .replace("{{foreign_key2}}", foreign_key2)
@@ -63,13 +60,12 @@ impl PivotTableGenerator {
     }
 
     pub fn get_pivot_file_path(&self, pivot_table: &PivotTable, config: &Config) -> String {
-        let timestamp = Utc::now().format("%Y_%m_%d_%H%M%S");
-        format!(
-            "{}/database/migrations/{}_create_{}_table.php",
-            config.output_dir,
-            timestamp,
-            pivot_table.name
-        )
+        Self::migration_path(&pivot_table.name, config)
+    }
+
+    /// Each pivot table gets its own timestamp after every model table, which pivot tables reference
+    fn migration_path(name: &str, config: &Config) -> String {
+        PathResolver::get_create_migration_path(name, RelationshipHelper::pivot_sequence(name, config), config)
     }
 
     fn build_field_definition(&self, field: &Field) -> String {
@@ -108,50 +104,113 @@ impl PivotTableGenerator {
     }
 
     fn add_field_modifiers(&self, definition: &mut String, field: &Field) {
-        if field.unsigned {
-            definition.push_str("->unsigned()");
-        }
+        definition.push_str(&ColumnModifiers::chain(field, field.unique, field.index));
+    }
+}
 
-        if field.nullable {
-            definition.push_str("->nullable()");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::migration_generator::MigrationGenerator;
+    use crate::types::ColumnDefault;
+    use std::path::Path;
+
+    fn create_pivot_table() -> PivotTable {
+        PivotTable {
+            name: "role_user".to_string(),
+            model1: "Role".to_string(),
+            model2: "User".to_string(),
+            foreign_key1: "role_id".to_string(),
+            foreign_key2: "user_id".to_string(),
+            additional_fields: vec![],
+            timestamps: false,
         }
+    }
 
-        if field.unique {
-            definition.push_str("->unique()");
-        }
+    #[test]
+    fn test_pivot_file_name_matches_table() {
+        let pivot_table = create_pivot_table();
+        let config = Config::default();
+
+        let path = PivotTableGenerator.get_pivot_file_path(&pivot_table, &config);
+        let file_name = Path::new(&path).file_name().unwrap().to_str().unwrap();
+        assert_eq!(Path::new(&path).parent(), Some(Path::new("./database/migrations")));
+        assert!(file_name.ends_with("_create_role_user_table.php"));
+        // 2024_01_01_000000_ prefix
+        assert_eq!(file_name.len(), "2024_01_01_000000_create_role_user_table.php".len());
+
+        let content = PivotTableGenerator.generate_pivot_table(&pivot_table, &config).unwrap();
+        assert!(content.contains("Schema::create('role_user'"));
+        assert!(content.contains("Schema::dropIfExists('role_user')"));
+    }
 
-        if field.index {
-            definition.push_str("->index()");
-        }
+    #[test]
+    fn test_each_pivot_table_follows_every_model_table() {
+        let model = |name: &str, table: &str, pivot_tables: Vec<PivotTable>| ModelDefinition {
+            name: name.to_string(),
+            table: table.to_string(),
+            pivot_tables,
+            ..Default::default()
+        };
+        let tag_post = PivotTable {
+            name: "post_tag".to_string(),
+            model1: "Post".to_string(),
+            model2: "Tag".to_string(),
+            ..create_pivot_table()
+        };
+        let config = Config {
+            models: vec![
+                model("User", "users", vec![create_pivot_table(), tag_post.clone()]),
+                model("Role", "roles", vec![]),
+                model("Post", "posts", vec![]),
+            ],
+            ..Config::default()
+        }.for_migration_run();
+        let timestamp = |path: String| Path::new(&path).file_name().unwrap().to_str().unwrap()[..17].to_string();
+
+        let role_user = timestamp(PivotTableGenerator.get_pivot_file_path(&create_pivot_table(), &config));
+        let post_tag = timestamp(PivotTableGenerator.get_pivot_file_path(&tag_post, &config));
+        let last_model = timestamp(MigrationGenerator.get_file_path(&config.models[2], &config));
+        assert!(last_model < role_user, "{} !< {}", last_model, role_user);
+        assert!(role_user < post_tag, "{} !< {}", role_user, post_tag);
+
+        // The trait path names the file after the model's pivot table
+        let path = PivotTableGenerator.get_file_path(&config.models[0], &config);
+        assert!(path.ends_with("_create_role_user_table.php"));
+        assert_eq!(timestamp(path), role_user);
+    }
 
-        if let Some(default_value) = &field.default {
-            definition.push_str(&format!("->default('{}')", default_value));
-        }
+    #[test]
+    fn test_pivot_references_declared_tables() {
+        let config = Config {
+            models: vec![ModelDefinition {
+                name: "User".to_string(),
+                table: "members".to_string(),
+                ..Default::default()
+            }],
+            ..Config::default()
+        };
+        let content = PivotTableGenerator.generate_pivot_table(&create_pivot_table(), &config).unwrap();
 
-        if let Some(comment) = &field.comment {
-            definition.push_str(&format!("->comment('{}')", comment));
-        }
+        assert!(content.contains("->references('id')->on('members')"));
+        // Models outside the config keep the conventional table
+        assert!(content.contains("->references('id')->on('roles')"));
     }
 
-    fn model_name_to_table(&self, model_name: &str) -> String {
-        let snake_case = model_name
-            .chars()
-            .enumerate()
-            .map(|(i, c)| {
-                if i > 0 && c.is_uppercase() {
-                    format!("_{}", c.to_lowercase())
-                } else {
-                    c.to_lowercase().to_string()
-                }
-            })
-            .collect::<String>();
-
-        if snake_case.ends_with("y") {
-            format!("{}ies", &snake_case[..snake_case.len()-1])
-        } else if snake_case.ends_with("s") {
-            format!("{}es", snake_case)
-        } else {
-            format!("{}s", snake_case)
-        }
+    #[test]
+    fn test_additional_field_modifier_order() {
+        let mut pivot_table = create_pivot_table();
+        pivot_table.additional_fields.push(Field {
+            name: "expires_at".to_string(),
+            field_type: crate::types::FieldType::Timestamp,
+            nullable: true,
+            default: Some(ColumnDefault::Null),
+            index: true,
+            comment: Some("Role expiry".to_string()),
+            ..Default::default()
+        });
+        let content = PivotTableGenerator.generate_pivot_table(&pivot_table, &Config::default()).unwrap();
+
+        assert!(content.contains("$table->timestamp('expires_at')->nullable()->default(null)->index()->comment('Role expiry');"));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,398 @@
+use crate::error::Result;
+use crate::generators::shared::{ClassNameResolver, PathResolver, RelationshipHelper};
+use crate::generators::{
+    enum_generator::EnumGenerator, graphql_generator::GraphQlGenerator, pivot_table_generator::PivotTableGenerator,
+    repository_generator::RepositoryGenerator, request_generator::RequestGenerator, route_generator::RouteGenerator,
+    rule_generator::RuleGenerator, Generator, GeneratorKind,
+};
+use crate::types::{Config, MigrationMode, ModelDefinition, PivotTable};
+use crate::validation::Validator;
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A file rendered in memory, not yet written
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedFile {
+    pub path: String,
+    pub content: String,
+    /// Progress line printed once the file is written (`Generated model: User`)
+    pub message: String,
+    /// Component name as accepted by `--only` (e.g. `models`)
+    pub component: &'static str,
+    /// Model the file was generated for; `None` for files shared by every model
+    pub model: Option<String>,
+}
+
+/// Hooks a caller can thread through [`render_with`]
+#[derive(Clone, Copy, Default)]
+pub struct RenderOptions<'a> {
+    /// Threads the models are spread over; files come back in model order whatever the count
+    pub jobs: usize,
+    /// Leaves out the files of every model it returns `true` for, pivot tables included
+    pub skip_model: Option<&'a (dyn Fn(&ModelDefinition) -> bool + Sync)>,
+    /// Receives the wall-clock time spent on each component, once per render call
+    pub on_timing: Option<&'a (dyn Fn(&'static str, Duration) + Sync)>,
+}
+
+impl RenderOptions<'_> {
+    fn skips(&self, model: &ModelDefinition) -> bool {
+        self.skip_model.is_some_and(|skip| skip(model))
+    }
+
+    /// Runs `render`, reporting its time to `on_timing` under `component`
+    fn timed<T>(&self, component: &'static str, render: impl FnOnce() -> Result<T>) -> Result<T> {
+        let Some(on_timing) = self.on_timing else {
+            return render();
+        };
+        let started = Instant::now();
+        let result = render();
+        on_timing(component, started.elapsed());
+        result
+    }
+}
+
+/// Renders every enabled component of `config` in write order: pivot tables, each model's
+/// files, then the routes include, custom validation rules and the GraphQL schema
+///
+/// Migrations are timestamped from this call, in foreign-key order.
+pub fn render_all(config: &Config) -> Result<Vec<RenderedFile>> {
+    render_with(config, RenderOptions::default())
+}
+
+/// [`render_all`] as a map of file path to content, writing nothing
+pub fn render_to_map(config: &Config) -> Result<HashMap<String, String>> {
+    Ok(render_all(config)?
+        .into_iter()
+        .map(|file| (file.path, file.content))
+        .collect())
+}
+
+/// [`render_all`] with the threads, model filter and timing hook of `options`
+pub fn render_with(config: &Config, options: RenderOptions) -> Result<Vec<RenderedFile>> {
+    let config = &config.for_migration_run();
+    let mut files = Vec::new();
+
+    if config.generate_pivot_tables {
+        for (model, pivot_table) in RelationshipHelper::pivot_tables(&config.models) {
+            if !options.skips(model) {
+                files.push(options.timed("pivot", || render_pivot_table(model, &pivot_table, config))?);
+            }
+        }
+    }
+
+    files.extend(render_models(config, options)?);
+
+    // One routes include covers every controller
+    if config.generate_routes && config.generate_controllers && !config.models.is_empty() {
+        files.push(options.timed("routes", || render_routes(config))?);
+    }
+
+    // Custom validation rules are shared across models
+    if config.generate_validation_rules {
+        files.extend(options.timed("rules", || render_rules(config))?);
+    }
+
+    // One combined GraphQL schema covers every model
+    if config.generate_graphql && !config.models.is_empty() {
+        files.push(options.timed("graphql", || render_graphql(config))?);
+    }
+
+    Ok(files)
+}
+
+/// Renders each model's files, spreading the models over `options.jobs` threads
+fn render_models(config: &Config, options: RenderOptions) -> Result<Vec<RenderedFile>> {
+    let models: Vec<&ModelDefinition> = config.models.iter()
+        .filter(|model| !options.skips(model))
+        .collect();
+    if options.jobs <= 1 || models.len() <= 1 {
+        let mut files = Vec::new();
+        for model in models {
+            files.extend(render_model(model, config, options)?);
+        }
+        return Ok(files);
+    }
+
+    let chunk_size = models.len().div_ceil(options.jobs);
+    let chunks: Vec<Result<Vec<RenderedFile>>> = thread::scope(|scope| {
+        let handles: Vec<_> = models.chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || {
+                let mut files = Vec::new();
+                for model in chunk {
+                    files.extend(render_model(model, config, options)?);
+                }
+                Ok(files)
+            }))
+            .collect();
+        handles.into_iter()
+            .map(|handle| handle.join().expect("render thread panicked"))
+            .collect()
+    });
+
+    let mut files = Vec::new();
+    for chunk in chunks {
+        files.extend(chunk?);
+    }
+    Ok(files)
+}
+
+/// Validates `model` and renders its enabled per-model components and enums
+pub fn render_model(model: &ModelDefinition, config: &Config, options: RenderOptions) -> Result<Vec<RenderedFile>> {
+    Validator::validate_model(model)?;
+    let mut files = Vec::new();
+    for kind in GeneratorKind::ALL.into_iter().filter(|kind| kind.is_enabled(config)) {
+        files.extend(options.timed(kind.component(), || render_component(kind, model, config))?);
+    }
+    if config.generate_enums {
+        files.extend(options.timed("enums", || render_enums(model, config))?);
+    }
+    Ok(files)
+}
+
+/// Files one per-model component produces for `model`
+///
+/// Requests come as a store and an update file, repositories as an interface and a class,
+/// and an alter migration is left out when the model has no new column.
+pub fn render_component(kind: GeneratorKind, model: &ModelDefinition, config: &Config) -> Result<Vec<RenderedFile>> {
+    log::debug!("Rendering {} for {}", kind.component(), model.name);
+    let file = |path, content, message| RenderedFile {
+        path,
+        content,
+        message,
+        component: kind.component(),
+        model: Some(model.name.clone()),
+    };
+
+    match kind {
+        GeneratorKind::Migration
+            if config.migration_mode == MigrationMode::Alter && !model.fields.iter().any(|field| field.is_new) =>
+        {
+            Ok(Vec::new())
+        }
+        // Store and update requests come from one generator
+        GeneratorKind::Request => {
+            let generator = RequestGenerator;
+            ["Store", "Update"].into_iter()
+                .map(|prefix| {
+                    let action = prefix.to_lowercase();
+                    Ok(file(
+                        generator.get_file_path_action(model, config, &action),
+                        generator.generate_action(model, config, &action)?,
+                        format!("Generated request: {}{}Request", prefix, model.name),
+                    ))
+                })
+                .collect()
+        }
+        GeneratorKind::Repository => {
+            let generator = RepositoryGenerator;
+            Ok(vec![
+                file(
+                    generator.get_interface_file_path(model, config),
+                    generator.generate_interface(model, config)?,
+                    format!("Generated repository interface: {}RepositoryInterface", model.name),
+                ),
+                file(generator.get_file_path(model, config), generator.generate(model, config)?, generated_message(kind, model)),
+            ])
+        }
+        _ => {
+            let generator = kind.generator();
+            Ok(vec![file(generator.get_file_path(model, config), generator.generate(model, config)?, generated_message(kind, model))])
+        }
+    }
+}
+
+/// Progress line printed once a component's file is written
+fn generated_message(kind: GeneratorKind, model: &ModelDefinition) -> String {
+    match kind {
+        GeneratorKind::Model => format!("Generated model: {}", model.name),
+        GeneratorKind::Migration => format!("Generated migration for table: {}", model.table),
+        GeneratorKind::Controller => format!("Generated controller: {}Controller", model.name),
+        GeneratorKind::Resource => format!("Generated resource: {}", ClassNameResolver::resource_class(model)),
+        GeneratorKind::Factory => format!("Generated factory: {}", ClassNameResolver::factory_class(model)),
+        GeneratorKind::Dto => format!("Generated DTO: {}", ClassNameResolver::dto_class(model)),
+        GeneratorKind::Repository => format!("Generated repository: {}Repository", model.name),
+        GeneratorKind::Service => format!("Generated service: {}Service", model.name),
+        GeneratorKind::Seeder => format!("Generated seeder: {}Seeder", model.name),
+        GeneratorKind::Request => format!("Generated request: Store{}Request", model.name),
+    }
+}
+
+/// Enum classes for the model's enum fields
+pub fn render_enums(model: &ModelDefinition, config: &Config) -> Result<Vec<RenderedFile>> {
+    let generator = EnumGenerator;
+    EnumGenerator::enum_fields(model)
+        .map(|field| Ok(RenderedFile {
+            path: generator.get_enum_file_path(model, field, config),
+            content: generator.generate_enum(model, field, config)?,
+            message: format!("Generated enum: {}", ClassNameResolver::enum_class(model, field)),
+            component: "enums",
+            model: Some(model.name.clone()),
+        }))
+        .collect()
+}
+
+/// The migration for one pivot table, listed under `model`
+pub fn render_pivot_table(model: &ModelDefinition, pivot_table: &PivotTable, config: &Config) -> Result<RenderedFile> {
+    let generator = PivotTableGenerator;
+    Ok(RenderedFile {
+        path: generator.get_pivot_file_path(pivot_table, config),
+        content: generator.generate_pivot_table(pivot_table, config)?,
+        message: format!("Generated pivot table: {}", pivot_table.name),
+        component: "pivot",
+        model: Some(model.name.clone()),
+    })
+}
+
+/// The routes include covering every model's controller
+pub fn render_routes(config: &Config) -> Result<RenderedFile> {
+    Ok(RenderedFile {
+        path: PathResolver::get_routes_path(config),
+        content: RouteGenerator.generate_routes_file(&config.models, config)?,
+        message: "Generated routes: routes/api_generated.php".to_string(),
+        component: "routes",
+        model: None,
+    })
+}
+
+/// Custom validation rule classes shared across models
+pub fn render_rules(config: &Config) -> Result<Vec<RenderedFile>> {
+    let generator = RuleGenerator;
+    RuleGenerator::custom_rules(config).into_iter()
+        .map(|rule_name| Ok(RenderedFile {
+            path: generator.get_rule_file_path(&rule_name, config),
+            content: generator.generate_rule(&rule_name, config)?,
+            message: format!("Generated validation rule: {}", rule_name),
+            component: "rules",
+            model: None,
+        }))
+        .collect()
+}
+
+/// The combined GraphQL schema covering every model
+pub fn render_graphql(config: &Config) -> Result<RenderedFile> {
+    Ok(RenderedFile {
+        path: PathResolver::get_graphql_schema_path(config),
+        content: GraphQlGenerator.generate_schema(&config.models, config)?,
+        message: "Generated GraphQL schema: schema.graphql".to_string(),
+        component: "graphql",
+        model: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Field, FieldType};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_to_map_writes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_dir = temp_dir.path().join("app");
+        let root = output_dir.to_str().unwrap().to_string();
+        let model = |name: &str, table: &str| ModelDefinition {
+            name: name.to_string(),
+            table: table.to_string(),
+            fields: vec![Field::new("title", FieldType::String)],
+            ..Default::default()
+        };
+        let config = Config {
+            models: vec![model("User", "users"), model("Post", "posts")],
+            output_dir: root.clone(),
+            generate_migrations: false,
+            ..Config::default()
+        };
+
+        let files = render_to_map(&config).unwrap();
+        let mut paths: Vec<&Path> = files.keys().map(|path| Path::new(path).strip_prefix(&root).unwrap()).collect();
+        paths.sort();
+        assert_eq!(paths, [
+            "app/Http/Controllers/PostController.php",
+            "app/Http/Controllers/UserController.php",
+            "app/Http/Resources/PostResource.php",
+            "app/Http/Resources/UserResource.php",
+            "app/Models/Post.php",
+            "app/Models/User.php",
+            "database/factories/PostFactory.php",
+            "database/factories/UserFactory.php",
+        ].map(Path::new));
+        let user = files.iter().find(|(path, _)| Path::new(path).ends_with("app/Models/User.php")).unwrap().1;
+        assert!(user.contains("class User extends Model"));
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    fn test_render_component_splits_requests_and_repositories() {
+        let model = ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            fields: vec![Field::new("title", FieldType::String)],
+            ..Default::default()
+        };
+        let config = Config::default();
+
+        let requests = render_component(GeneratorKind::Request, &model, &config).unwrap();
+        assert_eq!(requests.iter().map(|file| file.message.as_str()).collect::<Vec<_>>(),
+                   ["Generated request: StorePostRequest", "Generated request: UpdatePostRequest"]);
+
+        let repositories = render_component(GeneratorKind::Repository, &model, &config).unwrap();
+        assert!(repositories[0].content.contains("interface PostRepositoryInterface"));
+        assert!(repositories[1].content.contains("class PostRepository implements"));
+
+        let config = Config { migration_mode: MigrationMode::Alter, ..Config::default() };
+        assert!(render_component(GeneratorKind::Migration, &model, &config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parallel_rendering_matches_sequential() {
+        let models = (0..12)
+            .map(|i| ModelDefinition {
+                name: format!("Model{}", i),
+                table: format!("model{}s", i),
+                fields: vec![Field::new("title", FieldType::String)],
+                ..Default::default()
+            })
+            .collect();
+        // Migration file names embed the current time, so leave them out of the comparison
+        let config = Config { models, generate_migrations: false, generate_dto: true, generate_services: true, ..Config::default() };
+
+        let render = |jobs| {
+            render_with(&config, RenderOptions { jobs, ..RenderOptions::default() }).unwrap().into_iter()
+                .map(|file| (file.path, file.content))
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = render(1);
+        assert!(sequential.len() > 12);
+        assert_eq!(sequential, render(4));
+        assert_eq!(sequential, render(64));
+    }
+
+    #[test]
+    fn test_skipped_models_and_timings() {
+        let post = ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            fields: vec![Field::new("title", FieldType::String)],
+            ..Default::default()
+        };
+        let user = ModelDefinition { name: "User".to_string(), table: "users".to_string(), ..post.clone() };
+        let config = Config { models: vec![post, user], ..Config::default() };
+        let timings = std::sync::Mutex::new(Vec::new());
+
+        let files = render_with(&config, RenderOptions {
+            skip_model: Some(&|model: &ModelDefinition| model.name == "User"),
+            on_timing: Some(&|component, _| timings.lock().unwrap().push(component)),
+            ..RenderOptions::default()
+        }).unwrap();
+
+        assert!(files.iter().all(|file| file.model.as_deref() != Some("User")));
+        assert!(files.iter().any(|file| file.model.as_deref() == Some("Post")));
+        let timings = timings.into_inner().unwrap();
+        for file in &files {
+            assert!(timings.contains(&file.component), "no timing for {}", file.component);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::error::Result;
+use crate::generators::Generator;
+use crate::generators::shared::{PathResolver, NamespaceResolver, NameHelper, GeneratedMarker, FieldTypeHelper};
+use crate::types::{Config, ModelDefinition};
+
+/// Generator for repository-pattern classes
+///
+/// Produces two files per model: a `{Model}RepositoryInterface` and the Eloquent
+/// `{Model}Repository` implementing it. `generate` renders the implementation;
+/// use `generate_interface` for the contract.
+pub struct RepositoryGenerator;
+
+impl Generator for RepositoryGenerator {
+    fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let variable = NameHelper::camel_case(&model.name);
+        let key_type = FieldTypeHelper::key_type_hint(model);
+        let mut content = String::new();
+
+        content.push_str(&GeneratedMarker::php_header(config));
+        let namespace = NamespaceResolver::get_repository_namespace(model, config);
+        content.push_str(&format!("namespace {};\n\n", namespace));
+        content.push_str(&self.build_imports(model, config));
+
+        content.push_str(&format!("class {}Repository implements {}RepositoryInterface\n{{\n", model.name, model.name));
+
+        content.push_str("    public function all(): Collection\n    {\n");
+        content.push_str(&format!("        return {}::all();\n", model.name));
+        content.push_str("    }\n\n");
+
+        content.push_str(&format!("    public function find({} $id): ?{}\n    {{\n", key_type, model.name));
+        content.push_str(&format!("        return {}::find($id);\n", model.name));
+        content.push_str("    }\n\n");
+
+        content.push_str(&format!("    public function create(array $data): {}\n    {{\n", model.name));
+        content.push_str(&format!("        return {}::create($data);\n", model.name));
+        content.push_str("    }\n\n");
+
+        content.push_str(&format!("    public function update({} ${}, array $data): {}\n    {{\n", model.name, variable, model.name));
+        content.push_str(&format!("        ${}->update($data);\n\n", variable));
+        content.push_str(&format!("        return ${};\n", variable));
+        content.push_str("    }\n\n");
+
+        content.push_str(&format!("    public function delete({} ${}): bool\n    {{\n", model.name, variable));
+        content.push_str(&format!("        return (bool) ${}->delete();\n", variable));
+        content.push_str("    }\n");
+
+        content.push_str("}\n");
+        Ok(content)
+    }
+
+    fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
+        PathResolver::get_repository_path(model, config)
+    }
+}
+
+impl RepositoryGenerator {
+    /// Renders the `{Model}RepositoryInterface` contract
+    pub fn generate_interface(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let variable = NameHelper::camel_case(&model.name);
+        let key_type = FieldTypeHelper::key_type_hint(model);
+        let mut content = String::new();
+
+        content.push_str(&GeneratedMarker::php_header(config));
+        let namespace = NamespaceResolver::get_repository_namespace(model, config);
+        content.push_str(&format!("namespace {};\n\n", namespace));
+        content.push_str(&self.build_imports(model, config));
+
+        content.push_str(&format!("interface {}RepositoryInterface\n{{\n", model.name));
+        content.push_str("    public function all(): Collection;\n\n");
+        content.push_str(&format!("    public function find({} $id): ?{};\n\n", key_type, model.name));
+        content.push_str(&format!("    public function create(array $data): {};\n\n", model.name));
+        content.push_str(&format!("    public function update({} ${}, array $data): {};\n\n", model.name, variable, model.name));
+        content.push_str(&format!("    public function delete({} ${}): bool;\n", model.name, variable));
+        content.push_str("}\n");
+
+        Ok(content)
+    }
+
+    pub fn get_interface_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
+        PathResolver::get_repository_interface_path(model, config)
+    }
+
+    fn build_imports(&self, model: &ModelDefinition, config: &Config) -> String {
+        let model_namespace = NamespaceResolver::get_model_namespace(model, config);
+        format!(
+            "use {}\\{};\nuse Illuminate\\Database\\Eloquent\\Collection;\n\n",
+            model_namespace, model.name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Field, FieldType};
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "BlogPost".to_string(),
+            table: "blog_posts".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_interface_method_list() {
+        let result = RepositoryGenerator.generate_interface(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("namespace App\\Repositories;"));
+        assert!(result.contains("use App\\Models\\BlogPost;"));
+        assert!(result.contains("interface BlogPostRepositoryInterface"));
+        assert!(result.contains("public function all(): Collection;"));
+        assert!(result.contains("public function find(int $id): ?BlogPost;"));
+        assert!(result.contains("public function create(array $data): BlogPost;"));
+        assert!(result.contains("public function update(BlogPost $blogPost, array $data): BlogPost;"));
+        assert!(result.contains("public function delete(BlogPost $blogPost): bool;"));
+    }
+
+    #[test]
+    fn test_implementation_find_body() {
+        let result = RepositoryGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("class BlogPostRepository implements BlogPostRepositoryInterface"));
+        assert!(result.contains("    public function find(int $id): ?BlogPost\n    {\n        return BlogPost::find($id);\n    }"));
+    }
+
+    #[test]
+    fn test_uuid_key_found_by_string() {
+        let model = ModelDefinition {
+            fields: vec![Field { primary: true, ..Field::new("uuid", FieldType::Uuid) }],
+            ..create_test_model()
+        };
+
+        let interface = RepositoryGenerator.generate_interface(&model, &Config::default()).unwrap();
+        let implementation = RepositoryGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(interface.contains("public function find(string $id): ?BlogPost;"));
+        assert!(implementation.contains("public function find(string $id): ?BlogPost\n"));
+    }
+
+    #[test]
+    fn test_ddd_namespace() {
+        let config = Config {
+            use_ddd_structure: true,
+            ..Config::default()
+        };
+        let result = RepositoryGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("namespace App\\Domain\\BlogPost\\Repositories;"));
+        assert!(result.contains("use App\\Domain\\BlogPost\\Models\\BlogPost;"));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::generators::Generator;
-use crate::generators::shared::{PathResolver, NamespaceResolver};
+use crate::generators::shared::{FieldTypeHelper, NamespaceResolver, PathResolver, GeneratedMarker};
 use crate::types::{Config, ModelDefinition};
 use crate::validation::Validator;
-use crate::template::{TemplateContext, TemplateRenderer};
+use crate::template::{CompiledTemplate, TemplateContext};
+use std::sync::OnceLock;
 use serde::{Deserialize, Serialize};
 
 // Type aliases for better readability
@@ -16,15 +17,18 @@ pub struct RequestGenerator;
 
 // Template constants
 const TEMPLATE: &str = include_str!("../templates/request.php.template");
+static COMPILED_TEMPLATE: OnceLock<CompiledTemplate> = OnceLock::new();
 
 // Template variable names
 mod template_vars {
+    pub const PHP_HEADER: &str = "php_header";
     pub const NAMESPACE: &str = "namespace";
     pub const REQUEST_NAME: &str = "request_name";
     pub const RULES: &str = "rules";
 }
 
 const REQUIRED_TEMPLATE_VARS: &[&str] = &[
+    template_vars::PHP_HEADER,
     template_vars::NAMESPACE,
     template_vars::REQUEST_NAME,
     template_vars::RULES,
@@ -86,14 +90,15 @@ impl RequestGenerator {
         let prefix = if action == "store" { "Store" } else { "Update" };
         let request_name = format!("{}{}", prefix, &model.name);
         
-        let rules = self.generate_rules(model, action)?;
+        let rules = self.generate_rules(model, config, action)?;
         let mut rules_str = String::new();
         for rule in &rules {
             rules_str.push_str(&format!("'{}' => '{}',\n            ", rule.field, rule.validation));
         }
 
         let context = TemplateContext::new()
-            .with(template_vars::NAMESPACE, format!("\\{}", namespace))
+            .with(template_vars::PHP_HEADER, GeneratedMarker::php_header(config))
+            .with(template_vars::NAMESPACE, namespace)
             .with(template_vars::REQUEST_NAME, request_name)
             .with(template_vars::RULES, rules_str.trim_end());
 
@@ -102,15 +107,12 @@ impl RequestGenerator {
 
     /// Renders the Request template with the provided context
     fn render_template(&self, context: &TemplateContext) -> GeneratorResult<String> {
-        TemplateRenderer::render_with_required_vars(
-            TEMPLATE,
-            context,
-            REQUIRED_TEMPLATE_VARS
-        )
+        CompiledTemplate::cached(&COMPILED_TEMPLATE, TEMPLATE)?
+            .render_with_required_vars(context, REQUIRED_TEMPLATE_VARS)
     }
 
     /// Generates rules list
-    fn generate_rules(&self, model: &ModelDefinition, action: &str) -> GeneratorResult<Vec<RuleContext>> {
+    fn generate_rules(&self, model: &ModelDefinition, config: &Config, action: &str) -> GeneratorResult<Vec<RuleContext>> {
         let mut rules = Vec::new();
 
         for field in &model.fields {
@@ -136,7 +138,8 @@ impl RequestGenerator {
                         field_rules.push(format!("max:{}", len));
                     }
                 }
-                crate::types::FieldType::Integer | crate::types::FieldType::BigInteger | crate::types::FieldType::TinyInteger => {
+                crate::types::FieldType::Integer | crate::types::FieldType::BigInteger | crate::types::FieldType::TinyInteger
+                    | crate::types::FieldType::SmallInteger | crate::types::FieldType::MediumInteger => {
                     field_rules.push("integer".to_string());
                 }
                 crate::types::FieldType::Float | crate::types::FieldType::Decimal => {
@@ -148,6 +151,13 @@ impl RequestGenerator {
                 crate::types::FieldType::Date => {
                     field_rules.push("date".to_string());
                 }
+                crate::types::FieldType::Year => {
+                    field_rules.push("integer".to_string());
+                    field_rules.push("digits:4".to_string());
+                }
+                crate::types::FieldType::Time => {
+                    field_rules.push("date_format:H:i:s".to_string());
+                }
                 crate::types::FieldType::DateTime | crate::types::FieldType::Timestamp => {
                     field_rules.push("date".to_string());
                 }
@@ -160,6 +170,13 @@ impl RequestGenerator {
                 _ => {}
             }
 
+            // Opt-in lower bound for unsigned columns, unless the schema sets its own
+            if config.unsigned_min_rule && field.unsigned
+                && FieldTypeHelper::numeric_rule(&field.field_type).is_some()
+                && !field.validation_rules.iter().any(|r| r.rule.starts_with("min:")) {
+                field_rules.push("min:0".to_string());
+            }
+
             // Expose user defined rules from the schema (@validate(...))
             for custom_rule in &field.validation_rules {
                 field_rules.push(custom_rule.rule.clone());
@@ -176,3 +193,35 @@ impl RequestGenerator {
         Ok(rules)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Field, FieldType};
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "Product".to_string(),
+            table: "products".to_string(),
+            fields: vec![Field { unsigned: true, ..Field::new("quantity", FieldType::Integer) }],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unsigned_field_gains_min_rule() {
+        let config = Config { unsigned_min_rule: true, ..Config::default() };
+        let result = RequestGenerator.generate_action(&create_test_model(), &config, "store").unwrap();
+
+        assert!(result.contains("'quantity' => 'required|integer|min:0',"));
+    }
+
+    #[test]
+    fn test_unsigned_min_rule_is_opt_in() {
+        let result = RequestGenerator.generate_action(&create_test_model(), &Config::default(), "store").unwrap();
+
+        assert!(result.contains("'quantity' => 'required|integer',"));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::generators::Generator;
-use crate::generators::shared::{PathResolver, NamespaceResolver};
-use crate::types::{Config, ModelDefinition};
+use crate::generators::shared::{PathResolver, NamespaceResolver, ClassNameResolver, GeneratedMarker};
+use crate::types::{Config, FieldType, ModelDefinition};
 
 pub struct ResourceGenerator;
 
@@ -9,13 +9,13 @@ impl Generator for ResourceGenerator {
     fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
         let mut content = String::new();
 
-        content.push_str("<?php\n\n");
+        content.push_str(&GeneratedMarker::php_header(config));
         let namespace = NamespaceResolver::get_resource_namespace(model, config);
         content.push_str(&format!("namespace {};\n\n", namespace));
         content.push_str("use Illuminate\\Http\\Request;\n");
         content.push_str("use Illuminate\\Http\\Resources\\Json\\JsonResource;\n\n");
 
-        content.push_str(&format!("class {}Resource extends JsonResource\n{{\n", model.name));
+        content.push_str(&format!("class {} extends JsonResource\n{{\n", ClassNameResolver::resource_class(model)));
         content.push_str("    public function toArray(Request $request): array\n    {\n");
         content.push_str("        return [\n");
 
@@ -25,19 +25,20 @@ impl Generator for ResourceGenerator {
         // Include all fields
         for field in &model.fields {
             if field.name != "id" {
-                content.push_str(&format!("            '{}' => $this->{},\n", field.name, field.name));
+                let is_timestamp = matches!(field.field_type, FieldType::DateTime | FieldType::Timestamp);
+                content.push_str(&self.build_attribute(&field.name, is_timestamp, config));
             }
         }
 
         // Include timestamps if enabled
-        if model.timestamps {
-            content.push_str("            'created_at' => $this->created_at,\n");
-            content.push_str("            'updated_at' => $this->updated_at,\n");
+        if model.has_timestamps(config) {
+            content.push_str(&self.build_attribute("created_at", true, config));
+            content.push_str(&self.build_attribute("updated_at", true, config));
         }
 
-        // Include soft delete timestamp if enabled
-        if model.soft_deletes {
-            content.push_str("            'deleted_at' => $this->deleted_at,\n");
+        // Soft delete timestamp only when the API exposes it
+        if model.has_soft_deletes(config) && config.resource_include_deleted_at {
+            content.push_str(&self.build_attribute("deleted_at", true, config));
         }
 
         content.push_str("        ];\n");
@@ -50,4 +51,59 @@ impl Generator for ResourceGenerator {
     fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
         PathResolver::get_resource_path(model, config)
     }
+}
+
+impl ResourceGenerator {
+    /// One `'name' => $this->name` entry, ISO-8601 formatted for timestamps when enabled
+    fn build_attribute(&self, name: &str, is_timestamp: bool, config: &Config) -> String {
+        if is_timestamp && config.resource_iso_timestamps {
+            format!("            '{}' => $this->{}?->toIso8601String(),\n", name, name)
+        } else {
+            format!("            '{}' => $this->{},\n", name, name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_raw_timestamps_by_default() {
+        let result = ResourceGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("            'created_at' => $this->created_at,\n"));
+        assert!(result.contains("            'updated_at' => $this->updated_at,\n"));
+        assert!(!result.contains("toIso8601String"));
+    }
+
+    #[test]
+    fn test_deleted_at_hidden_by_default() {
+        let result = ResourceGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+        assert!(!result.contains("deleted_at"));
+
+        let config = Config { resource_include_deleted_at: true, ..Config::default() };
+        let result = ResourceGenerator.generate(&create_test_model(), &config).unwrap();
+        assert!(result.contains("            'deleted_at' => $this->deleted_at,\n"));
+    }
+
+    #[test]
+    fn test_iso_timestamps_when_enabled() {
+        let config = Config { resource_iso_timestamps: true, resource_include_deleted_at: true, ..Config::default() };
+        let result = ResourceGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("            'created_at' => $this->created_at?->toIso8601String(),\n"));
+        assert!(result.contains("            'updated_at' => $this->updated_at?->toIso8601String(),\n"));
+        assert!(result.contains("            'deleted_at' => $this->deleted_at?->toIso8601String(),\n"));
+    }
 }
\ No newline at end of file
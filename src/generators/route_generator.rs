@@ -0,0 +1,145 @@
+use crate::error::Result;
+use crate::generators::Generator;
+use crate::generators::shared::{PathResolver, NamespaceResolver, NameHelper, GeneratedMarker};
+use crate::types::{Config, ModelDefinition, RouteCase, RouteStyle};
+
+/// Generator for `Route::apiResource` entries, or the equivalent per-verb routes
+///
+/// All models share one `routes/api_generated.php` include so the user's own
+/// `routes/api.php` is never overwritten.
+pub struct RouteGenerator;
+
+impl Generator for RouteGenerator {
+    fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let uri = Self::resource_uri(model, config.route_case);
+        let controller = format!("{}Controller::class", model.name);
+        if config.route_style == RouteStyle::ApiResource {
+            return Ok(format!("Route::apiResource('{}', {});\n", uri, controller));
+        }
+
+        // Same URIs, parameter and route names that apiResource registers
+        let member = format!("{}/{{{}}}", uri, NameHelper::snake_case(&model.name));
+        let routes = [
+            ("get", &uri, "index"),
+            ("post", &uri, "store"),
+            ("get", &member, "show"),
+            ("put", &member, "update"),
+            ("delete", &member, "destroy"),
+        ];
+        Ok(routes.iter()
+            .map(|(verb, path, action)| format!(
+                "Route::{}('{}', [{}, '{}'])->name('{}.{}');\n",
+                verb, path, controller, action, uri, action
+            ))
+            .collect())
+    }
+
+    fn get_file_path(&self, _model: &ModelDefinition, config: &Config) -> String {
+        PathResolver::get_routes_path(config)
+    }
+}
+
+impl RouteGenerator {
+    /// Renders the combined routes file for a set of models
+    pub fn generate_routes_file(&self, models: &[ModelDefinition], config: &Config) -> Result<String> {
+        let mut imports: Vec<String> = models.iter()
+            .map(|model| format!(
+                "use {}\\{}Controller;",
+                NamespaceResolver::get_controller_namespace(model, config), model.name
+            ))
+            .collect();
+        imports.push("use Illuminate\\Support\\Facades\\Route;".to_string());
+        imports.sort();
+
+        let mut content = String::new();
+        content.push_str(&GeneratedMarker::php_header(config));
+        content.push_str(&imports.join("\n"));
+        content.push_str("\n\n");
+        content.push_str("// Generated by Schemly. Include this file from routes/api.php:\n");
+        content.push_str("// require __DIR__.'/api_generated.php';\n\n");
+
+        for model in models {
+            content.push_str(&self.generate(model, config)?);
+        }
+
+        Ok(content)
+    }
+
+    /// Pluralized URI segment for a model (e.g. `BlogPost` -> `blog-posts`)
+    fn resource_uri(model: &ModelDefinition, route_case: RouteCase) -> String {
+        let plural = NameHelper::pluralize(&NameHelper::snake_case(&model.name));
+        match route_case {
+            RouteCase::Kebab => NameHelper::kebab_case(&plural),
+            RouteCase::Snake => plural,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_model(name: &str) -> ModelDefinition {
+        ModelDefinition {
+            name: name.to_string(),
+            table: format!("{}s", name.to_lowercase()),
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_routes_file_for_user() {
+        let result = RouteGenerator.generate_routes_file(&[create_test_model("User")], &Config::default()).unwrap();
+
+        assert!(result.contains("use App\\Http\\Controllers\\UserController;"));
+        assert!(result.contains("use Illuminate\\Support\\Facades\\Route;"));
+        assert!(result.contains("Route::apiResource('users', UserController::class);"));
+    }
+
+    #[test]
+    fn test_route_case() {
+        let model = create_test_model("BlogCategory");
+
+        let kebab = RouteGenerator.generate(&model, &Config::default()).unwrap();
+        assert_eq!(kebab, "Route::apiResource('blog-categories', BlogCategoryController::class);\n");
+
+        let config = Config {
+            route_case: RouteCase::Snake,
+            ..Config::default()
+        };
+        let snake = RouteGenerator.generate(&model, &config).unwrap();
+        assert_eq!(snake, "Route::apiResource('blog_categories', BlogCategoryController::class);\n");
+    }
+
+    #[test]
+    fn test_explicit_route_style() {
+        let config = Config {
+            route_style: RouteStyle::Explicit,
+            ..Config::default()
+        };
+        let result = RouteGenerator.generate_routes_file(&[create_test_model("BlogPost")], &config).unwrap();
+
+        let routes: Vec<&str> = result.lines().filter(|line| line.starts_with("Route::")).collect();
+        assert_eq!(routes, [
+            "Route::get('blog-posts', [BlogPostController::class, 'index'])->name('blog-posts.index');",
+            "Route::post('blog-posts', [BlogPostController::class, 'store'])->name('blog-posts.store');",
+            "Route::get('blog-posts/{blog_post}', [BlogPostController::class, 'show'])->name('blog-posts.show');",
+            "Route::put('blog-posts/{blog_post}', [BlogPostController::class, 'update'])->name('blog-posts.update');",
+            "Route::delete('blog-posts/{blog_post}', [BlogPostController::class, 'destroy'])->name('blog-posts.destroy');",
+        ]);
+        assert!(!result.contains("apiResource"));
+    }
+
+    #[test]
+    fn test_ddd_controller_import() {
+        let config = Config {
+            use_ddd_structure: true,
+            ..Config::default()
+        };
+        let result = RouteGenerator.generate_routes_file(&[create_test_model("User")], &config).unwrap();
+
+        assert!(result.contains("use App\\Domain\\User\\Controllers\\UserController;"));
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeSet;
+
+use crate::error::Result;
+use crate::generators::shared::{PathResolver, NameHelper, GeneratedMarker};
+use crate::types::{Config, ValidationRule};
+use crate::validation::Validator;
+
+/// Laravel's built-in validation rule names
+const BUILT_IN_RULES: &[&str] = &[
+    "accepted", "accepted_if", "active_url", "after", "after_or_equal", "alpha", "alpha_dash",
+    "alpha_num", "array", "ascii", "bail", "before", "before_or_equal", "between", "boolean",
+    "confirmed", "current_password", "date", "date_equals", "date_format", "decimal", "declined",
+    "declined_if", "different", "digits", "digits_between", "dimensions", "distinct",
+    "doesnt_end_with", "doesnt_start_with", "email", "ends_with", "enum", "exclude", "exclude_if",
+    "exclude_unless", "exclude_with", "exclude_without", "exists", "extensions", "file", "filled",
+    "gt", "gte", "hex_color", "image", "in", "in_array", "integer", "ip", "ipv4", "ipv6", "json",
+    "list", "lowercase", "lt", "lte", "mac_address", "max", "max_digits", "mimes", "mimetypes",
+    "min", "min_digits", "missing", "missing_if", "missing_unless", "missing_with",
+    "missing_with_all", "multiple_of", "not_in", "not_regex", "nullable", "numeric", "present",
+    "present_if", "present_unless", "present_with", "present_with_all", "prohibited",
+    "prohibited_if", "prohibited_unless", "prohibits", "regex", "required", "required_array_keys",
+    "required_if", "required_if_accepted", "required_unless", "required_with", "required_with_all",
+    "required_without", "required_without_all", "same", "size", "sometimes", "starts_with",
+    "string", "timezone", "ulid", "unique", "uppercase", "url", "uuid",
+];
+
+/// Generator for custom validation rule classes
+///
+/// Any rule referenced in `validation_rules` that isn't one of Laravel's built-in
+/// rules gets an `app/Rules/{RuleName}.php` class implementing `ValidationRule`.
+pub struct RuleGenerator;
+
+impl RuleGenerator {
+    /// Unique custom rule class names referenced anywhere in the config, sorted
+    pub fn custom_rules(config: &Config) -> Vec<String> {
+        let rules = config.models.iter().flat_map(|model| {
+            model.validation_rules.iter()
+                .chain(model.fields.iter().flat_map(|field| field.validation_rules.iter()))
+        });
+
+        rules
+            .map(Self::rule_name)
+            .filter(|name| !name.is_empty() && !Self::is_built_in(name))
+            .map(NameHelper::studly_case)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Whether a rule name (without parameters) is one of Laravel's own rules
+    pub fn is_built_in(name: &str) -> bool {
+        BUILT_IN_RULES.contains(&name)
+    }
+
+    /// Renders the `ValidationRule` class for a custom rule
+    pub fn generate_rule(&self, rule_name: &str, config: &Config) -> Result<String> {
+        Validator::validate_identifier(rule_name, "Rule name")?;
+
+        let mut content = String::new();
+
+        content.push_str(&GeneratedMarker::php_header(config));
+        content.push_str("namespace App\\Rules;\n\n");
+        content.push_str("use Closure;\n");
+        content.push_str("use Illuminate\\Contracts\\Validation\\ValidationRule;\n\n");
+
+        content.push_str(&format!("class {} implements ValidationRule\n{{\n", rule_name));
+        content.push_str("    public function validate(string $attribute, mixed $value, Closure $fail): void\n    {\n");
+        content.push_str(&format!("        // TODO: implement the {} rule\n", rule_name));
+        content.push_str("        // $fail('The :attribute is invalid.');\n");
+        content.push_str("    }\n");
+        content.push_str("}\n");
+
+        Ok(content)
+    }
+
+    pub fn get_rule_file_path(&self, rule_name: &str, config: &Config) -> String {
+        PathResolver::get_rule_path(rule_name, config)
+    }
+
+    /// Strips parameters from a rule string (`max:255` -> `max`)
+    fn rule_name(rule: &ValidationRule) -> &str {
+        rule.rule.split(':').next().unwrap_or_default().trim()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ModelDefinition;
+    use std::path::Path;
+
+    fn create_rule(rule: &str) -> ValidationRule {
+        ValidationRule {
+            rule: rule.to_string(),
+            parameters: None,
+        }
+    }
+
+    fn create_config(rules: Vec<ValidationRule>) -> Config {
+        Config {
+            models: vec![ModelDefinition {
+                name: "User".to_string(),
+                table: "users".to_string(),
+                timestamps: Some(true),
+                soft_deletes: Some(false),
+                validation_rules: rules,
+                ..Default::default()
+            }],
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_custom_rule_detection() {
+        let config = create_config(vec![
+            create_rule("required"),
+            create_rule("max:255"),
+            create_rule("Uppercase"),
+            create_rule("Uppercase"),
+        ]);
+
+        assert_eq!(RuleGenerator::custom_rules(&config), vec!["Uppercase".to_string()]);
+    }
+
+    #[test]
+    fn test_rule_class() {
+        let result = RuleGenerator.generate_rule("Uppercase", &Config::default()).unwrap();
+
+        assert!(result.contains("namespace App\\Rules;"));
+        assert!(result.contains("class Uppercase implements ValidationRule"));
+        assert!(result.contains("public function validate(string $attribute, mixed $value, Closure $fail): void"));
+        assert_eq!(
+            Path::new(&RuleGenerator.get_rule_file_path("Uppercase", &Config::default())),
+            Path::new("./app/Rules/Uppercase.php")
+        );
+    }
+}
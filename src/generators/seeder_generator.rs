@@ -0,0 +1,74 @@
+use crate::error::Result;
+use crate::generators::Generator;
+use crate::generators::shared::{PathResolver, NamespaceResolver, GeneratedMarker};
+use crate::types::{Config, ModelDefinition};
+
+/// Generator for `{Model}Seeder` classes
+///
+/// Each seeder creates the model's `seed_count` rows (config-wide `seed_count` by
+/// default) through its factory, so the model needs `HasFactory`.
+pub struct SeederGenerator;
+
+impl Generator for SeederGenerator {
+    fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let mut content = String::new();
+
+        content.push_str(&GeneratedMarker::php_header(config));
+        content.push_str(&format!("namespace {};\n\n", NamespaceResolver::get_seeder_namespace(model, config)));
+        content.push_str(&format!("use {}\\{};\n", NamespaceResolver::get_model_namespace(model, config), model.name));
+        content.push_str("use Illuminate\\Database\\Seeder;\n\n");
+
+        content.push_str(&format!("class {}Seeder extends Seeder\n{{\n", model.name));
+        content.push_str("    public function run(): void\n    {\n");
+        content.push_str(&format!("        {}::factory()->count({})->create();\n", model.name, model.seed_count(config)));
+        content.push_str("    }\n");
+        content.push_str("}\n");
+
+        Ok(content)
+    }
+
+    fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
+        PathResolver::get_seeder_path(model, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_seeder_uses_configured_count() {
+        let model = create_test_model();
+        let result = SeederGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("namespace Database\\Seeders;"));
+        assert!(result.contains("use App\\Models\\User;"));
+        assert!(result.contains("class UserSeeder extends Seeder"));
+        assert!(result.contains("        User::factory()->count(10)->create();\n"));
+        assert_eq!(Path::new(&SeederGenerator.get_file_path(&model, &Config::default())), Path::new("./database/seeders/UserSeeder.php"));
+
+        let config = Config { seed_count: 50, ..Config::default() };
+        let result = SeederGenerator.generate(&model, &config).unwrap();
+        assert!(result.contains("User::factory()->count(50)->create();"));
+    }
+
+    #[test]
+    fn test_model_seed_count_overrides_config() {
+        let model = ModelDefinition { seed_count: Some(3), ..create_test_model() };
+        let config = Config { seed_count: 50, ..Config::default() };
+        let result = SeederGenerator.generate(&model, &config).unwrap();
+
+        assert!(result.contains("User::factory()->count(3)->create();"));
+    }
+}
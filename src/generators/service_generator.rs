@@ -0,0 +1,188 @@
+use crate::error::Result;
+use crate::generators::Generator;
+use crate::generators::shared::{PathResolver, NamespaceResolver, NameHelper, ClassNameResolver, GeneratedMarker, FieldTypeHelper};
+use crate::types::{Config, ModelDefinition};
+
+/// Generator for service classes wrapping a model's CRUD operations
+///
+/// When repositories are enabled the service depends on `{Model}RepositoryInterface`,
+/// otherwise it is injected with the model itself. When DTOs are enabled every
+/// method hands back `{Model}DTO` instances instead of models.
+pub struct ServiceGenerator;
+
+impl Generator for ServiceGenerator {
+    fn generate(&self, model: &ModelDefinition, config: &Config) -> Result<String> {
+        let variable = NameHelper::camel_case(&model.name);
+        let return_type = if config.generate_dto {
+            ClassNameResolver::dto_class(model)
+        } else {
+            model.name.clone()
+        };
+        let dependency = if config.generate_repositories { "repository" } else { "model" };
+
+        let mut content = String::new();
+
+        content.push_str(&GeneratedMarker::php_header(config));
+        let namespace = NamespaceResolver::get_service_namespace(model, config);
+        content.push_str(&format!("namespace {};\n\n", namespace));
+
+        // Imports
+        content.push_str(&format!("use {}\\{};\n", NamespaceResolver::get_model_namespace(model, config), model.name));
+        if config.generate_dto {
+            content.push_str(&format!("use {}\\{};\n", NamespaceResolver::get_dto_namespace(model, config), return_type));
+        }
+        if config.generate_repositories {
+            content.push_str(&format!(
+                "use {}\\{}RepositoryInterface;\n",
+                NamespaceResolver::get_repository_namespace(model, config), model.name
+            ));
+        }
+        content.push_str("use Illuminate\\Support\\Collection;\n\n");
+
+        content.push_str(&format!("class {}Service\n{{\n", model.name));
+
+        // Constructor injection
+        let dependency_type = if config.generate_repositories {
+            format!("{}RepositoryInterface", model.name)
+        } else {
+            model.name.clone()
+        };
+        content.push_str("    public function __construct(\n");
+        content.push_str(&format!("        private {} ${}\n", dependency_type, dependency));
+        content.push_str("    ) {\n    }\n\n");
+
+        // list
+        content.push_str("    public function list(): Collection\n    {\n");
+        if config.generate_dto {
+            content.push_str(&format!(
+                "        return $this->{}->all()->map(fn ({} ${}) => {});\n",
+                dependency, model.name, variable, self.wrap(model, config, &format!("${}", variable))
+            ));
+        } else {
+            content.push_str(&format!("        return $this->{}->all();\n", dependency));
+        }
+        content.push_str("    }\n\n");
+
+        // get
+        content.push_str(&format!(
+            "    public function get({} $id): ?{}\n    {{\n",
+            FieldTypeHelper::key_type_hint(model), return_type
+        ));
+        content.push_str(&format!("        ${} = $this->{}->find($id);\n\n", variable, dependency));
+        content.push_str(&format!(
+            "        return ${} ? {} : null;\n",
+            variable, self.wrap(model, config, &format!("${}", variable))
+        ));
+        content.push_str("    }\n\n");
+
+        // create
+        content.push_str(&format!("    public function create(array $data): {}\n    {{\n", return_type));
+        content.push_str(&format!("        ${} = $this->{}->create($data);\n\n", variable, dependency));
+        content.push_str(&format!("        return {};\n", self.wrap(model, config, &format!("${}", variable))));
+        content.push_str("    }\n\n");
+
+        // update
+        content.push_str(&format!("    public function update({} ${}, array $data): {}\n    {{\n", model.name, variable, return_type));
+        if config.generate_repositories {
+            content.push_str(&format!("        ${} = $this->repository->update(${}, $data);\n\n", variable, variable));
+        } else {
+            content.push_str(&format!("        ${}->update($data);\n\n", variable));
+        }
+        content.push_str(&format!("        return {};\n", self.wrap(model, config, &format!("${}", variable))));
+        content.push_str("    }\n\n");
+
+        // delete
+        content.push_str(&format!("    public function delete({} ${}): bool\n    {{\n", model.name, variable));
+        if config.generate_repositories {
+            content.push_str(&format!("        return $this->repository->delete(${});\n", variable));
+        } else {
+            content.push_str(&format!("        return (bool) ${}->delete();\n", variable));
+        }
+        content.push_str("    }\n");
+
+        content.push_str("}\n");
+        Ok(content)
+    }
+
+    fn get_file_path(&self, model: &ModelDefinition, config: &Config) -> String {
+        PathResolver::get_service_path(model, config)
+    }
+}
+
+impl ServiceGenerator {
+    /// Wraps a model expression in a DTO conversion when DTOs are enabled
+    fn wrap(&self, model: &ModelDefinition, config: &Config, expression: &str) -> String {
+        if config.generate_dto {
+            format!("{}::fromModel({})", ClassNameResolver::dto_class(model), expression)
+        } else {
+            expression.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Field, FieldType};
+
+    fn create_test_model() -> ModelDefinition {
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_service_references_model() {
+        let result = ServiceGenerator.generate(&create_test_model(), &Config::default()).unwrap();
+
+        assert!(result.contains("namespace App\\Services;"));
+        assert!(result.contains("use App\\Models\\User;"));
+        assert!(result.contains("class UserService"));
+        assert!(result.contains("private User $model"));
+        assert!(result.contains("public function get(int $id): ?User\n"));
+        assert!(result.contains("$user = $this->model->create($data);"));
+        assert!(!result.contains("UserDTO"));
+    }
+
+    #[test]
+    fn test_service_returns_dto_when_enabled() {
+        let config = Config {
+            generate_dto: true,
+            ..Config::default()
+        };
+        let result = ServiceGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\DTOs\\UserDTO;"));
+        assert!(result.contains("public function get(int $id): ?UserDTO\n"));
+        assert!(result.contains("public function create(array $data): UserDTO\n"));
+        assert!(result.contains("return UserDTO::fromModel($user);"));
+    }
+
+    #[test]
+    fn test_uuid_key_fetched_by_string() {
+        let model = ModelDefinition {
+            fields: vec![Field { primary: true, ..Field::new("uuid", FieldType::Uuid) }],
+            ..create_test_model()
+        };
+        let result = ServiceGenerator.generate(&model, &Config::default()).unwrap();
+
+        assert!(result.contains("public function get(string $id): ?User\n"));
+    }
+
+    #[test]
+    fn test_service_uses_repository_when_enabled() {
+        let config = Config {
+            generate_repositories: true,
+            ..Config::default()
+        };
+        let result = ServiceGenerator.generate(&create_test_model(), &config).unwrap();
+
+        assert!(result.contains("use App\\Repositories\\UserRepositoryInterface;"));
+        assert!(result.contains("private UserRepositoryInterface $repository"));
+        assert!(result.contains("return $this->repository->delete($user);"));
+    }
+}
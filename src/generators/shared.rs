@@ -1,5 +1,7 @@
-use crate::types::{Config, ModelDefinition, FieldType};
+use crate::types::{Config, Field, ModelDefinition, FieldType, PivotTable, Relationship, StandardRelationship};
+use chrono::{DateTime, Utc};
 use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Resolves file paths for both traditional Laravel and DDD structures
 pub struct PathResolver;
@@ -7,150 +9,363 @@ pub struct PathResolver;
 impl PathResolver {
     /// Get the file path for a model component
     pub fn get_model_path(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("{}/app/Domain/{}/Models/{}.php", config.output_dir, model.name, model.name)
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Models", &format!("{}.php", model.name)])
         } else {
-            format!("{}/app/Models/{}.php", config.output_dir, model.name)
+            Self::join(&config.output_dir, &["app", "Models", &format!("{}.php", model.name)])
         }
     }
 
+    /// Directory holding the model's own component folders, mirroring
+    /// [`NamespaceResolver::module_namespace`] (`App\Billing` -> `{output}/app/Billing`)
+    pub fn module_dir(model: &ModelDefinition, config: &Config) -> Option<String> {
+        let namespace = NamespaceResolver::module_namespace(model, config)?;
+        let mut segments: Vec<&str> = namespace.split('\\').collect();
+        if segments[0] == "App" {
+            segments[0] = "app";
+        }
+        Some(Self::join(&config.output_dir, &segments))
+    }
 
+    /// Get the file path for a controller
+    pub fn get_controller_path(model: &ModelDefinition, config: &Config) -> String {
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Controllers", &format!("{}Controller.php", model.name)])
+        } else {
+            Self::join(&config.output_dir, &["app", "Http", "Controllers", &format!("{}Controller.php", model.name)])
+        }
+    }
 
     /// Get the file path for a resource
     pub fn get_resource_path(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("{}/app/Domain/{}/Resources/{}Resource.php", config.output_dir, model.name, model.name)
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Resources", &format!("{}.php", ClassNameResolver::resource_class(model))])
         } else {
-            format!("{}/app/Http/Resources/{}Resource.php", config.output_dir, model.name)
+            Self::join(&config.output_dir, &["app", "Http", "Resources", &format!("{}.php", ClassNameResolver::resource_class(model))])
         }
     }
 
     /// Get the file path for a factory
     pub fn get_factory_path(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("{}/app/Domain/{}/Factories/{}Factory.php", config.output_dir, model.name, model.name)
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Factories", &format!("{}.php", ClassNameResolver::factory_class(model))])
         } else {
-            format!("{}/database/factories/{}Factory.php", config.output_dir, model.name)
+            Self::join(&config.output_dir, &["database", "factories", &format!("{}.php", ClassNameResolver::factory_class(model))])
         }
     }
 
     /// Get the file path for a DTO
     pub fn get_dto_path(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("{}/app/Domain/{}/DTOs/{}DTO.php", config.output_dir, model.name, model.name)
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["DTOs", &format!("{}.php", ClassNameResolver::dto_class(model))])
         } else {
-            format!("{}/app/DTOs/{}DTO.php", config.output_dir, model.name)
+            Self::join(&config.output_dir, &["app", "DTOs", &format!("{}.php", ClassNameResolver::dto_class(model))])
         }
     }
 
     /// Get the file path for a Request
     pub fn get_request_path(model: &ModelDefinition, config: &Config, action: &str) -> String {
         let prefix = if action == "store" { "Store" } else { "Update" };
-        if config.use_ddd_structure {
-            format!("{}/app/Domain/{}/Requests/{}{}Request.php", config.output_dir, model.name, prefix, model.name)
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Requests", &format!("{}{}Request.php", prefix, model.name)])
+        } else {
+            Self::join(&config.output_dir, &["app", "Http", "Requests", &format!("{}{}Request.php", prefix, model.name)])
+        }
+    }
+
+    /// Get the file path for a repository implementation
+    pub fn get_repository_path(model: &ModelDefinition, config: &Config) -> String {
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Repositories", &format!("{}Repository.php", model.name)])
         } else {
-            format!("{}/app/Http/Requests/{}{}Request.php", config.output_dir, prefix, model.name)
+            Self::join(&config.output_dir, &["app", "Repositories", &format!("{}Repository.php", model.name)])
         }
     }
 
+    /// Get the file path for a repository interface
+    pub fn get_repository_interface_path(model: &ModelDefinition, config: &Config) -> String {
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Repositories", &format!("{}RepositoryInterface.php", model.name)])
+        } else {
+            Self::join(&config.output_dir, &["app", "Repositories", &format!("{}RepositoryInterface.php", model.name)])
+        }
+    }
+
+    /// Get the file path for a service
+    pub fn get_service_path(model: &ModelDefinition, config: &Config) -> String {
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Services", &format!("{}Service.php", model.name)])
+        } else {
+            Self::join(&config.output_dir, &["app", "Services", &format!("{}Service.php", model.name)])
+        }
+    }
+
+    /// Get the file path for a database seeder
+    pub fn get_seeder_path(model: &ModelDefinition, config: &Config) -> String {
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Seeders", &format!("{}Seeder.php", model.name)])
+        } else {
+            Self::join(&config.output_dir, &["database", "seeders", &format!("{}Seeder.php", model.name)])
+        }
+    }
+
+    /// Get the file path for a backed enum class
+    pub fn get_enum_path(model: &ModelDefinition, enum_class: &str, config: &Config) -> String {
+        if let Some(module_dir) = Self::module_dir(model, config) {
+            Self::join(&module_dir, &["Enums", &format!("{}.php", enum_class)])
+        } else {
+            Self::join(&config.output_dir, &["app", "Enums", &format!("{}.php", enum_class)])
+        }
+    }
+
+    /// Get the timestamped path of the migration that creates `table`
+    ///
+    /// `sequence` is the migration's place in the run ([`RelationshipHelper::migration_order`]).
+    pub fn get_create_migration_path(table: &str, sequence: usize, config: &Config) -> String {
+        let timestamp = Self::migration_timestamp(sequence, config);
+        Self::join(&config.output_dir, &["database", "migrations", &format!("{}_create_{}_table.php", timestamp, table)])
+    }
+
+    /// Get the timestamped path of the migration adding `columns` to `table`
+    pub fn get_add_columns_migration_path(table: &str, columns: &[&str], sequence: usize, config: &Config) -> String {
+        let timestamp = Self::migration_timestamp(sequence, config);
+        let columns = match columns {
+            [column] => column.to_string(),
+            _ => "columns".to_string(),
+        };
+        Self::join(&config.output_dir, &["database", "migrations", &format!("{}_add_{}_to_{}_table.php", timestamp, columns, table)])
+    }
+
+    /// Time the run started plus `sequence` seconds, so Laravel runs the migrations in that order
+    fn migration_timestamp(sequence: usize, config: &Config) -> String {
+        let started = config.migration_run.as_ref().map_or_else(Utc::now, |run| run.started);
+        (started + chrono::Duration::seconds(sequence as i64)).format("%Y_%m_%d_%H%M%S").to_string()
+    }
+
+    /// Get the file path for the generated API routes include
+    pub fn get_routes_path(config: &Config) -> String {
+        Self::join(&config.output_dir, &["routes", "api_generated.php"])
+    }
+
+    /// Get the file path for a custom validation rule class (shared by all models)
+    pub fn get_rule_path(rule_name: &str, config: &Config) -> String {
+        Self::join(&config.output_dir, &["app", "Rules", &format!("{}.php", rule_name)])
+    }
 
+    /// Get the file path for the combined GraphQL schema
+    pub fn get_graphql_schema_path(config: &Config) -> String {
+        Self::join(&config.output_dir, &["graphql", "schema.graphql"])
+    }
+
+    /// `base` followed by `segments`, joined with the platform's path separator
+    pub fn join(base: &str, segments: &[&str]) -> String {
+        segments.iter()
+            .fold(PathBuf::from(base), |path, segment| path.join(segment))
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Start time and migration order shared by every migration of one render
+///
+/// [`Config::for_migration_run`] attaches one, so a long-lived process (`watch`, the MCP
+/// server) timestamps each render's migrations from when that render started. Without one,
+/// each migration path takes the current time and computes the order itself.
+#[derive(Debug, Clone)]
+pub struct MigrationRun {
+    started: DateTime<Utc>,
+    /// Model names in foreign-key order, parents first
+    models: Vec<String>,
+    /// Pivot table names, whose migrations follow every model table
+    pivot_tables: Vec<String>,
+}
+
+impl MigrationRun {
+    /// Starts a run now, ordering `models` by their foreign keys (config order on a cycle)
+    pub fn new(models: &[ModelDefinition]) -> Self {
+        let pivot_tables = RelationshipHelper::pivot_tables(models).into_iter()
+            .map(|(_, pivot_table)| pivot_table.name)
+            .collect();
+        let models = match RelationshipHelper::migration_order(models) {
+            Ok(order) => order.iter().map(|m| m.name.clone()).collect(),
+            Err(_) => models.iter().map(|m| m.name.clone()).collect(),
+        };
+        MigrationRun { started: Utc::now(), models, pivot_tables }
+    }
+
+    /// Position of `model` in the run's migration order
+    fn sequence(&self, model: &ModelDefinition) -> usize {
+        self.models.iter().position(|name| *name == model.name).unwrap_or(0)
+    }
+
+    /// Position of the pivot table `name`, after every model of the run
+    fn pivot_sequence(&self, name: &str) -> usize {
+        self.models.len() + self.pivot_tables.iter().position(|pivot_table| pivot_table == name).unwrap_or(0)
+    }
 }
 
 /// Resolves namespaces for both traditional Laravel and DDD structures
 pub struct NamespaceResolver;
 
 impl NamespaceResolver {
-    /// Get the namespace for a model
-    pub fn get_model_namespace(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("App\\Domain\\{}\\Models", model.name)
+    /// Root namespace of a model grouped into its own module: the model's `module`,
+    /// else `App\Domain\{Model}` under DDD
+    pub fn module_namespace(model: &ModelDefinition, config: &Config) -> Option<String> {
+        if let Some(module) = &model.module {
+            Some(module.clone())
+        } else if config.use_ddd_structure {
+            Some(format!("App\\Domain\\{}", model.name))
         } else {
-            config.namespace.clone()
+            None
         }
     }
 
+    /// Get the namespace for a model
+    pub fn get_model_namespace(model: &ModelDefinition, config: &Config) -> String {
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Models", module),
+            None => config.namespace.clone(),
+        }
+    }
 
+    /// Get the namespace for a controller
+    pub fn get_controller_namespace(model: &ModelDefinition, config: &Config) -> String {
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Controllers", module),
+            None => "App\\Http\\Controllers".to_string(),
+        }
+    }
 
     /// Get the namespace for a resource
     pub fn get_resource_namespace(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("App\\Domain\\{}\\Resources", model.name)
-        } else {
-            "App\\Http\\Resources".to_string()
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Resources", module),
+            None => "App\\Http\\Resources".to_string(),
         }
     }
 
     /// Get the namespace for a factory
     pub fn get_factory_namespace(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("App\\Domain\\{}\\Factories", model.name)
-        } else {
-            "Database\\Factories".to_string()
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Factories", module),
+            None => "Database\\Factories".to_string(),
         }
     }
 
     /// Get the namespace for a DTO
     pub fn get_dto_namespace(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("App\\Domain\\{}\\DTOs", model.name)
-        } else {
-            "App\\DTOs".to_string()
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\DTOs", module),
+            None => "App\\DTOs".to_string(),
         }
     }
 
     /// Get the namespace for a Request
     pub fn get_request_namespace(model: &ModelDefinition, config: &Config) -> String {
-        if config.use_ddd_structure {
-            format!("App\\Domain\\{}\\Requests", model.name)
-        } else {
-            "App\\Http\\Requests".to_string()
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Requests", module),
+            None => "App\\Http\\Requests".to_string(),
+        }
+    }
+
+    /// Get the namespace for a repository and its interface
+    pub fn get_repository_namespace(model: &ModelDefinition, config: &Config) -> String {
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Repositories", module),
+            None => "App\\Repositories".to_string(),
+        }
+    }
+
+    /// Get the namespace for a service
+    pub fn get_service_namespace(model: &ModelDefinition, config: &Config) -> String {
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Services", module),
+            None => "App\\Services".to_string(),
+        }
+    }
+
+    /// Get the namespace for a database seeder
+    pub fn get_seeder_namespace(model: &ModelDefinition, config: &Config) -> String {
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Seeders", module),
+            None => "Database\\Seeders".to_string(),
+        }
+    }
+
+    /// Get the namespace for backed enums
+    pub fn get_enum_namespace(model: &ModelDefinition, config: &Config) -> String {
+        match Self::module_namespace(model, config) {
+            Some(module) => format!("{}\\Enums", module),
+            None => "App\\Enums".to_string(),
         }
     }
 }
 
+/// Resolves generated class names, honouring per-model overrides
+pub struct ClassNameResolver;
+
+impl ClassNameResolver {
+    /// API resource class name (`{Model}Resource` unless overridden)
+    pub fn resource_class(model: &ModelDefinition) -> String {
+        model.resource_name.clone().unwrap_or_else(|| format!("{}Resource", model.name))
+    }
+
+    /// DTO class name (`{Model}DTO` unless overridden)
+    pub fn dto_class(model: &ModelDefinition) -> String {
+        model.dto_name.clone().unwrap_or_else(|| format!("{}DTO", model.name))
+    }
+
+    /// Factory class name (`{Model}Factory` unless overridden)
+    pub fn factory_class(model: &ModelDefinition) -> String {
+        model.factory_name.clone().unwrap_or_else(|| format!("{}Factory", model.name))
+    }
+
+    /// Backed enum class name for an enum field (`{Model}{Field}`, e.g. `UserStatus`)
+    pub fn enum_class(model: &ModelDefinition, field: &Field) -> String {
+        format!("{}{}", model.name, NameHelper::studly_case(&field.name))
+    }
+}
+
 /// Creates directories for both traditional Laravel and DDD structures
 pub struct DirectoryCreator;
 
 impl DirectoryCreator {
     /// Create all necessary directories for a model
     pub fn create_model_directories(model: &ModelDefinition, config: &Config) -> crate::error::Result<()> {
-        if config.use_ddd_structure {
-            let base_domain_dir = format!("{}/app/Domain/{}", config.output_dir, model.name);
-            
-            let dirs = [
-                &base_domain_dir,
-                &format!("{}/Models", base_domain_dir),
-                &format!("{}/Resources", base_domain_dir),
-                &format!("{}/Factories", base_domain_dir),
-                &format!("{}/DTOs", base_domain_dir),
-                &format!("{}/Requests", base_domain_dir),
-            ];
-
-            for dir in dirs {
-                fs::create_dir_all(dir)?;
+        if let Some(module_dir) = PathResolver::module_dir(model, config) {
+            fs::create_dir_all(&module_dir)?;
+            for dir in ["Models", "Controllers", "Resources", "Factories", "DTOs", "Requests", "Repositories", "Services", "Seeders", "Enums"] {
+                fs::create_dir_all(Path::new(&module_dir).join(dir))?;
             }
         } else {
-            let dirs = [
-                &format!("{}/app/Models", config.output_dir),
-                &format!("{}/app/Http/Resources", config.output_dir),
-                &format!("{}/database/factories", config.output_dir),
-                &format!("{}/app/DTOs", config.output_dir),
-                &format!("{}/app/Http/Requests", config.output_dir),
+            let dirs: [&[&str]; 9] = [
+                &["app", "Models"],
+                &["app", "Http", "Resources"],
+                &["database", "factories"],
+                &["app", "DTOs"],
+                &["app", "Http", "Requests"],
+                &["app", "Repositories"],
+                &["app", "Services"],
+                &["database", "seeders"],
+                &["app", "Enums"],
             ];
 
             for dir in dirs {
-                fs::create_dir_all(dir)?;
+                fs::create_dir_all(PathResolver::join(&config.output_dir, dir))?;
             }
         }
 
         // Always create these directories (they don't change with DDD)
-        let common_dirs = [
-            &format!("{}/app/Http/Controllers", config.output_dir),
-            &format!("{}/database/migrations", config.output_dir),
+        let common_dirs: [&[&str]; 5] = [
+            &["app", "Http", "Controllers"],
+            &["database", "migrations"],
+            &["graphql"],
+            &["routes"],
+            &["app", "Rules"],
         ];
 
         for dir in common_dirs {
-            fs::create_dir_all(dir)?;
+            fs::create_dir_all(PathResolver::join(&config.output_dir, dir))?;
         }
 
         Ok(())
@@ -166,15 +381,32 @@ impl FieldTypeHelper {
         match field_type {
             FieldType::String | FieldType::Text | FieldType::LongText | FieldType::MediumText => "string",
             FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger | 
-            FieldType::SmallInteger | FieldType::MediumInteger => "int",
+            FieldType::SmallInteger | FieldType::MediumInteger | FieldType::Year => "int",
             FieldType::Float | FieldType::Decimal => "float",
             FieldType::Boolean => "bool",
             FieldType::Json => "array",
-            FieldType::Date | FieldType::DateTime | FieldType::Timestamp => "string",
+            FieldType::Date | FieldType::DateTime | FieldType::Timestamp | FieldType::Time => "string",
             FieldType::Uuid => "string",
             FieldType::Enum => "string",
             FieldType::Binary => "string",
             FieldType::Inet => "string",
+            // Spatial values travel as WKT (`POINT(lng lat)`)
+            FieldType::Point | FieldType::Geometry | FieldType::Polygon => "string",
+        }
+    }
+
+    /// PHP type hint for the model's key, `int` for the implicit auto-incrementing `id`
+    pub fn key_type_hint(model: &ModelDefinition) -> &'static str {
+        model.primary_key().map_or("int", |field| Self::to_php_type_hint(&field.field_type))
+    }
+
+    /// Laravel type rule for numeric fields (`integer` or `numeric`), `None` for anything else
+    pub fn numeric_rule(field_type: &FieldType) -> Option<&'static str> {
+        match field_type {
+            FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger |
+            FieldType::SmallInteger | FieldType::MediumInteger => Some("integer"),
+            FieldType::Float | FieldType::Decimal => Some("numeric"),
+            _ => None,
         }
     }
 
@@ -188,45 +420,372 @@ impl FieldTypeHelper {
     }
 }
 
+/// Fluent column modifiers shared by the migration generators
+pub struct ColumnModifiers;
+
+impl ColumnModifiers {
+    /// Modifier chain in a fixed order: unsigned, nullable, default, unique, index, comment, after
+    ///
+    /// `unique` and `index` are left to the caller, which may emit them as named table-level calls instead.
+    pub fn chain(field: &Field, unique: bool, index: bool) -> String {
+        let mut chain = String::new();
+
+        if field.unsigned {
+            chain.push_str("->unsigned()");
+        }
+
+        if field.nullable {
+            chain.push_str("->nullable()");
+        }
+
+        if field.has_null_default() {
+            chain.push_str("->default(null)");
+        } else if let Some(default_value) = field.default_value() {
+            chain.push_str(&format!("->default('{}')", Self::escape(default_value)));
+        }
+
+        if unique {
+            chain.push_str("->unique()");
+        }
+
+        if index {
+            chain.push_str("->index()");
+        }
+
+        if let Some(comment) = &field.comment {
+            chain.push_str(&format!("->comment('{}')", Self::escape(comment)));
+        }
+
+        if let Some(column) = &field.after {
+            chain.push_str(&format!("->after('{}')", Self::escape(column)));
+        }
+
+        chain
+    }
+
+    /// Escapes a value for a single-quoted PHP string
+    fn escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('\'', "\\'")
+    }
+}
+
+/// Naming helpers shared across generators
+pub struct NameHelper;
+
+impl NameHelper {
+    /// Convert StudlyCase to camelCase (e.g. `BlogPost` -> `blogPost`)
+    pub fn camel_case(name: &str) -> String {
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => format!("{}{}", first.to_lowercase(), chars.as_str()),
+            None => String::new(),
+        }
+    }
+
+    /// Convert StudlyCase or camelCase to snake_case (e.g. `BlogPost` -> `blog_post`)
+    pub fn snake_case(name: &str) -> String {
+        let mut result = String::new();
+        for (i, c) in name.chars().enumerate() {
+            if c.is_uppercase() {
+                if i > 0 {
+                    result.push('_');
+                }
+                result.extend(c.to_lowercase());
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    }
+
+    /// Convert StudlyCase or camelCase to kebab-case (e.g. `BlogPost` -> `blog-post`)
+    pub fn kebab_case(name: &str) -> String {
+        Self::snake_case(name).replace('_', "-")
+    }
+
+    /// Naive English pluralization (e.g. `category` -> `categories`, `post` -> `posts`)
+    pub fn pluralize(word: &str) -> String {
+        if let Some(stem) = word.strip_suffix('y') {
+            format!("{}ies", stem)
+        } else if word.ends_with('s') {
+            format!("{}es", word)
+        } else {
+            format!("{}s", word)
+        }
+    }
+
+    /// Convert snake_case to StudlyCase (e.g. `full_name` -> `FullName`)
+    pub fn studly_case(name: &str) -> String {
+        name.split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Naming helpers for relationship methods
+pub struct RelationshipHelper;
+
+impl RelationshipHelper {
+    /// Get the Eloquent method name for a relationship (e.g. `author`, `posts`)
+    ///
+    /// An explicit `method_name` wins over the name derived from the related model.
+    pub fn method_name(relationship: &Relationship) -> String {
+        if let Some(method_name) = Self::explicit_method_name(relationship) {
+            return method_name.to_string();
+        }
+
+        match relationship {
+            Relationship::BelongsTo(rel) | Relationship::HasOne(rel) => {
+                NameHelper::camel_case(&rel.model)
+            },
+            Relationship::HasMany(rel) | Relationship::BelongsToMany(rel) => {
+                Self::pluralize_model_name(&rel.model)
+            },
+            Relationship::MorphMany(rel) | Relationship::MorphToMany(rel) | Relationship::MorphedByMany(rel) => {
+                Self::pluralize_model_name(&rel.model)
+            },
+            Relationship::MorphOne(rel) => {
+                NameHelper::camel_case(&rel.model)
+            },
+            Relationship::MorphTo(rel) => {
+                rel.morph_name.clone()
+            },
+        }
+    }
+
+    /// Relationship type and target as written in a schema (`belongsTo User`, `morphTo commentable`)
+    pub fn describe(relationship: &Relationship) -> String {
+        match relationship {
+            Relationship::BelongsTo(rel) => format!("belongsTo {}", rel.model),
+            Relationship::HasMany(rel) => format!("hasMany {}", rel.model),
+            Relationship::HasOne(rel) => format!("hasOne {}", rel.model),
+            Relationship::BelongsToMany(rel) => format!("belongsToMany {}", rel.model),
+            Relationship::MorphTo(rel) => format!("morphTo {}", rel.morph_name),
+            Relationship::MorphOne(rel) => format!("morphOne {}", rel.model),
+            Relationship::MorphMany(rel) => format!("morphMany {}", rel.model),
+            Relationship::MorphToMany(rel) => format!("morphToMany {}", rel.model),
+            Relationship::MorphedByMany(rel) => format!("morphedByMany {}", rel.model),
+        }
+    }
+
+    /// The user-supplied method name, if the relationship declares one
+    pub fn explicit_method_name(relationship: &Relationship) -> Option<&str> {
+        match relationship {
+            Relationship::BelongsTo(rel) | Relationship::HasOne(rel) |
+            Relationship::HasMany(rel) | Relationship::BelongsToMany(rel) => rel.method_name.as_deref(),
+            Relationship::MorphOne(rel) | Relationship::MorphMany(rel) |
+            Relationship::MorphToMany(rel) | Relationship::MorphedByMany(rel) => rel.method_name.as_deref(),
+            Relationship::MorphTo(_) => None,
+        }
+    }
+
+    /// Conventional pivot table joining two models: singular snake-case names in alphabetical order (`role_user`)
+    pub fn pivot_table_name(model1: &str, model2: &str) -> String {
+        let mut names = [NameHelper::snake_case(model1), NameHelper::snake_case(model2)];
+        names.sort();
+        names.join("_")
+    }
+
+    /// Pivot table a belongsToMany goes through: the declared one, or the conventional name
+    pub fn belongs_to_many_pivot(owner: &str, rel: &StandardRelationship) -> String {
+        rel.pivot_table.clone().unwrap_or_else(|| Self::pivot_table_name(owner, &rel.model))
+    }
+
+    /// Models in the order their create migrations must run: every belongsTo parent before its
+    /// children, config order otherwise
+    ///
+    /// Fails with the model names along a foreign-key cycle (`["User", "Team", "User"]`).
+    /// Self-references and parents missing from `models` don't constrain the order.
+    pub fn migration_order(models: &[ModelDefinition]) -> std::result::Result<Vec<&ModelDefinition>, Vec<String>> {
+        let parents = |model| Self::belongs_to_parents(model, models);
+
+        // Repeatedly take the first model, in config order, whose parents are all placed
+        let mut ordered: Vec<&ModelDefinition> = Vec::with_capacity(models.len());
+        let mut remaining: Vec<&ModelDefinition> = models.iter().collect();
+        while let Some(next) = remaining.iter()
+            .position(|model| parents(model).iter().all(|parent| ordered.iter().any(|m| m.name == *parent)))
+        {
+            ordered.push(remaining.remove(next));
+        }
+        if remaining.is_empty() {
+            return Ok(ordered);
+        }
+
+        // Every model left has an unplaced parent, so following them must loop
+        let mut path: Vec<&str> = vec![remaining[0].name.as_str()];
+        loop {
+            let current = remaining.iter().find(|model| model.name == *path.last().unwrap()).unwrap();
+            let parent = parents(current).into_iter()
+                .find(|parent| remaining.iter().any(|m| m.name == *parent))
+                .unwrap();
+            if let Some(start) = path.iter().position(|name| *name == parent) {
+                let mut cycle: Vec<String> = path[start..].iter().map(|name| name.to_string()).collect();
+                cycle.push(parent.to_string());
+                return Err(cycle);
+            }
+            path.push(parent);
+        }
+    }
+
+    /// Other models of `models` that `model` belongs to
+    fn belongs_to_parents<'a>(model: &'a ModelDefinition, models: &[ModelDefinition]) -> Vec<&'a str> {
+        model.relationships.iter()
+            .filter_map(|relationship| match relationship {
+                Relationship::BelongsTo(rel) if rel.model != model.name
+                    && models.iter().any(|m| m.name == rel.model) => Some(rel.model.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Position of a model's migration in [`migration_order`](Self::migration_order), config order on a cycle
+    ///
+    /// Taken from the config's [`MigrationRun`] when it has one rather than computed for every model.
+    pub fn migration_sequence(model: &ModelDefinition, config: &Config) -> usize {
+        match &config.migration_run {
+            Some(run) => run.sequence(model),
+            None => MigrationRun::new(&config.models).sequence(model),
+        }
+    }
+
+    /// Position of a pivot table's migration: after every model table, in [`pivot_tables`](Self::pivot_tables) order
+    pub fn pivot_sequence(name: &str, config: &Config) -> usize {
+        match &config.migration_run {
+            Some(run) => run.pivot_sequence(name),
+            None => MigrationRun::new(&config.models).pivot_sequence(name),
+        }
+    }
+
+    /// Prefixed table a foreign key to `model_name` references
+    ///
+    /// The related model's declared `table` wins; a model missing from the config falls back
+    /// to Laravel's convention (`BlogPost` -> `blog_posts`).
+    pub fn related_table(model_name: &str, config: &Config) -> String {
+        match config.models.iter().find(|model| model.name == model_name) {
+            Some(model) => model.table_name(config),
+            None => config.table_name(&NameHelper::pluralize(&NameHelper::snake_case(model_name))),
+        }
+    }
+
+    /// Declared pivot tables, then the conventional ones implied by belongsToMany relationships,
+    /// each with the model that owns it
+    pub fn pivot_tables(models: &[ModelDefinition]) -> Vec<(&ModelDefinition, PivotTable)> {
+        let declared = models.iter()
+            .flat_map(|model| model.pivot_tables.iter().map(move |pivot_table| (model, pivot_table.clone())));
+        declared.chain(Self::implied_pivot_tables(models)).collect()
+    }
+
+    /// Pivot tables implied by belongsToMany relationships without a `pivot_table`, one per name
+    ///
+    /// Tables some model already declares in `pivot_tables` are left out, as are self-referencing
+    /// relationships (whose two keys would collide) and ones to models missing from `models`.
+    pub fn implied_pivot_tables(models: &[ModelDefinition]) -> Vec<(&ModelDefinition, PivotTable)> {
+        let mut names: Vec<String> = models.iter()
+            .flat_map(|model| model.pivot_tables.iter().map(|pivot_table| pivot_table.name.clone()))
+            .collect();
+
+        let mut pivot_tables = Vec::new();
+        for model in models {
+            for relationship in &model.relationships {
+                let Relationship::BelongsToMany(rel) = relationship else {
+                    continue;
+                };
+                if rel.pivot_table.is_some() || rel.model == model.name || !models.iter().any(|m| m.name == rel.model) {
+                    continue;
+                }
+
+                let name = Self::pivot_table_name(&model.name, &rel.model);
+                if names.contains(&name) {
+                    continue;
+                }
+                names.push(name.clone());
+
+                let mut joined = [model.name.as_str(), rel.model.as_str()];
+                joined.sort_by_key(|name| NameHelper::snake_case(name));
+                pivot_tables.push((model, PivotTable {
+                    name,
+                    model1: joined[0].to_string(),
+                    model2: joined[1].to_string(),
+                    foreign_key1: format!("{}_id", NameHelper::snake_case(joined[0])),
+                    foreign_key2: format!("{}_id", NameHelper::snake_case(joined[1])),
+                    additional_fields: Vec::new(),
+                    timestamps: rel.with_timestamps,
+                }));
+            }
+        }
+        pivot_tables
+    }
+
+    fn pluralize_model_name(model_name: &str) -> String {
+        NameHelper::pluralize(&NameHelper::camel_case(model_name))
+    }
+}
+
+/// Header comment written at the top of every generated file
+///
+/// `generate --update` only overwrites files that still carry the marker, so removing
+/// the line protects a hand-edited file. `includeComments = false` leaves it out.
+pub struct GeneratedMarker;
+
+impl GeneratedMarker {
+    pub const TEXT: &'static str = "@generated by schemly";
+
+    /// Header line with the schemly version, `None` when comments are disabled
+    pub fn banner(config: &Config) -> Option<String> {
+        config.include_comments.then(|| format!(
+            "{} v{}. Do not edit: changes are lost on regeneration (remove this line to keep them).",
+            Self::TEXT,
+            env!("CARGO_PKG_VERSION")
+        ))
+    }
+
+    /// Header as a comment in the target language (`//`, `#`, `--`), followed by a blank line
+    pub fn comment(prefix: &str, config: &Config) -> String {
+        Self::banner(config)
+            .map(|banner| format!("{} {}\n\n", prefix, banner))
+            .unwrap_or_default()
+    }
+
+    /// Opening of a generated PHP file: the `<?php` tag followed by the header
+    pub fn php_header(config: &Config) -> String {
+        format!("<?php\n\n{}", Self::comment("//", config))
+    }
+
+    /// Whether existing file content was written by schemly (marker within the first lines)
+    pub fn is_present(content: &str) -> bool {
+        content.lines().take(5).any(|line| line.contains(Self::TEXT))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{FieldType, FillableGuarded};
+    use crate::types::FieldType;
 
     fn create_test_model() -> ModelDefinition {
         ModelDefinition {
             name: "User".to_string(),
             table: "users".to_string(),
-            fields: vec![],
-            timestamps: true,
-            soft_deletes: false,
-            relationships: vec![],
-            pivot_tables: vec![],
-            validation_rules: vec![],
-            traits: vec![],
-            fillable_guarded: FillableGuarded::All,
-            compound_indexes: vec![],
-            compound_uniques: vec![],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
         }
     }
 
     fn create_test_config(use_ddd: bool) -> Config {
         Config {
-            models: vec![],
             output_dir: "/tmp/test".to_string(),
-            namespace: "App\\Models".to_string(),
-            generate_models: true,
-            generate_controllers: true,
-            generate_resources: true,
-            generate_factories: true,
-            generate_migrations: true,
-            generate_pivot_tables: true,
-            generate_validation_rules: true,
             generate_requests: true,
             generate_dto: true,
             use_ddd_structure: use_ddd,
-            database_engine: "mysql".to_string(),
-            force_overwrite: false,
+            ..Config::default()
         }
     }
 
@@ -236,21 +795,21 @@ mod tests {
         let config = create_test_config(false);
 
         assert_eq!(
-            PathResolver::get_model_path(&model, &config),
-            "/tmp/test/app/Models/User.php"
+            Path::new(&PathResolver::get_model_path(&model, &config)),
+            Path::new("/tmp/test/app/Models/User.php")
         );
 
         assert_eq!(
-            PathResolver::get_resource_path(&model, &config),
-            "/tmp/test/app/Http/Resources/UserResource.php"
+            Path::new(&PathResolver::get_resource_path(&model, &config)),
+            Path::new("/tmp/test/app/Http/Resources/UserResource.php")
         );
         assert_eq!(
-            PathResolver::get_factory_path(&model, &config),
-            "/tmp/test/database/factories/UserFactory.php"
+            Path::new(&PathResolver::get_factory_path(&model, &config)),
+            Path::new("/tmp/test/database/factories/UserFactory.php")
         );
         assert_eq!(
-            PathResolver::get_dto_path(&model, &config),
-            "/tmp/test/app/DTOs/UserDTO.php"
+            Path::new(&PathResolver::get_dto_path(&model, &config)),
+            Path::new("/tmp/test/app/DTOs/UserDTO.php")
         );
     }
 
@@ -260,24 +819,43 @@ mod tests {
         let config = create_test_config(true);
 
         assert_eq!(
-            PathResolver::get_model_path(&model, &config),
-            "/tmp/test/app/Domain/User/Models/User.php"
+            Path::new(&PathResolver::get_model_path(&model, &config)),
+            Path::new("/tmp/test/app/Domain/User/Models/User.php")
         );
 
         assert_eq!(
-            PathResolver::get_resource_path(&model, &config),
-            "/tmp/test/app/Domain/User/Resources/UserResource.php"
+            Path::new(&PathResolver::get_resource_path(&model, &config)),
+            Path::new("/tmp/test/app/Domain/User/Resources/UserResource.php")
         );
         assert_eq!(
-            PathResolver::get_factory_path(&model, &config),
-            "/tmp/test/app/Domain/User/Factories/UserFactory.php"
+            Path::new(&PathResolver::get_factory_path(&model, &config)),
+            Path::new("/tmp/test/app/Domain/User/Factories/UserFactory.php")
         );
         assert_eq!(
-            PathResolver::get_dto_path(&model, &config),
-            "/tmp/test/app/Domain/User/DTOs/UserDTO.php"
+            Path::new(&PathResolver::get_dto_path(&model, &config)),
+            Path::new("/tmp/test/app/Domain/User/DTOs/UserDTO.php")
         );
     }
 
+    #[test]
+    fn test_paths_use_platform_separator() {
+        let model = create_test_model();
+        let components = |path: String| -> Vec<String> {
+            Path::new(&path).components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect()
+        };
+
+        let config = Config { output_dir: "project".to_string(), ..create_test_config(false) };
+        assert_eq!(components(PathResolver::get_model_path(&model, &config)), ["project", "app", "Models", "User.php"]);
+        assert_eq!(components(PathResolver::get_request_path(&model, &config, "store")), ["project", "app", "Http", "Requests", "StoreUserRequest.php"]);
+        assert_eq!(components(PathResolver::get_routes_path(&config)), ["project", "routes", "api_generated.php"]);
+
+        let config = Config { output_dir: "project".to_string(), ..create_test_config(true) };
+        assert_eq!(components(PathResolver::get_model_path(&model, &config)), ["project", "app", "Domain", "User", "Models", "User.php"]);
+        assert_eq!(components(PathResolver::get_factory_path(&model, &config)), ["project", "app", "Domain", "User", "Factories", "UserFactory.php"]);
+    }
+
     #[test]
     fn test_namespace_resolver_traditional_structure() {
         let model = create_test_model();
@@ -326,6 +904,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_model_namespace_overrides_ddd_structure() {
+        let model = ModelDefinition {
+            module: Some("App\\Billing".to_string()),
+            ..create_test_model()
+        };
+        let config = create_test_config(true);
+
+        assert_eq!(NamespaceResolver::get_model_namespace(&model, &config), "App\\Billing\\Models");
+        assert_eq!(NamespaceResolver::get_factory_namespace(&model, &config), "App\\Billing\\Factories");
+        assert_eq!(Path::new(&PathResolver::get_model_path(&model, &config)), Path::new("/tmp/test/app/Billing/Models/User.php"));
+        assert_eq!(Path::new(&PathResolver::get_dto_path(&model, &config)), Path::new("/tmp/test/app/Billing/DTOs/UserDTO.php"));
+
+        // Roots outside App map straight onto directories
+        let model = ModelDefinition { module: Some("Modules\\Billing".to_string()), ..model };
+        assert_eq!(Path::new(&PathResolver::get_model_path(&model, &config)), Path::new("/tmp/test/Modules/Billing/Models/User.php"));
+    }
+
+    #[test]
+    fn test_pivot_table_name_joins_singular_names_alphabetically() {
+        assert_eq!(RelationshipHelper::pivot_table_name("User", "Role"), "role_user");
+        assert_eq!(RelationshipHelper::pivot_table_name("Role", "User"), "role_user");
+        assert_eq!(RelationshipHelper::pivot_table_name("Tag", "BlogPost"), "blog_post_tag");
+    }
+
+    #[test]
+    fn test_implied_pivot_tables_from_both_sides() {
+        let belongs_to_many = |model: &str| Relationship::BelongsToMany(StandardRelationship {
+            model: model.to_string(),
+            foreign_key: None,
+            local_key: None,
+            owner_key: None,
+            pivot_table: None,
+            pivot_fields: vec![],
+            on_delete: None,
+            on_update: None,
+            with_timestamps: false,
+            nullable: false,
+            method_name: None,
+        });
+        let user = ModelDefinition {
+            relationships: vec![belongs_to_many("Role")],
+            ..create_test_model()
+        };
+        let role = ModelDefinition {
+            name: "Role".to_string(),
+            table: "roles".to_string(),
+            relationships: vec![belongs_to_many("User")],
+            ..create_test_model()
+        };
+
+        let models = [user, role];
+        let pivot_tables = RelationshipHelper::implied_pivot_tables(&models);
+        assert_eq!(pivot_tables.len(), 1);
+        let (owner, pivot_table) = &pivot_tables[0];
+        assert_eq!(owner.name, "User");
+        assert_eq!(pivot_table.name, "role_user");
+        assert_eq!((pivot_table.model1.as_str(), pivot_table.model2.as_str()), ("Role", "User"));
+        assert_eq!((pivot_table.foreign_key1.as_str(), pivot_table.foreign_key2.as_str()), ("role_id", "user_id"));
+    }
+
+    #[test]
+    fn test_migration_run_belongs_to_its_config() {
+        let post = ModelDefinition {
+            name: "Post".to_string(),
+            table: "posts".to_string(),
+            relationships: vec![Relationship::BelongsTo(StandardRelationship {
+                model: "User".to_string(),
+                foreign_key: None,
+                local_key: None,
+                owner_key: None,
+                pivot_table: None,
+                pivot_fields: vec![],
+                on_delete: None,
+                on_update: None,
+                with_timestamps: false,
+                nullable: false,
+                method_name: None,
+            })],
+            ..create_test_model()
+        };
+        let config = Config { models: vec![post.clone(), create_test_model()], ..Config::default() }.for_migration_run();
+        assert_eq!(RelationshipHelper::migration_sequence(&config.models[1], &config), 0);
+        assert_eq!(RelationshipHelper::migration_sequence(&post, &config), 1);
+        let path = PathResolver::get_create_migration_path("posts", 1, &config);
+        assert_eq!(path, PathResolver::get_create_migration_path("posts", 1, &config.clone()));
+
+        // Another config's run keeps its own order and leaves the first one alone
+        let unrelated = ModelDefinition { relationships: vec![], ..post.clone() };
+        let other = Config { models: vec![unrelated.clone(), create_test_model()], ..Config::default() }.for_migration_run();
+        assert_eq!(RelationshipHelper::migration_sequence(&unrelated, &other), 0);
+        assert_eq!(RelationshipHelper::migration_sequence(&post, &config), 1);
+    }
+
     #[test]
     fn test_field_type_helper_php_type_hints() {
         assert_eq!(FieldTypeHelper::to_php_type_hint(&FieldType::String), "string");
@@ -352,4 +1024,28 @@ mod tests {
         assert!(FieldTypeHelper::is_nullable_in_php("email", true));
         assert!(!FieldTypeHelper::is_nullable_in_php("email", false));
     }
+
+    #[test]
+    fn test_generated_marker_in_every_file() {
+        use crate::generators::Generator;
+        use crate::generators::{migration_generator::MigrationGenerator, model_generator::ModelGenerator, resource_generator::ResourceGenerator};
+
+        let model = create_test_model();
+        let mut config = create_test_config(false);
+        let header = format!("<?php\n\n// @generated by schemly v{}. Do not edit", env!("CARGO_PKG_VERSION"));
+
+        for content in [
+            ModelGenerator.generate(&model, &config).unwrap(),
+            ResourceGenerator.generate(&model, &config).unwrap(),
+            MigrationGenerator.generate(&model, &config).unwrap(),
+        ] {
+            assert!(content.starts_with(&header));
+            assert!(GeneratedMarker::is_present(&content));
+        }
+
+        config.include_comments = false;
+        let content = MigrationGenerator.generate(&model, &config).unwrap();
+        assert!(content.starts_with("<?php\n\nuse Illuminate"));
+        assert!(!GeneratedMarker::is_present(&content));
+    }
 }
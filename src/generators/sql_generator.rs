@@ -0,0 +1,320 @@
+use crate::error::Result;
+use crate::generators::shared::GeneratedMarker;
+use crate::types::{ColumnDefault, Config, Field, FieldType, ModelDefinition, Relationship};
+
+/// Generator for a best-effort `CREATE TABLE` script covering every model
+///
+/// This is a diagnostic view of the schema for `--emit-sql`; the migrations stay
+/// the source of truth. Column types follow `database_engine` (`mysql`, `pgsql`,
+/// `sqlite`), indexes and foreign key constraints are left out.
+pub struct SqlGenerator;
+
+/// SQL dialect picked from `database_engine`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Dialect {
+    MySql,
+    Postgres,
+    Sqlite,
+}
+
+impl SqlGenerator {
+    /// Renders one `CREATE TABLE` statement per model
+    pub fn generate_schema(&self, models: &[ModelDefinition], config: &Config) -> Result<String> {
+        let mut content = GeneratedMarker::comment("--", config);
+        content.push_str(&format!(
+            "-- {} schema, diagnostic only: run the migrations to build the database\n",
+            config.database_engine
+        ));
+        for model in models {
+            content.push('\n');
+            content.push_str(&self.generate_table(model, config));
+        }
+        Ok(content)
+    }
+
+    fn generate_table(&self, model: &ModelDefinition, config: &Config) -> String {
+        let dialect = Self::dialect(config);
+        let mut columns = Vec::new();
+
+        if !model.fields.iter().any(|f| f.primary) {
+            columns.push(match dialect {
+                Dialect::MySql => "id BIGINT UNSIGNED NOT NULL AUTO_INCREMENT PRIMARY KEY".to_string(),
+                Dialect::Postgres => "id BIGSERIAL PRIMARY KEY".to_string(),
+                Dialect::Sqlite => "id INTEGER PRIMARY KEY AUTOINCREMENT".to_string(),
+            });
+        }
+
+        // A composite key is declared once as a table constraint
+        let composite_primary_key = model.composite_primary_key();
+        for field in &model.fields {
+            if field.name != "id" || field.primary {
+                columns.push(self.column_definition(field, dialect, composite_primary_key.is_none()));
+            }
+        }
+
+        // Columns the migration adds on top of the declared fields
+        for relationship in &model.relationships {
+            match relationship {
+                Relationship::BelongsTo(rel) if config.auto_foreign_ids => {
+                    if let Some(foreign_key) = &rel.foreign_key
+                        && !model.fields.iter().any(|f| &f.name == foreign_key) {
+                        let null = if rel.nullable { "NULL" } else { "NOT NULL" };
+                        columns.push(format!("{} {} {}", foreign_key, Self::foreign_id_type(dialect), null));
+                    }
+                }
+                Relationship::MorphTo(rel) => {
+                    let declared = model.fields.iter().any(|f| {
+                        f.name == format!("{}_id", rel.morph_name) || f.name == format!("{}_type", rel.morph_name)
+                    });
+                    if !declared {
+                        let null = if rel.optional { "NULL" } else { "NOT NULL" };
+                        columns.push(format!("{}_type VARCHAR(255) {}", rel.morph_name, null));
+                        columns.push(format!("{}_id {} {}", rel.morph_name, Self::foreign_id_type(dialect), null));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if model.has_timestamps(config) {
+            columns.push(format!("created_at {} NULL", Self::timestamp_type(dialect)));
+            columns.push(format!("updated_at {} NULL", Self::timestamp_type(dialect)));
+        }
+
+        if model.has_soft_deletes(config) {
+            columns.push(format!("deleted_at {} NULL", Self::timestamp_type(dialect)));
+        }
+
+        if let Some(primary_columns) = &composite_primary_key {
+            columns.push(format!("PRIMARY KEY ({})", primary_columns.join(", ")));
+        }
+
+        for unique_fields in &model.compound_uniques {
+            columns.push(format!("UNIQUE ({})", unique_fields.join(", ")));
+        }
+
+        format!("CREATE TABLE {} (\n    {}\n);\n", model.table_name(config), columns.join(",\n    "))
+    }
+
+    fn column_definition(&self, field: &Field, dialect: Dialect, inline_primary: bool) -> String {
+        let mut definition = format!("{} {}", field.name, self.column_type(field, dialect));
+
+        if field.unsigned && dialect == Dialect::MySql && field.field_type.is_integer() {
+            definition.push_str(" UNSIGNED");
+        }
+
+        definition.push_str(if field.nullable { " NULL" } else { " NOT NULL" });
+
+        match &field.default {
+            Some(ColumnDefault::Null) => definition.push_str(" DEFAULT NULL"),
+            Some(ColumnDefault::Value(value)) => definition.push_str(&format!(" DEFAULT {}", Self::default_literal(value))),
+            None => {}
+        }
+
+        if field.primary && inline_primary {
+            if field.auto_increment && dialect == Dialect::MySql {
+                definition.push_str(" AUTO_INCREMENT");
+            }
+            definition.push_str(" PRIMARY KEY");
+        } else if field.unique {
+            definition.push_str(" UNIQUE");
+        }
+
+        definition
+    }
+
+    /// Column type as Laravel's schema grammar for the dialect would create it
+    fn column_type(&self, field: &Field, dialect: Dialect) -> String {
+        use Dialect::*;
+
+        // An auto-incrementing key has to be SERIAL / INTEGER to count on its own
+        if field.primary && field.auto_increment {
+            match dialect {
+                Postgres => return if matches!(field.field_type, FieldType::BigInteger) { "BIGSERIAL" } else { "SERIAL" }.to_string(),
+                Sqlite => return "INTEGER".to_string(),
+                MySql => {}
+            }
+        }
+
+        // Laravel stores encrypted values as base64 payloads: longer than a VARCHAR, and not JSON
+        if field.encrypted && matches!(field.field_type, FieldType::String | FieldType::Json) {
+            return "TEXT".to_string();
+        }
+
+        match (&field.field_type, dialect) {
+            (FieldType::String, _) => format!("VARCHAR({})", field.length.unwrap_or(255)),
+            (FieldType::Text, _) => "TEXT".to_string(),
+            (FieldType::MediumText, MySql) => "MEDIUMTEXT".to_string(),
+            (FieldType::LongText, MySql) => "LONGTEXT".to_string(),
+            (FieldType::MediumText | FieldType::LongText, _) => "TEXT".to_string(),
+            (FieldType::TinyInteger, MySql) => "TINYINT".to_string(),
+            (FieldType::MediumInteger, MySql) => "MEDIUMINT".to_string(),
+            (FieldType::TinyInteger | FieldType::SmallInteger, Postgres) => "SMALLINT".to_string(),
+            (FieldType::SmallInteger, MySql) => "SMALLINT".to_string(),
+            (FieldType::Integer | FieldType::MediumInteger, MySql | Postgres) => "INT".to_string(),
+            (FieldType::BigInteger, MySql | Postgres) => "BIGINT".to_string(),
+            (FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger
+                | FieldType::SmallInteger | FieldType::MediumInteger, Sqlite) => "INTEGER".to_string(),
+            (FieldType::Float, MySql) => "DOUBLE".to_string(),
+            (FieldType::Float, Postgres) => "DOUBLE PRECISION".to_string(),
+            (FieldType::Float, Sqlite) => "REAL".to_string(),
+            (FieldType::Decimal, Sqlite) => "NUMERIC".to_string(),
+            (FieldType::Decimal, _) => {
+                let (precision, scale) = field.decimal_precision.as_ref()
+                    .map_or((8, 2), |p| (p.precision, p.scale));
+                format!("DECIMAL({}, {})", precision, scale)
+            }
+            (FieldType::Boolean, MySql) => "TINYINT(1)".to_string(),
+            (FieldType::Boolean, Postgres) => "BOOLEAN".to_string(),
+            (FieldType::Boolean, Sqlite) => "INTEGER".to_string(),
+            (FieldType::Date, _) => "DATE".to_string(),
+            (FieldType::Time, _) => "TIME".to_string(),
+            (FieldType::Year, MySql) => "YEAR".to_string(),
+            (FieldType::Year, _) => "INTEGER".to_string(),
+            (FieldType::DateTime, Postgres) => "TIMESTAMP".to_string(),
+            (FieldType::DateTime, _) => "DATETIME".to_string(),
+            (FieldType::Timestamp, _) => Self::timestamp_type(dialect).to_string(),
+            (FieldType::Json, Sqlite) => "TEXT".to_string(),
+            (FieldType::Json, _) => "JSON".to_string(),
+            (FieldType::Uuid, MySql) => "CHAR(36)".to_string(),
+            (FieldType::Uuid, Postgres) => "UUID".to_string(),
+            (FieldType::Uuid, Sqlite) => "VARCHAR(36)".to_string(),
+            (FieldType::Enum, MySql) if !field.enum_values.is_empty() => {
+                format!("ENUM({})", Self::enum_list(field))
+            }
+            (FieldType::Enum, _) if !field.enum_values.is_empty() => {
+                format!("VARCHAR(255) CHECK ({} IN ({}))", field.name, Self::enum_list(field))
+            }
+            (FieldType::Enum, _) => "VARCHAR(255)".to_string(),
+            (FieldType::Binary, Postgres) => "BYTEA".to_string(),
+            (FieldType::Binary, _) => "BLOB".to_string(),
+            (FieldType::Inet, Postgres) => "INET".to_string(),
+            (FieldType::Inet, _) => "VARCHAR(45)".to_string(),
+            (FieldType::Point, Postgres) => "GEOMETRY(POINT)".to_string(),
+            (FieldType::Polygon, Postgres) => "GEOMETRY(POLYGON)".to_string(),
+            (FieldType::Point, _) => "POINT".to_string(),
+            (FieldType::Polygon, _) => "POLYGON".to_string(),
+            (FieldType::Geometry, _) => "GEOMETRY".to_string(),
+        }
+    }
+
+    fn dialect(config: &Config) -> Dialect {
+        match config.database_engine.to_lowercase().as_str() {
+            "pgsql" | "postgres" | "postgresql" => Dialect::Postgres,
+            "sqlite" => Dialect::Sqlite,
+            _ => Dialect::MySql,
+        }
+    }
+
+    fn foreign_id_type(dialect: Dialect) -> &'static str {
+        match dialect {
+            Dialect::MySql => "BIGINT UNSIGNED",
+            Dialect::Postgres => "BIGINT",
+            Dialect::Sqlite => "INTEGER",
+        }
+    }
+
+    fn timestamp_type(dialect: Dialect) -> &'static str {
+        match dialect {
+            Dialect::Sqlite => "DATETIME",
+            _ => "TIMESTAMP",
+        }
+    }
+
+    fn enum_list(field: &Field) -> String {
+        field.enum_values.iter()
+            .map(|v| Self::quote(&v.value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Numbers, booleans and `CURRENT_TIMESTAMP` stay bare; anything else is a string literal
+    fn default_literal(value: &str) -> String {
+        if value.eq_ignore_ascii_case("CURRENT_TIMESTAMP") || value.parse::<f64>().is_ok() {
+            value.to_string()
+        } else if value == "true" || value == "false" {
+            value.to_uppercase()
+        } else {
+            Self::quote(value)
+        }
+    }
+
+    fn quote(value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn users_model() -> ModelDefinition {
+        let mut email = Field::new("email", FieldType::String);
+        email.unique = true;
+        let mut nickname = Field::new("nickname", FieldType::String);
+        nickname.nullable = true;
+        nickname.length = Some(50);
+        let mut active = Field::new("active", FieldType::Boolean);
+        active.default = Some(ColumnDefault::Value("true".to_string()));
+
+        ModelDefinition {
+            name: "User".to_string(),
+            table: "users".to_string(),
+            fields: vec![Field::new("name", FieldType::String), email, nickname, active],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_create_table_for_mysql() {
+        let sql = SqlGenerator.generate_schema(&[users_model()], &Config::default()).unwrap();
+
+        assert!(sql.contains("CREATE TABLE users (\n"));
+        assert!(sql.contains("    id BIGINT UNSIGNED NOT NULL AUTO_INCREMENT PRIMARY KEY,\n"));
+        assert!(sql.contains("    name VARCHAR(255) NOT NULL,\n"));
+        assert!(sql.contains("    email VARCHAR(255) NOT NULL UNIQUE,\n"));
+        assert!(sql.contains("    nickname VARCHAR(50) NULL,\n"));
+        assert!(sql.contains("    active TINYINT(1) NOT NULL DEFAULT TRUE,\n"));
+        assert!(sql.contains("    updated_at TIMESTAMP NULL\n);\n"));
+    }
+
+    #[test]
+    fn test_create_table_follows_database_engine() {
+        let config = Config { database_engine: "pgsql".to_string(), ..Config::default() };
+        let sql = SqlGenerator.generate_schema(&[users_model()], &config).unwrap();
+
+        assert!(sql.contains("    id BIGSERIAL PRIMARY KEY,\n"));
+        assert!(sql.contains("    active BOOLEAN NOT NULL DEFAULT TRUE,\n"));
+    }
+
+    #[test]
+    fn test_null_default_apart_from_null_string() {
+        let mut nickname = Field::new("nickname", FieldType::String);
+        nickname.nullable = true;
+        nickname.default = Some(ColumnDefault::Null);
+        let mut code = Field::new("code", FieldType::String);
+        code.default = Some(ColumnDefault::Value("NULL".to_string()));
+        let model = ModelDefinition { fields: vec![nickname, code], ..users_model() };
+
+        let sql = SqlGenerator.generate_schema(&[model], &Config::default()).unwrap();
+
+        assert!(sql.contains("    nickname VARCHAR(255) NULL DEFAULT NULL,\n"));
+        assert!(sql.contains("    code VARCHAR(255) NOT NULL DEFAULT 'NULL',\n"));
+    }
+
+    #[test]
+    fn test_encrypted_columns_are_text() {
+        let mut ssn = Field::new("ssn", FieldType::String);
+        ssn.encrypted = true;
+        let mut history = Field::new("medical_history", FieldType::Json);
+        history.encrypted = true;
+        let model = ModelDefinition { fields: vec![ssn, history], ..users_model() };
+
+        let sql = SqlGenerator.generate_schema(&[model], &Config::default()).unwrap();
+
+        assert!(sql.contains("    ssn TEXT NOT NULL,\n"));
+        assert!(sql.contains("    medical_history TEXT NOT NULL,\n"));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::error::{GeneratorError, Result};
+use crate::types::{Config, Field, FieldType};
+use crate::validation::Validator;
+use std::io::{BufRead, Write};
+
+/// Field types offered when adding a field, in menu order
+const FIELD_TYPES: [(&str, FieldType); 10] = [
+    ("string", FieldType::String),
+    ("text", FieldType::Text),
+    ("integer", FieldType::Integer),
+    ("bigInteger", FieldType::BigInteger),
+    ("decimal", FieldType::Decimal),
+    ("boolean", FieldType::Boolean),
+    ("date", FieldType::Date),
+    ("dateTime", FieldType::DateTime),
+    ("json", FieldType::Json),
+    ("uuid", FieldType::Uuid),
+];
+
+/// Prompts for quick edits to a loaded config: toggling timestamps and soft deletes
+/// and adding fields to the selected models
+///
+/// Answers are read from `input` and prompts written to `output`, so the whole
+/// session can be scripted.
+pub struct InteractiveMode<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> InteractiveMode<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        InteractiveMode { input, output }
+    }
+
+    /// Asks which models to edit, then runs the edit step for each of them
+    pub fn edit_models(&mut self, config: &mut Config) -> Result<()> {
+        for (number, model) in config.models.iter().enumerate() {
+            writeln!(self.output, "  {}) {}", number + 1, model.name)?;
+        }
+        let selection = self.ask("Models to edit (comma-separated numbers, blank for none): ")?;
+
+        for number in selection.split(',').map(str::trim).filter(|number| !number.is_empty()) {
+            match number.parse::<usize>() {
+                Ok(number) if (1..=config.models.len()).contains(&number) => self.edit_model(config, number - 1)?,
+                _ => writeln!(self.output, "Ignoring unknown model number '{}'", number)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn edit_model(&mut self, config: &mut Config, index: usize) -> Result<()> {
+        writeln!(self.output, "{}:", config.models[index].name)?;
+
+        let timestamps = config.models[index].has_timestamps(config);
+        if self.confirm(&format!("  Timestamps are {}. Toggle?", on_off(timestamps)))? {
+            config.models[index].timestamps = Some(!timestamps);
+        }
+        let soft_deletes = config.models[index].has_soft_deletes(config);
+        if self.confirm(&format!("  Soft deletes are {}. Toggle?", on_off(soft_deletes)))? {
+            config.models[index].soft_deletes = Some(!soft_deletes);
+        }
+
+        loop {
+            let name = self.ask("  New field name (blank to finish): ")?;
+            if name.is_empty() {
+                return Ok(());
+            }
+            let field_type = self.pick_field_type()?;
+            let nullable = self.confirm("  Nullable?")?;
+            let field = new_field(&name, field_type, nullable);
+
+            let model = &mut config.models[index];
+            if model.fields.iter().any(|existing| existing.name == field.name) {
+                writeln!(self.output, "  ✗ {} already has a field named '{}'", model.name, field.name)?;
+            } else if let Err(e) = Validator::validate_field(&field) {
+                writeln!(self.output, "  ✗ {}", e)?;
+            } else {
+                model.fields.push(field);
+            }
+        }
+    }
+
+    fn pick_field_type(&mut self) -> Result<FieldType> {
+        for (number, (label, _)) in FIELD_TYPES.iter().enumerate() {
+            writeln!(self.output, "    {}) {}", number + 1, label)?;
+        }
+        loop {
+            let answer = self.ask("  Type: ")?;
+            match answer.parse::<usize>() {
+                Ok(number) if (1..=FIELD_TYPES.len()).contains(&number) => return Ok(FIELD_TYPES[number - 1].1.clone()),
+                _ => writeln!(self.output, "  Pick a number from 1 to {}", FIELD_TYPES.len())?,
+            }
+        }
+    }
+
+    /// Yes/no question answered with `y`; anything else means no
+    fn confirm(&mut self, question: &str) -> Result<bool> {
+        let answer = self.ask(&format!("{} [y/N] ", question))?;
+        Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+    }
+
+    fn ask(&mut self, question: &str) -> Result<String> {
+        write!(self.output, "{}", question)?;
+        self.output.flush()?;
+        let mut answer = String::new();
+        if self.input.read_line(&mut answer)? == 0 {
+            return Err(GeneratorError::Configuration("Input ended before the edit was finished".to_string()));
+        }
+        Ok(answer.trim().to_string())
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { "on" } else { "off" }
+}
+
+fn new_field(name: &str, field_type: FieldType, nullable: bool) -> Field {
+    Field { nullable, ..Field::new(name, field_type) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config::from_json(r#"{
+            "models": [
+                { "name": "User", "table": "users", "fields": [{ "name": "name", "type": "string" }] },
+                { "name": "Post", "table": "posts", "timestamps": true, "fields": [{ "name": "title", "type": "string" }] }
+            ]
+        }"#, false).unwrap()
+    }
+
+    fn run(config: &mut Config, answers: &str) -> String {
+        let mut output = Vec::new();
+        InteractiveMode::new(answers.as_bytes(), &mut output).edit_models(config).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_add_field() {
+        let mut config = config();
+        // Post: keep timestamps and soft deletes, add a nullable boolean, reject a duplicate, finish
+        let output = run(&mut config, "2\nn\n\nis_draft\n6\ny\ntitle\n1\nn\n\n");
+
+        let field = config.models[1].fields.last().unwrap();
+        assert_eq!(field.name, "is_draft");
+        assert!(matches!(field.field_type, FieldType::Boolean));
+        assert!(field.nullable);
+        assert_eq!(config.models[1].fields.len(), 2);
+        assert!(output.contains("Post already has a field named 'title'"));
+        assert_eq!(config.models[0].fields.len(), 1);
+    }
+
+    #[test]
+    fn test_toggle_soft_deletes() {
+        let mut config = config();
+        run(&mut config, "1\nn\ny\n\n");
+
+        assert_eq!(config.models[0].soft_deletes, Some(true));
+        assert_eq!(config.models[0].timestamps, None);
+        assert_eq!(config.models[1].soft_deletes, None);
+    }
+
+    #[test]
+    fn test_invalid_field_name_rejected() {
+        let mut config = config();
+        let output = run(&mut config, "1\nn\nn\n9lives\n1\nn\n\n");
+
+        assert_eq!(config.models[0].fields.len(), 1);
+        assert!(output.contains("✗"));
+    }
+}
@@ -1,6 +1,97 @@
+pub mod builder;
+pub mod diff;
 pub mod error;
 pub mod generators;
 pub mod schema;
 pub mod template;
 pub mod types;
 pub mod validation;
+
+use error::Result;
+use generators::Generator;
+use types::{Config, ModelDefinition, PivotTable};
+
+/// Renders the Eloquent model class for a model
+pub fn generate_model_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::model_generator::ModelGenerator.generate(model, config)
+}
+
+/// Renders the create-table migration for a model
+pub fn generate_migration_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::migration_generator::MigrationGenerator.generate(model, config)
+}
+
+/// Renders the API controller for a model
+pub fn generate_controller_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::controller_generator::ControllerGenerator.generate(model, config)
+}
+
+/// Renders the API resource for a model
+pub fn generate_resource_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::resource_generator::ResourceGenerator.generate(model, config)
+}
+
+/// Renders the model factory for a model
+pub fn generate_factory_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::factory_generator::FactoryGenerator.generate(model, config)
+}
+
+/// Renders the DTO class for a model
+pub fn generate_dto_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::dto_generator::DtoGenerator.generate(model, config)
+}
+
+/// Renders the form request for a model, where `action` is either "store" or "update"
+pub fn generate_request_string(model: &ModelDefinition, config: &Config, action: &str) -> Result<String> {
+    generators::request_generator::RequestGenerator.generate_action(model, config, action)
+}
+
+/// Renders the Eloquent repository implementation for a model
+pub fn generate_repository_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::repository_generator::RepositoryGenerator.generate(model, config)
+}
+
+/// Renders the repository interface for a model
+pub fn generate_repository_interface_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::repository_generator::RepositoryGenerator.generate_interface(model, config)
+}
+
+/// Renders the service class for a model
+pub fn generate_service_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::service_generator::ServiceGenerator.generate(model, config)
+}
+
+/// Renders the database seeder for a model
+pub fn generate_seeder_string(model: &ModelDefinition, config: &Config) -> Result<String> {
+    generators::seeder_generator::SeederGenerator.generate(model, config)
+}
+
+/// Renders the `routes/api_generated.php` include for a set of models
+pub fn generate_routes_string(models: &[ModelDefinition], config: &Config) -> Result<String> {
+    generators::route_generator::RouteGenerator.generate_routes_file(models, config)
+}
+
+/// Renders the combined GraphQL schema for a set of models
+pub fn generate_graphql_schema_string(models: &[ModelDefinition], config: &Config) -> Result<String> {
+    generators::graphql_generator::GraphQlGenerator.generate_schema(models, config)
+}
+
+/// Renders the backed enum class for one of a model's enum fields
+pub fn generate_enum_string(model: &ModelDefinition, field: &types::Field, config: &Config) -> Result<String> {
+    generators::enum_generator::EnumGenerator.generate_enum(model, field, config)
+}
+
+/// Renders a best-effort `CREATE TABLE` script for a set of models
+pub fn generate_sql_schema_string(models: &[ModelDefinition], config: &Config) -> Result<String> {
+    generators::sql_generator::SqlGenerator.generate_schema(models, config)
+}
+
+/// Renders the `ValidationRule` class for a custom rule
+pub fn generate_rule_string(rule_name: &str, config: &Config) -> Result<String> {
+    generators::rule_generator::RuleGenerator.generate_rule(rule_name, config)
+}
+
+/// Renders the migration for a pivot table
+pub fn generate_pivot_table_string(pivot_table: &PivotTable, config: &Config) -> Result<String> {
+    generators::pivot_table_generator::PivotTableGenerator.generate_pivot_table(pivot_table, config)
+}
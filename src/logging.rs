@@ -0,0 +1,48 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Minimal stderr logger for the CLI
+///
+/// Warnings and errors are always shown; `--verbose` adds info and `--debug`
+/// adds debug events such as template diagnostics and timings. The per-file
+/// progress lines and the summary stay on stdout.
+struct CliLogger;
+
+static LOGGER: CliLogger = CliLogger;
+
+impl Log for CliLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        match record.level() {
+            Level::Error => eprintln!("❌ {}", record.args()),
+            Level::Warn => eprintln!("⚠️  Warning: {}", record.args()),
+            Level::Info => eprintln!("{}", record.args()),
+            Level::Debug | Level::Trace => eprintln!("[{}] {}", record.level().as_str().to_lowercase(), record.args()),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Level for the global `--verbose` / `--debug` flags
+pub fn level_for(verbose: bool, debug: bool) -> LevelFilter {
+    if debug {
+        LevelFilter::Debug
+    } else if verbose {
+        LevelFilter::Info
+    } else {
+        LevelFilter::Warn
+    }
+}
+
+/// Installs the CLI logger; later calls keep the first logger
+pub fn init(level: LevelFilter) {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
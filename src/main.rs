@@ -1,5 +1,9 @@
+mod cache;
+mod diff;
 mod error;
 mod generators;
+mod interactive;
+mod logging;
 mod schema;
 mod template;
 mod types;
@@ -8,8 +12,14 @@ mod validation;
 use clap::{Parser, Subcommand};
 use error::Result;
 use generators::*;
+use generators::render::RenderedFile;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime};
 use types::Config;
 use validation::Validator;
 
@@ -20,6 +30,53 @@ pub enum WriteResult {
     Error(String),
 }
 
+/// One entry of the `--manifest` report
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ManifestEntry {
+    path: String,
+    component: String,
+    /// `written`, `skipped` or `error`
+    result: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    error: Option<String>,
+}
+
+/// Generated files grouped by the model they came from
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct Manifest {
+    models: std::collections::BTreeMap<String, Vec<ManifestEntry>>,
+    /// Files covering every model (routes, GraphQL schema, validation rules)
+    shared: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    fn record(&mut self, file: &RenderedFile, result: &WriteResult) {
+        let (status, error) = match result {
+            WriteResult::Written => ("written", None),
+            WriteResult::Skipped => ("skipped", None),
+            WriteResult::Error(e) => ("error", Some(e.clone())),
+        };
+        let entry = ManifestEntry {
+            path: file.path.clone(),
+            component: file.component.to_string(),
+            result: status.to_string(),
+            error,
+        };
+
+        match &file.model {
+            Some(model) => self.models.entry(model.clone()).or_default().push(entry),
+            None => self.shared.push(entry),
+        }
+    }
+
+    fn write(&self, path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| error::GeneratorError::Configuration(format!("Failed to serialize manifest: {}", e)))?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default)]
 struct GenerationStats {
     written: usize,
@@ -27,11 +84,15 @@ struct GenerationStats {
     errors: usize,
 }
 
+/// Writes a generated file, deciding what to do when it already exists
+///
+/// `force` overwrites unconditionally; `update` overwrites only files that still
+/// carry schemly's generated marker. Otherwise existing files are skipped.
+fn safe_write_file(file_path: &str, content: &str, force: bool, update: bool) -> Result<WriteResult> {
+    let overwrite = force || (update && fs::read_to_string(file_path)
+        .is_ok_and(|existing| generators::shared::GeneratedMarker::is_present(&existing)));
 
-
-
-fn safe_write_file(file_path: &str, content: &str, force: bool) -> Result<WriteResult> {
-    if force {
+    if overwrite {
         // Always attempt to write, overwriting existing files
         match fs::write(file_path, content) {
             Ok(()) => Ok(WriteResult::Written),
@@ -61,23 +122,34 @@ EXAMPLES:
     schemly generate                                  # Generate all components
     schemly generate --dry-run                        # Preview what would be generated
     schemly generate --force                          # Overwrite existing files
+    schemly generate --update                         # Overwrite only files schemly generated
     schemly generate --only models,migrations         # Generate only specific components
+    schemly validate                                  # Check the schema without generating
     schemly watch                                     # Watch schema file and auto-generate
     schemly doctor                                    # Check Laravel project compatibility
 
 SAFETY:
-    By default, existing files are NOT overwritten. Use --force to overwrite.")]
+    By default, existing files are NOT overwritten. Use --force to overwrite, or --update
+    to overwrite only files that still carry the `@generated by schemly` marker.")]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// Path to schema file (default: ./schema.schemly)
+    /// Path to schema file, `.schemly`, `.json` or `.yml`, or a directory of `.yml` files (default: ./schema.schemly)
     #[arg(short, long, global = true)]
     file: Option<String>,
 
     /// Print detailed logs
     #[arg(short, long, global = true)]
     verbose: bool,
+
+    /// Print debug logs: template diagnostics and generation timings
+    #[arg(long, global = true)]
+    debug: bool,
+
+    /// Reject unknown keys in `.json` configs instead of ignoring them
+    #[arg(long, global = true)]
+    strict_config: bool,
 }
 
 #[derive(Subcommand)]
@@ -107,17 +179,69 @@ enum Commands {
         #[arg(long)]
         force: bool,
 
-        /// Generate only specific components (comma-separated: models,migrations,controllers,resources,factories,dtos,requests,pivot)
+        /// Overwrite only existing files that still carry schemly's generated marker, skipping hand-edited ones
+        #[arg(long, conflicts_with = "force")]
+        update: bool,
+
+        /// Rename invalid field names to valid identifiers (`invalid-name` -> `invalid_name`) instead of failing
+        #[arg(long)]
+        sanitize: bool,
+
+        /// Generate only specific components (comma-separated: models,migrations,controllers,resources,factories,dtos,requests,pivot,graphql,repositories,services,seeders,routes,rules,enums)
         #[arg(long, value_delimiter = ',')]
         only: Option<Vec<String>>,
 
-        /// Exclude specific components (comma-separated: models,migrations,controllers,resources,factories,dtos,requests,pivot)
+        /// Exclude specific components (comma-separated: models,migrations,controllers,resources,factories,dtos,requests,pivot,graphql,repositories,services,seeders,routes,rules,enums)
         #[arg(long, value_delimiter = ',', conflicts_with = "only")]
         exclude: Option<Vec<String>>,
 
         /// Use Domain-Driven Design folder structure
         #[arg(long)]
         ddd: bool,
+
+        /// Generate only specific models (comma-separated model names)
+        #[arg(long, value_delimiter = ',')]
+        model: Option<Vec<String>>,
+
+        /// Print the generated file to stdout instead of writing it (requires a selection producing exactly one file)
+        #[arg(long, conflicts_with = "dry_run")]
+        stdout: bool,
+
+        /// Show a unified diff of what would change in existing files, writing nothing
+        #[arg(long, conflicts_with_all = ["dry_run", "stdout"])]
+        diff: bool,
+
+        /// Render models on this many threads (files are still written one at a time)
+        #[arg(long, default_value_t = 1, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        jobs: usize,
+
+        /// Print how long each component took to render across all models
+        #[arg(long)]
+        timings: bool,
+
+        /// Skip models whose definition is unchanged since the last run with this flag (hashes kept in .schemly-cache.json)
+        #[arg(long)]
+        only_changed: bool,
+
+        /// Write create-table migrations, or alter migrations adding only the fields marked new (overrides migrationMode)
+        #[arg(long, value_parser = ["create", "alter"])]
+        migration_mode: Option<String>,
+
+        /// Write a JSON manifest of the generated files, grouped by model, to this path
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["dry_run", "stdout", "diff"])]
+        manifest: Option<String>,
+
+        /// Write a best-effort CREATE TABLE script for the models to this path instead of generating Laravel files
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["dry_run", "stdout", "diff", "manifest"])]
+        emit_sql: Option<String>,
+
+        /// Fail instead of warning when the output directory has no artisan or composer.json
+        #[arg(long)]
+        require_laravel: bool,
+
+        /// Rows each seeder creates, for models without their own seed count (overrides seedCount)
+        #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<u32>::new().range(1..))]
+        seed_count: Option<u32>,
     },
 
     /// Watches the schema file and auto-generates on save
@@ -126,6 +250,10 @@ enum Commands {
         #[arg(short, long, default_value = ".")]
         output: String,
 
+        /// Preview what would be generated on each change without writing files
+        #[arg(long)]
+        dry_run: bool,
+
         /// Force overwrite existing files
         #[arg(long)]
         force: bool,
@@ -146,6 +274,31 @@ enum Commands {
         path: String,
     },
 
+    /// Checks the schema for problems without generating anything
+    Validate {
+        /// First check the raw JSON or YAML config against the bundled JSON Schema (schemas/schemly.schema.json)
+        #[arg(long)]
+        schema: bool,
+    },
+
+    /// Lists the models and enabled components without generating anything
+    List {
+        /// Also show each model's table and the files `generate` would write
+        #[arg(long)]
+        paths: bool,
+
+        /// Laravel project root the file paths are resolved against
+        #[arg(short, long, default_value = ".")]
+        output: String,
+    },
+
+    /// Toggles timestamps / soft deletes and adds fields through prompts, saving the edited config
+    Edit {
+        /// Where to save the edited config (.json, .yml or .yaml)
+        #[arg(short, long)]
+        output: String,
+    },
+
     /// Creates AI editor rules (.cursorrules, .windsurfrules) for Schemly
     InitRules {
         /// Output directory (default: current directory)
@@ -160,78 +313,151 @@ enum Commands {
 
 struct LaravelGenerator {
     config: Config,
+    /// Number of threads rendering models in parallel
+    jobs: usize,
+    /// Time spent rendering each component across all models, collected with `--timings`
+    timings: Option<Mutex<BTreeMap<&'static str, Duration>>>,
+    /// Model hashes from the last run; set by `--only-changed` to skip unchanged models
+    cache: Option<cache::GenerationCache>,
 }
 
 impl LaravelGenerator {
-    pub fn from_file(file_path: &str) -> Result<Self> {
-        let schema_content = fs::read_to_string(file_path)?;
-        let schema = schema::parse_schema(&schema_content)
-            .map_err(error::GeneratorError::ParseError)?;
-        let config = schema::SchemaConverter::convert_to_config(schema)
-            .map_err(error::GeneratorError::ParseError)?;
+    /// Loads a `.schemly` schema, or a serialized `Config` when the file ends in `.json`
+    ///
+    /// With `strict`, JSON keys that no config struct declares are rejected.
+    pub fn from_file(file_path: &str, strict: bool) -> Result<Self> {
+        let config = Config::read(file_path, strict)?;
         config.validate()?;
-        Ok(LaravelGenerator { config })
+        Ok(LaravelGenerator { config, jobs: 1, timings: None, cache: None })
     }
 
-    pub fn generate_all(&self) -> Result<()> {
+    /// Writes every enabled component, returning what happened to each file
+    pub fn generate_all(&self) -> Result<Manifest> {
+        let files = self.render_files()?;
         self.create_directories()?;
 
         let mut stats = GenerationStats::default();
-
-        // Generate pivot tables first
-        if self.config.generate_pivot_tables {
-            // Process pivot tables from each model
-            for model in &self.config.models {
-                for pivot_table in &model.pivot_tables {
-                    let result = self.generate_pivot_table(pivot_table)?;
-                    self.update_stats(&mut stats, result);
-                }
-            }
+        let mut manifest = Manifest::default();
+        for file in &files {
+            let result = self.write_file(file)?;
+            manifest.record(file, &result);
+            self.update_stats(&mut stats, result);
         }
 
-        for model in &self.config.models {
-            // Validate each model before processing
-            Validator::validate_model(model)?;
+        // Enhanced summary logging
+        self.print_summary(&stats);
+        self.print_timings();
+        Ok(manifest)
+    }
 
-            if self.config.generate_models {
-                let result = self.generate_component(&model_generator::ModelGenerator, model, &format!("Generated model: {}", model.name))?;
-                self.update_stats(&mut stats, result);
-            }
+    /// Renders every enabled component to a map of file path to content, writing nothing
+    pub fn generate_all_to_map(&self) -> Result<HashMap<String, String>> {
+        render::render_to_map(&self.config)
+    }
 
-            if self.config.generate_migrations {
-                let result = self.generate_component(&migration_generator::MigrationGenerator, model, &format!("Generated migration for table: {}", model.table))?;
-                self.update_stats(&mut stats, result);
+    /// Renders every enabled component in memory without touching the filesystem
+    fn render_files(&self) -> Result<Vec<RenderedFile>> {
+        let skip_model = |model: &types::ModelDefinition| self.is_unchanged(model);
+        let on_timing = |component, elapsed| {
+            if let Some(timings) = &self.timings {
+                *timings.lock().unwrap().entry(component).or_default() += elapsed;
             }
+        };
+        render::render_with(&self.config, render::RenderOptions {
+            jobs: self.jobs,
+            skip_model: Some(&skip_model),
+            on_timing: Some(&on_timing),
+        })
+    }
 
-            if self.config.generate_controllers {
-                let result = self.generate_component(&controller_generator::ControllerGenerator, model, &format!("Generated controller: {}Controller", model.name))?;
-                self.update_stats(&mut stats, result);
-            }
+    /// Prints the single selected file to stdout instead of writing it to disk
+    pub fn print_to_stdout(&self) -> Result<()> {
+        let files = self.render_files()?;
+        if files.len() != 1 {
+            return Err(error::GeneratorError::Configuration(format!(
+                "--stdout requires a selection that produces exactly one file, but {} files would be generated. Narrow it down with --only and --model.",
+                files.len()
+            )));
+        }
 
-            if self.config.generate_resources {
-                let result = self.generate_component(&resource_generator::ResourceGenerator, model, &format!("Generated resource: {}Resource", model.name))?;
-                self.update_stats(&mut stats, result);
+        print!("{}", files[0].content);
+        Ok(())
+    }
+
+    /// Overview of the models and enabled components; with `paths`, each model's table and files too
+    fn listing(&self, paths: bool) -> Result<String> {
+        let config = &self.config;
+        let mut listing = format!("Components: {}\n", get_enabled_components_list(config).join(", "));
+        let files = if paths { self.render_files()? } else { Vec::new() };
+
+        for model in &config.models {
+            let relationships = match model.relationships.len() {
+                0 => "no relationships".to_string(),
+                count => format!(
+                    "{} relationship{}: {}",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    model.relationships.iter().map(shared::RelationshipHelper::describe).collect::<Vec<_>>().join(", ")
+                ),
+            };
+            let field_count = model.fields.len();
+            listing.push_str(&format!(
+                "\n{}: {} field{}, {}\n",
+                model.name, field_count, if field_count == 1 { "" } else { "s" }, relationships
+            ));
+
+            if paths {
+                listing.push_str(&format!("  table: {}\n", model.table_name(config)));
+                for file in files.iter().filter(|file| file.model.as_deref() == Some(model.name.as_str())) {
+                    listing.push_str(&format!("  {}: {}\n", file.component, file.path));
+                }
             }
+        }
 
-            if self.config.generate_factories {
-                let result = self.generate_component(&factory_generator::FactoryGenerator, model, &format!("Generated factory: {}Factory", model.name))?;
-                self.update_stats(&mut stats, result);
+        // Routes, rules and the GraphQL schema belong to no single model
+        let shared_files: Vec<&RenderedFile> = files.iter().filter(|file| file.model.is_none()).collect();
+        if !shared_files.is_empty() {
+            listing.push_str("\nShared:\n");
+            for file in shared_files {
+                listing.push_str(&format!("  {}: {}\n", file.component, file.path));
             }
+        }
+        Ok(listing)
+    }
+
+    /// Prints a unified diff for every generated file that already exists
+    pub fn print_diff(&self) -> Result<()> {
+        let (mut changed, mut unchanged, mut new) = (0, 0, 0);
 
-            if self.config.generate_dto {
-                let result = self.generate_component(&dto_generator::DtoGenerator, model, &format!("Generated DTO: {}DTO", model.name))?;
-                self.update_stats(&mut stats, result);
+        for file in self.render_files()? {
+            if !Path::new(&file.path).exists() {
+                println!("New file: {}", file.path);
+                new += 1;
+                continue;
             }
 
-            if self.config.generate_requests {
-                let (store_res, update_res) = self.generate_request(model)?;
-                self.update_stats(&mut stats, store_res);
-                self.update_stats(&mut stats, update_res);
+            let existing = fs::read_to_string(&file.path)?;
+            let file_diff = diff::unified_diff(&existing, &file.content, &file.path, &format!("{} (generated)", file.path));
+            if file_diff.is_empty() {
+                unchanged += 1;
+            } else {
+                print!("{}", file_diff);
+                changed += 1;
             }
         }
 
-        // Enhanced summary logging
-        self.print_summary(&stats);
+        println!("\nDiff summary: {} changed, {} unchanged, {} new", changed, unchanged, new);
+        Ok(())
+    }
+
+    /// Writes a `CREATE TABLE` script for the selected models
+    pub fn write_sql(&self, path: &str) -> Result<()> {
+        for model in &self.config.models {
+            Validator::validate_model(model)?;
+        }
+        let content = sql_generator::SqlGenerator.generate_schema(&self.config.models, &self.config)?;
+        fs::write(path, content)?;
+        println!("Wrote SQL schema for {} models: {}", self.config.models.len(), path);
         Ok(())
     }
 
@@ -247,72 +473,56 @@ impl LaravelGenerator {
         Ok(())
     }
 
-    fn generate_pivot_table(&self, pivot_table: &types::PivotTable) -> Result<WriteResult> {
-        let generator = pivot_table_generator::PivotTableGenerator;
-        let content = generator.generate_pivot_table(pivot_table, &self.config)?;
-        let file_path = generator.get_pivot_file_path(pivot_table, &self.config);
+    /// Whether `--only-changed` can skip the model: cached with the same definition, and no `--force`
+    fn is_unchanged(&self, model: &types::ModelDefinition) -> bool {
+        !self.config.force_overwrite
+            && self.cache.as_ref().is_some_and(|cache| cache.is_unchanged(model))
+    }
 
-        let result = safe_write_file(&file_path, &content, self.config.force_overwrite)?;
-        match &result {
-            WriteResult::Written => println!("Generated pivot table: {}", pivot_table.name),
-            WriteResult::Skipped => {
-                println!("Warning: File already exists, skipping: {}", file_path)
+    /// Cache for the next `--only-changed` run, leaving out models whose files failed to write
+    fn updated_cache(&self, manifest: &Manifest) -> Option<cache::GenerationCache> {
+        let mut cache = self.cache.clone()?;
+        for model in &self.config.models {
+            let failed = manifest.models.get(&model.name)
+                .is_some_and(|entries| entries.iter().any(|entry| entry.error.is_some()));
+            if failed {
+                cache.remove(&model.name);
+            } else {
+                cache.insert(model);
             }
-            WriteResult::Error(e) => println!("Error writing {}: {}", file_path, e),
         }
-        Ok(result)
+        Some(cache)
     }
 
-    fn generate_component<G: generators::Generator>(
-        &self,
-        generator: &G,
-        model: &types::ModelDefinition,
-        message: &str,
-    ) -> Result<WriteResult> {
-        let content = generator.generate(model, &self.config)?;
-        let file_path = generator.get_file_path(model, &self.config);
+    /// Prints the `--timings` breakdown, slowest component first
+    fn print_timings(&self) {
+        let Some(timings) = &self.timings else {
+            return;
+        };
+        let mut timings: Vec<_> = timings.lock().unwrap().iter().map(|(component, elapsed)| (*component, *elapsed)).collect();
+        timings.sort_by_key(|(_, elapsed)| std::cmp::Reverse(*elapsed));
+
+        println!("\nTimings:");
+        for (component, elapsed) in timings {
+            println!("  {}: {:.2?}", component, elapsed);
+        }
+    }
 
-        let result = safe_write_file(&file_path, &content, self.config.force_overwrite)?;
+    fn write_file(&self, file: &RenderedFile) -> Result<WriteResult> {
+        let result = safe_write_file(&file.path, &file.content, self.config.force_overwrite, self.config.update_generated)?;
         match &result {
-            WriteResult::Written => println!("{}", message),
+            WriteResult::Written => println!("{}", file.message),
+            WriteResult::Skipped if self.config.update_generated => {
+                println!("Warning: File was not generated by schemly, skipping: {}", file.path)
+            }
             WriteResult::Skipped => {
-                println!("Warning: File already exists, skipping: {}", file_path)
+                println!("Warning: File already exists, skipping: {}", file.path)
             }
-            WriteResult::Error(e) => println!("Error writing {}: {}", file_path, e),
+            WriteResult::Error(e) => println!("Error writing {}: {}", file.path, e),
         }
         Ok(result)
     }
 
-    fn generate_request(&self, model: &types::ModelDefinition) -> Result<(WriteResult, WriteResult)> {
-        let generator = request_generator::RequestGenerator;
-
-        // Store
-        let store_content = generator.generate_action(model, &self.config, "store")
-            .map_err(|e| error::GeneratorError::Template(e.to_string()))?;
-        let store_file_path = generator.get_file_path_action(model, &self.config, "store");
-        let store_result = safe_write_file(&store_file_path, &store_content, self.config.force_overwrite)?;
-
-        match &store_result {
-            WriteResult::Written => println!("Generated request: Store{}Request", model.name),
-            WriteResult::Skipped => println!("Warning: File already exists, skipping: {}", store_file_path),
-            WriteResult::Error(e) => println!("Error writing {}: {}", store_file_path, e),
-        }
-
-        // Update
-        let update_content = generator.generate_action(model, &self.config, "update")
-            .map_err(|e| error::GeneratorError::Template(e.to_string()))?;
-        let update_file_path = generator.get_file_path_action(model, &self.config, "update");
-        let update_result = safe_write_file(&update_file_path, &update_content, self.config.force_overwrite)?;
-
-        match &update_result {
-            WriteResult::Written => println!("Generated request: Update{}Request", model.name),
-            WriteResult::Skipped => println!("Warning: File already exists, skipping: {}", update_file_path),
-            WriteResult::Error(e) => println!("Error writing {}: {}", update_file_path, e),
-        }
-
-        Ok((store_result, update_result))
-    }
-
     fn update_stats(&self, stats: &mut GenerationStats, result: WriteResult) {
         match result {
             WriteResult::Written => stats.written += 1,
@@ -340,7 +550,207 @@ impl LaravelGenerator {
 }
 
 impl Config {
+    /// Parses a schema, JSON or YAML config file, or a directory of YAML files, without validating it
+    ///
+    /// Field names are sanitized here when `sanitizeFieldNames` is on.
+    fn read(file_path: &str, strict: bool) -> Result<Self> {
+        let mut config = if Path::new(file_path).is_dir() {
+            Self::read_dir(file_path, strict)?
+        } else {
+            Self::read_file(file_path, strict)?
+        };
+        config.sanitize_fields();
+        Ok(config)
+    }
+
+    fn read_file(file_path: &str, strict: bool) -> Result<Self> {
+        let content = fs::read_to_string(file_path)?;
+
+        match Self::extension(file_path).as_str() {
+            "json" => Self::from_json(&content, strict),
+            "yml" | "yaml" => Self::from_yaml(&content, strict),
+            _ => Self::from_schema(&content),
+        }
+    }
+
+    /// Merges every `*.yml` / `*.yaml` file of a directory, in name order
+    ///
+    /// Models are concatenated; generator settings come from the first file.
+    fn read_dir(dir_path: &str, strict: bool) -> Result<Self> {
+        let paths = Self::yaml_files(dir_path)?;
+
+        let mut merged: Option<(Config, String)> = None;
+        let mut sources: BTreeMap<String, String> = BTreeMap::new();
+        for path in &paths {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let mut config = Self::read_file(&path.to_string_lossy(), strict)
+                .map_err(|e| error::GeneratorError::Configuration(format!("{}: {}", file_name, e)))?;
+
+            for model in &config.models {
+                if let Some(other) = sources.insert(model.name.clone(), file_name.clone()) {
+                    return Err(error::GeneratorError::Configuration(
+                        format!("Model '{}' is defined in both {} and {}", model.name, other, file_name)
+                    ));
+                }
+            }
+
+            match &mut merged {
+                None => merged = Some((config, file_name)),
+                Some((base, first_file)) => {
+                    let models = std::mem::take(&mut config.models);
+                    if serde_json::to_value(&config).ok() != serde_json::to_value(Config { models: Vec::new(), ..base.clone() }).ok() {
+                        log::warn!("Ignoring generator settings in {}: they are taken from {}", file_name, first_file);
+                    }
+                    base.models.extend(models);
+                }
+            }
+        }
+
+        merged.map(|(config, _)| config).ok_or_else(|| error::GeneratorError::Configuration(
+            format!("No .yml or .yaml files found in {}", dir_path)
+        ))
+    }
+
+    /// Checks every JSON or YAML document behind `file_path` against the bundled JSON Schema
+    fn json_schema_errors(file_path: &str) -> Result<Vec<error::GeneratorError>> {
+        let is_dir = Path::new(file_path).is_dir();
+        let paths = if is_dir { Self::yaml_files(file_path)? } else { vec![PathBuf::from(file_path)] };
+
+        let mut errors = Vec::new();
+        for path in &paths {
+            let content = fs::read_to_string(path)?;
+            let document = match Self::extension(&path.to_string_lossy()).as_str() {
+                "json" => Self::parse_json(&content)?,
+                "yml" | "yaml" => Self::parse_yaml(&content)?,
+                _ => {
+                    return Err(error::GeneratorError::Configuration(
+                        format!("--schema checks JSON and YAML configs; {} is a schema file", file_path)
+                    ));
+                }
+            };
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            errors.extend(Validator::json_schema_errors(&document).into_iter().map(|e| if is_dir {
+                error::GeneratorError::Configuration(format!("{}: {}", file_name, e))
+            } else {
+                e
+            }));
+        }
+        Ok(errors)
+    }
+
+    /// `*.yml` / `*.yaml` files of a directory, sorted by name
+    fn yaml_files(dir_path: &str) -> Result<Vec<PathBuf>> {
+        let mut paths: Vec<_> = fs::read_dir(dir_path)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml")))
+            .collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn extension(file_path: &str) -> String {
+        Path::new(file_path)
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    }
+
+    /// Renames invalid field names when `sanitize_field_names` is on, reporting each rename
+    fn sanitize_fields(&mut self) {
+        if !self.sanitize_field_names {
+            return;
+        }
+        for model in &mut self.models {
+            for (original, sanitized) in Validator::sanitize_model_fields(model) {
+                log::warn!("Sanitized field {}.{} -> {}", model.name, original, sanitized);
+            }
+        }
+    }
+
+    fn from_schema(schema_content: &str) -> Result<Self> {
+        let schema = schema::parse_schema(schema_content)
+            .map_err(error::GeneratorError::ParseError)?;
+        schema::SchemaConverter::convert_to_config(schema)
+            .map_err(error::GeneratorError::ParseError)
+    }
+
+    fn from_json(json_content: &str, strict: bool) -> Result<Self> {
+        Self::from_value(Self::parse_json(json_content)?, strict, "JSON")
+    }
+
+    /// YAML configs use the same keys as JSON ones
+    fn from_yaml(yaml_content: &str, strict: bool) -> Result<Self> {
+        Self::from_value(Self::parse_yaml(yaml_content)?, strict, "YAML")
+    }
+
+    fn parse_json(json_content: &str) -> Result<serde_json::Value> {
+        serde_json::from_str(json_content)
+            .map_err(|e| error::GeneratorError::ParseError(format!("Invalid JSON config: {}", e)))
+    }
+
+    fn parse_yaml(yaml_content: &str) -> Result<serde_json::Value> {
+        serde_yaml::from_str(yaml_content)
+            .map_err(|e| error::GeneratorError::ParseError(format!("Invalid YAML config: {}", e)))
+    }
+
+    fn from_value(value: serde_json::Value, strict: bool, format: &str) -> Result<Self> {
+        if strict {
+            Validator::validate_known_keys(&value)?;
+        }
+        serde_json::from_value(value)
+            .map_err(|e| error::GeneratorError::ParseError(format!("Invalid {} config: {}", format, e)))
+    }
+
+    /// Collects every model, field and cross-model problem instead of stopping at the first
+    fn validation_errors(&self) -> Vec<error::GeneratorError> {
+        let mut errors = Vec::new();
+
+        if let Err(e) = Validator::validate_namespace(&self.namespace) {
+            errors.push(e);
+        }
+        if let Err(e) = Validator::validate_qualified_name(&self.base_model, "Base model") {
+            errors.push(e);
+        }
+        if let Err(e) = Validator::validate_seed_count(self.seed_count, "The config") {
+            errors.push(e);
+        }
+
+        let mut model_names = std::collections::HashSet::new();
+        let mut table_names = std::collections::HashSet::new();
+        for model in &self.models {
+            if let Err(e) = Validator::validate_model(model) {
+                errors.push(e);
+            }
+            if let Err(e) = Validator::validate_foreign_keys(model, self.auto_foreign_ids) {
+                errors.push(e);
+            }
+            if let Err(e) = Validator::validate_pivot_tables(model, &self.models) {
+                errors.push(e);
+            }
+            if !model_names.insert(&model.name) {
+                errors.push(error::GeneratorError::ModelValidation(
+                    format!("Duplicate model name '{}'", model.name),
+                ));
+            }
+            if !table_names.insert(&model.table) {
+                errors.push(error::GeneratorError::ModelValidation(
+                    format!("Table '{}' is used by more than one model", model.table),
+                ));
+            }
+        }
+        if let Err(e) = Validator::validate_migration_order(&self.models) {
+            errors.push(e);
+        }
+
+        errors
+    }
+
     fn validate(&self) -> Result<()> {
+        Validator::validate_namespace(&self.namespace)?;
+        Validator::validate_qualified_name(&self.base_model, "Base model")?;
+        Validator::validate_seed_count(self.seed_count, "The config")?;
+
         for model in &self.models {
             if model.name.is_empty() {
                 return Err(error::GeneratorError::ModelValidation(
@@ -352,13 +762,14 @@ impl Config {
                     "Table name cannot be empty".to_string(),
                 ));
             }
+            Validator::validate_foreign_keys(model, self.auto_foreign_ids)?;
+            Validator::validate_pivot_tables(model, &self.models)?;
         }
+        Validator::validate_migration_order(&self.models)?;
         Ok(())
     }
 }
 
-
-
 // Helper functions
 fn apply_component_filters(
     config: &mut Config,
@@ -375,6 +786,13 @@ fn apply_component_filters(
         config.generate_pivot_tables = false;
         config.generate_dto = false;
         config.generate_requests = false;
+        config.generate_graphql = false;
+        config.generate_repositories = false;
+        config.generate_services = false;
+        config.generate_seeders = false;
+        config.generate_routes = false;
+        config.generate_validation_rules = false;
+        config.generate_enums = false;
 
         for component in components {
             match component.to_lowercase().as_str() {
@@ -386,7 +804,14 @@ fn apply_component_filters(
                 "pivot" | "pivots" | "pivot_tables" => config.generate_pivot_tables = true,
                 "dtos" | "dto" => config.generate_dto = true,
                 "requests" | "request" => config.generate_requests = true,
-                _ => eprintln!("⚠️  Warning: Unknown component in --only '{}'", component),
+                "graphql" => config.generate_graphql = true,
+                "repositories" | "repository" => config.generate_repositories = true,
+                "services" | "service" => config.generate_services = true,
+                "seeders" | "seeder" => config.generate_seeders = true,
+                "routes" | "route" => config.generate_routes = true,
+                "rules" | "rule" => config.generate_validation_rules = true,
+                "enums" | "enum" => config.generate_enums = true,
+                _ => log::warn!("Unknown component in --only '{}'", component),
             }
         }
     } else if let Some(components) = exclude {
@@ -401,32 +826,50 @@ fn apply_component_filters(
                 "pivot" | "pivots" | "pivot_tables" => config.generate_pivot_tables = false,
                 "dtos" | "dto" => config.generate_dto = false,
                 "requests" | "request" => config.generate_requests = false,
-                _ => eprintln!("⚠️  Warning: Unknown component in --exclude '{}'", component),
+                "graphql" => config.generate_graphql = false,
+                "repositories" | "repository" => config.generate_repositories = false,
+                "services" | "service" => config.generate_services = false,
+                "seeders" | "seeder" => config.generate_seeders = false,
+                "routes" | "route" => config.generate_routes = false,
+                "rules" | "rule" => config.generate_validation_rules = false,
+                "enums" | "enum" => config.generate_enums = false,
+                _ => log::warn!("Unknown component in --exclude '{}'", component),
             }
         }
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn get_enabled_components_list(
-    models: bool,
-    controllers: bool,
-    resources: bool,
-    factories: bool,
-    migrations: bool,
-    pivot_tables: bool,
-    dtos: bool,
-    requests: bool,
-) -> Vec<String> {
+fn apply_model_filter(config: &mut Config, models: &Option<Vec<String>>) -> Result<()> {
+    if let Some(names) = models {
+        for name in names {
+            if !config.models.iter().any(|m| &m.name == name) {
+                return Err(error::GeneratorError::Configuration(
+                    format!("Model '{}' is not defined in the schema", name)
+                ));
+            }
+        }
+        config.models.retain(|m| names.contains(&m.name));
+    }
+    Ok(())
+}
+
+fn get_enabled_components_list(config: &Config) -> Vec<String> {
     let mut enabled = Vec::new();
-    if models { enabled.push("models".to_string()); }
-    if controllers { enabled.push("controllers".to_string()); }
-    if resources { enabled.push("resources".to_string()); }
-    if factories { enabled.push("factories".to_string()); }
-    if migrations { enabled.push("migrations".to_string()); }
-    if pivot_tables { enabled.push("pivot tables".to_string()); }
-    if dtos { enabled.push("DTOs".to_string()); }
-    if requests { enabled.push("requests".to_string()); }
+    if config.generate_models { enabled.push("models".to_string()); }
+    if config.generate_controllers { enabled.push("controllers".to_string()); }
+    if config.generate_resources { enabled.push("resources".to_string()); }
+    if config.generate_factories { enabled.push("factories".to_string()); }
+    if config.generate_migrations { enabled.push("migrations".to_string()); }
+    if config.generate_pivot_tables { enabled.push("pivot tables".to_string()); }
+    if config.generate_dto { enabled.push("DTOs".to_string()); }
+    if config.generate_requests { enabled.push("requests".to_string()); }
+    if config.generate_graphql { enabled.push("GraphQL schema".to_string()); }
+    if config.generate_repositories { enabled.push("repositories".to_string()); }
+    if config.generate_services { enabled.push("services".to_string()); }
+    if config.generate_seeders { enabled.push("seeders".to_string()); }
+    if config.generate_routes { enabled.push("routes".to_string()); }
+    if config.generate_validation_rules { enabled.push("validation rules".to_string()); }
+    if config.generate_enums { enabled.push("enums".to_string()); }
     enabled
 }
 
@@ -484,16 +927,17 @@ model Post {
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    logging::init(logging::level_for(cli.verbose, cli.debug));
 
     match &cli.command {
         Commands::Init { output, force } => {
             handle_init(output, *force)
         }
-        Commands::Generate { output, dry_run, force, only, exclude, ddd } => {
-            handle_generate(&cli, output, *dry_run, *force, only, exclude, *ddd)
+        Commands::Generate { output, dry_run, force, update, sanitize, only, exclude, ddd, model, stdout, diff, jobs, timings, only_changed, migration_mode, manifest, emit_sql, require_laravel, seed_count } => {
+            handle_generate(&cli, output, *dry_run, *force, *update, *sanitize, only, exclude, *ddd, model, *stdout, *diff, *jobs, *timings, *only_changed, migration_mode.as_deref(), manifest.as_deref(), emit_sql.as_deref(), *require_laravel, *seed_count)
         }
-        Commands::Watch { output, force, only, exclude } => {
-            handle_watch(&cli, output, *force, only, exclude)
+        Commands::Watch { output, dry_run, force, only, exclude } => {
+            handle_watch(&cli, output, *dry_run, *force, only, exclude)
         }
         Commands::Doctor { path } => {
             handle_doctor(path)
@@ -501,6 +945,15 @@ fn main() -> Result<()> {
         Commands::InitRules { output, force } => {
             handle_init_rules(output, *force)
         }
+        Commands::Validate { schema } => {
+            handle_validate(&get_schema_path(&cli.file), cli.strict_config, *schema)
+        }
+        Commands::List { paths, output } => {
+            handle_list(&get_schema_path(&cli.file), cli.strict_config, *paths, output)
+        }
+        Commands::Edit { output } => {
+            handle_edit(&get_schema_path(&cli.file), cli.strict_config, output)
+        }
     }
 }
 
@@ -522,87 +975,287 @@ fn handle_init(output: &str, force: bool) -> Result<()> {
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_generate(
     cli: &Cli,
     output: &str,
     dry_run: bool,
     force: bool,
+    update: bool,
+    sanitize: bool,
     only: &Option<Vec<String>>,
     exclude: &Option<Vec<String>>,
     ddd: bool,
+    models: &Option<Vec<String>>,
+    stdout: bool,
+    diff: bool,
+    jobs: usize,
+    timings: bool,
+    only_changed: bool,
+    migration_mode: Option<&str>,
+    manifest: Option<&str>,
+    emit_sql: Option<&str>,
+    require_laravel: bool,
+    seed_count: Option<u32>,
 ) -> Result<()> {
     let schema_path = get_schema_path(&cli.file);
 
-    if cli.verbose {
-        println!("📄 Reading schema from: {}", schema_path);
+    log::info!("📄 Reading schema from: {}", schema_path);
+
+    let mut generator = load_generator(&schema_path, cli.strict_config, output, force, only, exclude, ddd, models)?;
+    generator.config.update_generated = update;
+    if sanitize {
+        generator.config.sanitize_field_names = true;
+        generator.config.sanitize_fields();
+    }
+    match migration_mode {
+        Some("alter") => generator.config.migration_mode = types::MigrationMode::Alter,
+        Some(_) => generator.config.migration_mode = types::MigrationMode::Create,
+        None => {}
+    }
+    if let Some(count) = seed_count {
+        generator.config.seed_count = count;
+    }
+    generator.jobs = jobs;
+    generator.timings = timings.then(Default::default);
+    if only_changed {
+        generator.cache = Some(cache::GenerationCache::load(&generator.config));
     }
 
-    let mut generator = LaravelGenerator::from_file(&schema_path)?;
+    // Stream the single selected file and skip all filesystem writes
+    if stdout {
+        return generator.print_to_stdout();
+    }
+
+    // Review what --force would change without touching any file
+    if diff {
+        return generator.print_diff();
+    }
+
+    // Diagnostic SQL dump in place of the Laravel files
+    if let Some(path) = emit_sql {
+        return generator.write_sql(path);
+    }
+
+    check_laravel_project(output, require_laravel)?;
+    run_generation(&generator, dry_run, manifest)
+}
+
+/// Loads the schema and applies the CLI overrides shared by `generate` and `watch`
+#[allow(clippy::too_many_arguments)]
+fn load_generator(
+    schema_path: &str,
+    strict_config: bool,
+    output: &str,
+    force: bool,
+    only: &Option<Vec<String>>,
+    exclude: &Option<Vec<String>>,
+    ddd: bool,
+    models: &Option<Vec<String>>,
+) -> Result<LaravelGenerator> {
+    let mut generator = LaravelGenerator::from_file(schema_path, strict_config)?;
 
     // Apply component selection (CLI args take priority over schema config)
     apply_component_filters(&mut generator.config, only, exclude);
+    apply_model_filter(&mut generator.config, models)?;
 
     // Override config with CLI options
     generator.config.output_dir = output.to_string();
     generator.config.force_overwrite = force;
     generator.config.use_ddd_structure = ddd;
 
+    Ok(generator)
+}
+
+fn run_generation(generator: &LaravelGenerator, dry_run: bool, manifest_path: Option<&str>) -> Result<()> {
+    let config = &generator.config;
+
     // Warn user about force flag
-    if force {
+    if config.force_overwrite {
         println!("⚠️  Warning: --force flag enabled. Existing files will be overwritten!");
     }
 
     // Log which components will be generated
-    let enabled_components = get_enabled_components_list(
-        generator.config.generate_models,
-        generator.config.generate_controllers,
-        generator.config.generate_resources,
-        generator.config.generate_factories,
-        generator.config.generate_migrations,
-        generator.config.generate_pivot_tables,
-        generator.config.generate_dto,
-        generator.config.generate_requests,
-    );
+    let enabled_components = get_enabled_components_list(config);
 
     if dry_run {
         println!("🔍 Dry run mode - no files will be written\n");
         println!("Would generate: {}", enabled_components.join(", "));
-        println!("Output directory: {}", output);
-        println!("DDD structure: {}", if ddd { "enabled" } else { "disabled" });
+        println!("Output directory: {}", config.output_dir);
+        println!("DDD structure: {}", if config.use_ddd_structure { "enabled" } else { "disabled" });
         println!("\nModels to process:");
-        for model in &generator.config.models {
+        for model in &config.models {
             println!("  - {}", model.name);
         }
+        let mut paths: Vec<String> = generator.generate_all_to_map()?.into_keys().collect();
+        paths.sort();
+        println!("\nFiles:");
+        for path in paths {
+            println!("  - {}", path);
+        }
         return Ok(());
     }
 
     println!("Generating: {}", enabled_components.join(", "));
-    generator.generate_all()?;
+    let unchanged: Vec<&str> = config.models.iter()
+        .filter(|model| generator.is_unchanged(model))
+        .map(|model| model.name.as_str())
+        .collect();
+    if !unchanged.is_empty() {
+        println!("Skipping unchanged models: {}", unchanged.join(", "));
+    }
+
+    let started = std::time::Instant::now();
+    let manifest = generator.generate_all()?;
+    log::debug!("Generation took {:.2?}", started.elapsed());
+    if let Some(cache) = generator.updated_cache(&manifest) {
+        cache.save(config)?;
+    }
+    if let Some(path) = manifest_path {
+        manifest.write(path)?;
+        println!("Wrote manifest: {}", path);
+    }
     Ok(())
 }
 
-fn handle_watch(_cli: &Cli, _output: &str, _force: bool, _only: &Option<Vec<String>>, _exclude: &Option<Vec<String>>) -> Result<()> {
-    println!("⚠️  Watch mode is not yet implemented.");
-    println!("This feature will automatically regenerate code when the schema file changes.");
+fn handle_watch(
+    cli: &Cli,
+    output: &str,
+    dry_run: bool,
+    force: bool,
+    only: &Option<Vec<String>>,
+    exclude: &Option<Vec<String>>,
+) -> Result<()> {
+    let schema_path = get_schema_path(&cli.file);
+
+    let running = Arc::new(AtomicBool::new(true));
+    let handler_flag = Arc::clone(&running);
+    ctrlc::set_handler(move || handler_flag.store(false, Ordering::SeqCst))
+        .map_err(|e| error::GeneratorError::Configuration(format!("Failed to install Ctrl-C handler: {}", e)))?;
+
+    println!("👀 Watching {} for changes (press Ctrl-C to stop)", schema_path);
+
+    let mut watcher = SchemaWatcher::new(&schema_path, Duration::from_millis(WATCH_DEBOUNCE_MS));
+    let cycles = watch_loop(
+        || {
+            while running.load(Ordering::SeqCst) {
+                if watcher.poll() {
+                    return true;
+                }
+                thread::sleep(Duration::from_millis(WATCH_POLL_INTERVAL_MS));
+            }
+            false
+        },
+        |cycle| {
+            println!("\n━━━ Generation #{} ({}) ━━━", cycle, chrono::Local::now().format("%H:%M:%S"));
+            load_generator(&schema_path, cli.strict_config, output, force, only, exclude, false, &None)
+                .and_then(|generator| run_generation(&generator, dry_run, None))
+        },
+    );
+
+    println!("\n👋 Stopped watching after {} generation pass(es)", cycles);
     Ok(())
 }
 
-fn handle_doctor(path: &str) -> Result<()> {
-    println!("🔍 Checking Laravel project at: {}\n", path);
+const WATCH_POLL_INTERVAL_MS: u64 = 250;
+const WATCH_DEBOUNCE_MS: u64 = 300;
+
+/// Runs an initial generation pass, then one more each time `wait_for_change`
+/// reports a change. `wait_for_change` returns `false` once watching should stop.
+/// Failed passes are reported and watching continues. Returns the number of passes.
+fn watch_loop<W, G>(mut wait_for_change: W, mut generate: G) -> usize
+where
+    W: FnMut() -> bool,
+    G: FnMut(usize) -> Result<()>,
+{
+    let mut cycles = 0;
+
+    loop {
+        cycles += 1;
+        if let Err(e) = generate(cycles) {
+            eprintln!("❌ {}", e);
+        }
 
-    let path_obj = Path::new(path);
+        if !wait_for_change() {
+            return cycles;
+        }
+    }
+}
 
-    // Check if it's a Laravel project
-    let composer_json = path_obj.join("composer.json");
-    let artisan = path_obj.join("artisan");
+/// Polls a file's modification time to detect saves
+struct SchemaWatcher {
+    path: String,
+    last_modified: Option<SystemTime>,
+    debounce: Duration,
+}
+
+impl SchemaWatcher {
+    fn new(path: &str, debounce: Duration) -> Self {
+        Self {
+            path: path.to_string(),
+            last_modified: Self::modified(path),
+            debounce,
+        }
+    }
+
+    /// Returns true if the file changed since the last poll. Waits until the
+    /// modification time settles so a burst of saves triggers a single pass.
+    fn poll(&mut self) -> bool {
+        let current = Self::modified(&self.path);
+        if current == self.last_modified {
+            return false;
+        }
 
-    if !composer_json.exists() {
-        println!("❌ composer.json not found");
+        let mut settled = current;
+        loop {
+            thread::sleep(self.debounce);
+            let latest = Self::modified(&self.path);
+            if latest == settled {
+                break;
+            }
+            settled = latest;
+        }
+
+        self.last_modified = settled;
+        true
+    }
+
+    fn modified(path: &str) -> Option<SystemTime> {
+        fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+}
+
+/// Files every Laravel project root has, missing from `path`
+fn missing_laravel_files(path: &str) -> Vec<&'static str> {
+    ["composer.json", "artisan"].into_iter()
+        .filter(|file| !Path::new(path).join(file).exists())
+        .collect()
+}
+
+/// Preflight for `generate`: warns, or fails under `--require-laravel`, when the
+/// output directory isn't a Laravel project root so files aren't scattered elsewhere
+fn check_laravel_project(output: &str, require_laravel: bool) -> Result<()> {
+    let missing = missing_laravel_files(output);
+    if missing.is_empty() {
         return Ok(());
     }
 
-    if !artisan.exists() {
-        println!("❌ artisan file not found");
+    let message = format!("{} doesn't look like a Laravel project (no {})", output, missing.join(" or "));
+    if require_laravel {
+        return Err(error::GeneratorError::Configuration(message));
+    }
+    println!("⚠️  Warning: {}; run `schemly doctor --path {}` to check it", message, output);
+    Ok(())
+}
+
+fn handle_doctor(path: &str) -> Result<()> {
+    println!("🔍 Checking Laravel project at: {}\n", path);
+
+    let path_obj = Path::new(path);
+
+    // Check if it's a Laravel project
+    if let Some(missing) = missing_laravel_files(path).first() {
+        println!("❌ {} not found", missing);
         return Ok(());
     }
 
@@ -625,6 +1278,76 @@ fn handle_doctor(path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Reports every validation problem; writes no files and creates no directories
+fn handle_validate(schema_path: &str, strict_config: bool, json_schema: bool) -> Result<()> {
+    // Serde stops at the first mismatch; the JSON Schema check lists every one with its path
+    if json_schema {
+        let errors = Config::json_schema_errors(schema_path)?;
+        if !errors.is_empty() {
+            return report_problems(schema_path, &errors);
+        }
+    }
+
+    let config = Config::read(schema_path, strict_config)?;
+    let errors = config.validation_errors();
+
+    if errors.is_empty() {
+        println!("✓ {} is valid ({} models)", schema_path, config.models.len());
+        return Ok(());
+    }
+
+    report_problems(schema_path, &errors)
+}
+
+fn report_problems(schema_path: &str, errors: &[error::GeneratorError]) -> Result<()> {
+    eprintln!("✗ {} has {} problem(s):", schema_path, errors.len());
+    for error in errors {
+        eprintln!("  - {}", error);
+    }
+
+    Err(error::GeneratorError::Configuration(format!(
+        "{} validation problem(s) in {}", errors.len(), schema_path
+    )))
+}
+
+fn handle_list(schema_path: &str, strict_config: bool, paths: bool, output: &str) -> Result<()> {
+    let mut generator = LaravelGenerator::from_file(schema_path, strict_config)?;
+    generator.config.output_dir = output.to_string();
+    print!("{}", generator.listing(paths)?);
+    Ok(())
+}
+
+fn handle_edit(schema_path: &str, strict_config: bool, output: &str) -> Result<()> {
+    // Checked up front so a bad path doesn't throw away the answers
+    let yaml = match Path::new(output).extension().and_then(|ext| ext.to_str()) {
+        Some("json") => false,
+        Some("yml" | "yaml") => true,
+        _ => {
+            return Err(error::GeneratorError::Configuration(
+                format!("Cannot save to '{}': use a .json, .yml or .yaml file", output)
+            ));
+        }
+    };
+
+    let mut config = Config::read(schema_path, strict_config)?;
+    interactive::InteractiveMode::new(std::io::stdin().lock(), std::io::stdout()).edit_models(&mut config)?;
+    config.validate()?;
+
+    let content = if yaml {
+        // Through JSON so enums are written as plain maps rather than YAML tags, which `read` rejects
+        serde_json::to_value(&config)
+            .map_err(|e| e.to_string())
+            .and_then(|value| serde_yaml::to_string(&value).map_err(|e| e.to_string()))
+    } else {
+        serde_json::to_string_pretty(&config).map_err(|e| e.to_string())
+    };
+    let content = content
+        .map_err(|e| error::GeneratorError::Configuration(format!("Failed to serialize config: {}", e)))?;
+    fs::write(output, content)?;
+    println!("✓ Saved edited config to {}", output);
+    Ok(())
+}
+
 fn handle_init_rules(output: &str, force: bool) -> Result<()> {
     let out_path = Path::new(output);
 
@@ -651,3 +1374,409 @@ fn handle_init_rules(output: &str, force: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_watch_loop_regenerates_on_change() {
+        let signals = RefCell::new(vec![true, false].into_iter());
+        let mut passes = Vec::new();
+
+        let cycles = watch_loop(
+            || signals.borrow_mut().next().unwrap_or(false),
+            |cycle| {
+                passes.push(cycle);
+                Ok(())
+            },
+        );
+
+        assert_eq!(cycles, 2);
+        assert_eq!(passes, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_watch_loop_continues_after_failed_pass() {
+        let mut signals = vec![true, false].into_iter();
+        let mut passes = 0;
+
+        let cycles = watch_loop(
+            || signals.next().unwrap_or(false),
+            |_| {
+                passes += 1;
+                Err(error::GeneratorError::ParseError("broken schema".to_string()))
+            },
+        );
+
+        assert_eq!(cycles, 2);
+        assert_eq!(passes, 2);
+    }
+
+    #[test]
+    fn test_schema_watcher_detects_modification() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema_path = temp_dir.path().join("schema.schemly");
+        fs::write(&schema_path, "model User {}").unwrap();
+
+        let mut watcher = SchemaWatcher::new(schema_path.to_str().unwrap(), Duration::ZERO);
+        assert!(!watcher.poll());
+
+        let file = fs::File::options().write(true).open(&schema_path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+    }
+
+    #[test]
+    fn test_json_and_schema_files_produce_same_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema_path = temp_dir.path().join("schema.schemly");
+        fs::write(&schema_path, r#"
+model User {
+  id    Int    @id @default(autoincrement())
+  name  String @db.VarChar(255)
+  email String @unique
+
+  @@map("users")
+}
+"#).unwrap();
+
+        let from_schema = LaravelGenerator::from_file(schema_path.to_str().unwrap(), false).unwrap();
+
+        let json_path = temp_dir.path().join("schema.json");
+        fs::write(&json_path, serde_json::to_string_pretty(&from_schema.config).unwrap()).unwrap();
+        let from_json = LaravelGenerator::from_file(json_path.to_str().unwrap(), false).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&from_schema.config).unwrap(),
+            serde_json::to_value(&from_json.config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json_config_defaults_omitted_keys() {
+        let config = Config::from_json(r#"{
+            "models": [{ "name": "User", "table": "users", "fields": [{ "name": "name", "type": "string" }] }],
+            "generate_dto": true
+        }"#, false).unwrap();
+
+        assert_eq!(config.namespace, "App\\Models");
+        assert!(config.generate_models);
+        assert!(config.generate_dto);
+        assert_eq!(config.models[0].name, "User");
+
+        assert!(Config::from_json("{ \"models\": ", false).is_err());
+
+        let config = Config::from_json(r#"{
+            "models": [{ "name": "User", "table": "users", "fields": [
+                { "name": "nickname", "type": "string", "nullable": true, "default": null },
+                { "name": "bio", "type": "string", "nullable": true }
+            ] }]
+        }"#, false).unwrap();
+        assert!(config.models[0].fields[0].has_null_default());
+        assert_eq!(serde_json::to_value(&config.models[0].fields[0]).unwrap()["default"], serde_json::Value::Null);
+        assert_eq!(config.models[0].fields[1].default, None);
+    }
+
+    #[test]
+    fn test_yaml_directory_merges_models() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("post.yml"), "
+generate_dto: true
+models:
+  - name: Post
+    table: posts
+    fields:
+      - { name: title, type: string }
+").unwrap();
+        fs::write(temp_dir.path().join("user.yaml"), "
+models:
+  - name: User
+    table: users
+    fields:
+      - { name: name, type: string }
+").unwrap();
+        fs::write(temp_dir.path().join("notes.txt"), "not a config").unwrap();
+
+        let config = Config::read(temp_dir.path().to_str().unwrap(), true).unwrap();
+        let names: Vec<&str> = config.models.iter().map(|model| model.name.as_str()).collect();
+        assert_eq!(names, ["Post", "User"]);
+        assert!(config.generate_dto);
+
+        // A single YAML file still loads on its own
+        let config = Config::read(temp_dir.path().join("user.yaml").to_str().unwrap(), true).unwrap();
+        assert_eq!(config.models[0].name, "User");
+    }
+
+    #[test]
+    fn test_yaml_directory_rejects_duplicate_models() {
+        let temp_dir = TempDir::new().unwrap();
+        let user = "
+models:
+  - name: User
+    table: users
+    fields:
+      - { name: name, type: string }
+";
+        fs::write(temp_dir.path().join("a.yml"), user).unwrap();
+        fs::write(temp_dir.path().join("b.yml"), user).unwrap();
+
+        let error = Config::read(temp_dir.path().to_str().unwrap(), false).unwrap_err().to_string();
+        assert!(error.contains("Model 'User' is defined in both a.yml and b.yml"), "{}", error);
+    }
+
+    #[test]
+    fn test_default_schema_round_trips() {
+        let config = Config::from_schema(create_default_schema()).unwrap();
+        assert!(config.validate().is_ok());
+
+        let names: Vec<&str> = config.models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["User", "Post"]);
+        for model in &config.models {
+            assert!(Validator::validate_model(model).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_init_refuses_to_overwrite_without_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema_path = temp_dir.path().join("schema.schemly");
+        let schema_path = schema_path.to_str().unwrap();
+
+        handle_init(schema_path, false).unwrap();
+        fs::write(schema_path, "// edited").unwrap();
+
+        assert!(handle_init(schema_path, false).is_err());
+        assert_eq!(fs::read_to_string(schema_path).unwrap(), "// edited");
+
+        handle_init(schema_path, true).unwrap();
+        assert_eq!(fs::read_to_string(schema_path).unwrap(), create_default_schema());
+    }
+
+    #[test]
+    fn test_laravel_preflight_warns_or_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().to_str().unwrap();
+
+        assert_eq!(missing_laravel_files(output), ["composer.json", "artisan"]);
+        assert!(check_laravel_project(output, false).is_ok());
+        let error = check_laravel_project(output, true).unwrap_err().to_string();
+        assert!(error.contains("doesn't look like a Laravel project (no composer.json or artisan)"));
+
+        fs::write(temp_dir.path().join("composer.json"), "{}").unwrap();
+        fs::write(temp_dir.path().join("artisan"), "").unwrap();
+        assert!(missing_laravel_files(output).is_empty());
+        assert!(check_laravel_project(output, true).is_ok());
+    }
+
+    #[test]
+    fn test_update_overwrites_only_marked_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let marked = temp_dir.path().join("User.php");
+        let unmarked = temp_dir.path().join("Post.php");
+        let marked = marked.to_str().unwrap();
+        let unmarked = unmarked.to_str().unwrap();
+        let fresh = format!("{}class Fresh {{}}\n", generators::shared::GeneratedMarker::php_header(&Config::default()));
+
+        fs::write(marked, format!("{}class User {{}}\n", generators::shared::GeneratedMarker::php_header(&Config::default()))).unwrap();
+        fs::write(unmarked, "<?php\n\nclass Post {}\n").unwrap();
+
+        assert!(matches!(safe_write_file(marked, &fresh, false, true).unwrap(), WriteResult::Written));
+        assert!(matches!(safe_write_file(unmarked, &fresh, false, true).unwrap(), WriteResult::Skipped));
+        assert_eq!(fs::read_to_string(marked).unwrap(), fresh);
+        assert_eq!(fs::read_to_string(unmarked).unwrap(), "<?php\n\nclass Post {}\n");
+
+        // Without --update even marked files are left alone
+        assert!(matches!(safe_write_file(marked, "changed", false, false).unwrap(), WriteResult::Skipped));
+    }
+
+    #[test]
+    fn test_validate_reports_invalid_model_without_side_effects() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema_path = temp_dir.path().join("schema.json");
+        fs::write(&schema_path, r#"{
+            "models": [
+                { "name": "User", "table": "users", "fields": [{ "name": "name", "type": "string" }] },
+                { "name": "Post", "table": "posts", "timestamps": false, "fields": [] }
+            ]
+        }"#).unwrap();
+
+        let result = handle_validate(schema_path.to_str().unwrap(), false, false);
+
+        assert!(result.unwrap_err().to_string().contains("1 validation problem(s)"));
+        let entries: Vec<_> = fs::read_dir(temp_dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_manifest_groups_files_by_model() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema_path = temp_dir.path().join("schema.json");
+        fs::write(&schema_path, r#"{
+            "generate_controllers": false,
+            "generate_resources": false,
+            "generate_factories": false,
+            "generate_routes": false,
+            "models": [{ "name": "User", "table": "users", "timestamps": true, "fields": [{ "name": "name", "type": "string" }] }]
+        }"#).unwrap();
+        let output = temp_dir.path().join("app");
+        let manifest_path = temp_dir.path().join("manifest.json");
+
+        let generator = load_generator(
+            schema_path.to_str().unwrap(), false, output.to_str().unwrap(), false, &None, &None, false, &None
+        ).unwrap();
+        run_generation(&generator, false, Some(manifest_path.to_str().unwrap())).unwrap();
+
+        let manifest: Manifest = serde_json::from_str(&fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        let files = &manifest.models["User"];
+        let components: Vec<&str> = files.iter().map(|entry| entry.component.as_str()).collect();
+        assert_eq!(components, ["models", "migrations"]);
+        assert!(Path::new(&files[0].path).ends_with("app/Models/User.php"));
+        assert!(files.iter().all(|entry| entry.result == "written" && entry.error.is_none()));
+        assert!(manifest.shared.is_empty());
+    }
+
+    #[test]
+    fn test_only_changed_skips_unchanged_models() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema_path = temp_dir.path().join("schema.json");
+        let schema = r#"{
+            "generate_controllers": false,
+            "generate_routes": false,
+            "models": [
+                { "name": "User", "table": "users", "fields": [{ "name": "name", "type": "string" }] },
+                { "name": "Post", "table": "posts", "fields": [{ "name": "title", "type": "string" }] }
+            ]
+        }"#;
+        fs::write(&schema_path, schema).unwrap();
+        let output = temp_dir.path().join("app");
+        let manifest_path = temp_dir.path().join("manifest.json");
+
+        let run = || {
+            let mut generator = load_generator(
+                schema_path.to_str().unwrap(), false, output.to_str().unwrap(), false, &None, &None, false, &None
+            ).unwrap();
+            generator.cache = Some(cache::GenerationCache::load(&generator.config));
+            run_generation(&generator, false, Some(manifest_path.to_str().unwrap())).unwrap();
+            let manifest: Manifest = serde_json::from_str(&fs::read_to_string(&manifest_path).unwrap()).unwrap();
+            manifest.models.into_keys().collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(), ["Post", "User"]);
+        assert!(output.join(cache::CACHE_FILE).exists());
+        assert!(run().is_empty());
+
+        fs::write(&schema_path, schema.replace(r#""name": "title""#, r#""name": "headline""#)).unwrap();
+        assert_eq!(run(), ["Post"]);
+        assert!(run().is_empty());
+    }
+
+    #[test]
+    fn test_sanitized_field_name_used_in_every_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("schema.json");
+        fs::write(&config_path, r#"{
+            "generate_dto": true,
+            "sanitize_field_names": true,
+            "models": [{
+                "name": "User", "table": "users",
+                "fields": [{ "name": "invalid-name", "type": "string" }],
+                "fillable_guarded": { "Fillable": ["invalid-name"] }
+            }]
+        }"#).unwrap();
+        let config = Config::read(config_path.to_str().unwrap(), false).unwrap();
+        assert_eq!(config.models[0].fields[0].name, "invalid_name");
+
+        let files = LaravelGenerator { config, jobs: 1, timings: None, cache: None }.render_files().unwrap();
+        for component in ["models", "migrations", "resources", "dtos"] {
+            let file = files.iter().find(|file| file.component == component).unwrap();
+            assert!(file.content.contains("invalid_name"), "{} lacks the sanitized name", component);
+            assert!(!file.content.contains("invalid-name"), "{} kept the original name", component);
+        }
+    }
+
+    #[test]
+    fn test_timings_collected_for_each_enabled_component() {
+        let config = Config::from_json(r#"{
+            "generate_dto": true,
+            "generate_services": true,
+            "generate_routes": true,
+            "models": [{ "name": "Post", "table": "posts", "fields": [{ "name": "title", "type": "string" }] }]
+        }"#, false).unwrap();
+        let generator = LaravelGenerator { config, jobs: 1, timings: Some(Default::default()), cache: None };
+        let files = generator.render_files().unwrap();
+
+        let timings = generator.timings.unwrap().into_inner().unwrap();
+        for file in &files {
+            assert!(timings.contains_key(file.component), "no timing for {}", file.component);
+        }
+        assert!(timings.contains_key("dtos") && timings.contains_key("services") && timings.contains_key("routes"));
+    }
+
+    #[test]
+    fn test_listing_shows_models_and_field_counts() {
+        let config = Config::from_json(r#"{
+            "output_dir": "/app",
+            "generate_controllers": false,
+            "models": [
+                { "name": "User", "table": "users", "fields": [
+                    { "name": "name", "type": "string" }, { "name": "email", "type": "string" }
+                ] },
+                { "name": "Post", "table": "posts", "fields": [{ "name": "user_id", "type": "bigInteger" }],
+                  "relationships": [{ "type": "belongsTo", "model": "User", "foreign_key": "user_id" }] }
+            ]
+        }"#, false).unwrap();
+        let generator = LaravelGenerator { config, jobs: 1, timings: None, cache: None };
+
+        let listing = generator.listing(false).unwrap();
+        assert!(listing.starts_with("Components: models, resources, factories, migrations"));
+        assert!(listing.contains("\nUser: 2 fields, no relationships\n"));
+        assert!(listing.contains("\nPost: 1 field, 1 relationship: belongsTo User\n"));
+        assert!(!listing.contains("table:"));
+
+        let listing = generator.listing(true).unwrap();
+        assert!(listing.contains("Post: 1 field, 1 relationship: belongsTo User\n  table: posts\n  models: "));
+        let post_path = listing.lines().find_map(|line| line.strip_prefix("  models: ").filter(|path| path.ends_with("Post.php"))).unwrap();
+        assert!(Path::new(post_path).ends_with("app/Models/Post.php"));
+        assert!(!listing.contains("controllers:"));
+    }
+
+    #[test]
+    fn test_model_namespace_shared_by_every_component() {
+        let config = Config::from_json(r#"{
+            "generate_dto": true,
+            "generate_requests": true,
+            "generate_services": true,
+            "generate_routes": true,
+            "models": [
+                { "name": "Invoice", "table": "invoices", "module": "App\\Billing", "fields": [{ "name": "total", "type": "integer" }] },
+                { "name": "Post", "table": "posts", "fields": [{ "name": "title", "type": "string" }] }
+            ]
+        }"#, false).unwrap();
+        let files = LaravelGenerator { config, jobs: 1, timings: None, cache: None }.render_files().unwrap();
+        let file = |suffix: &str| files.iter().find(|file| Path::new(&file.path).ends_with(suffix)).unwrap_or_else(|| panic!("no {}", suffix));
+
+        let model = file("app/Billing/Models/Invoice.php");
+        assert!(model.content.contains("namespace App\\Billing\\Models;"));
+        assert!(model.content.contains("use App\\Billing\\Factories\\InvoiceFactory;"));
+        assert!(file("app/Billing/Factories/InvoiceFactory.php").content.contains("namespace App\\Billing\\Factories;"));
+        assert!(file("app/Billing/Resources/InvoiceResource.php").content.contains("namespace App\\Billing\\Resources;"));
+        assert!(file("app/Billing/DTOs/InvoiceDTO.php").content.contains("use App\\Billing\\Models\\Invoice;"));
+        assert!(file("app/Billing/Services/InvoiceService.php").content.contains("use App\\Billing\\Models\\Invoice;"));
+
+        let controller = &file("app/Billing/Controllers/InvoiceController.php").content;
+        assert!(controller.contains("use App\\Http\\Controllers\\Controller;"));
+        for import in ["Models\\Invoice", "Resources\\InvoiceResource", "Requests\\StoreInvoiceRequest"] {
+            assert!(controller.contains(&format!("use App\\Billing\\{};", import)), "controller lacks {}", import);
+        }
+        assert!(file("routes/api_generated.php").content.contains("use App\\Billing\\Controllers\\InvoiceController;"));
+
+        // Other models keep the default layout
+        assert!(file("app/Models/Post.php").content.contains("namespace App\\Models;"));
+        assert!(!file("app/Http/Controllers/PostController.php").content.contains("use App\\Http\\Controllers\\Controller;"));
+    }
+}
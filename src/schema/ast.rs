@@ -197,30 +197,139 @@ impl Model {
         Vec::new()
     }
 
+    /// Extract eager-loaded relationships from @@with(["author", "tags"])
+    pub fn get_with(&self) -> Vec<String> {
+        if let Some(with_attr) = self.get_attribute("with")
+            && let Some(AttributeArg::Positional(Value::Array(relations))) = with_attr.args.first()
+        {
+            return relations.iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    Value::FieldReference(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Extract relationships whose parent timestamps are touched from @@touches(["post"])
+    pub fn get_touches(&self) -> Vec<String> {
+        if let Some(touches_attr) = self.get_attribute("touches")
+            && let Some(AttributeArg::Positional(Value::Array(relations))) = touches_attr.args.first()
+        {
+            return relations.iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    Value::FieldReference(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Extract implemented interfaces from @@implements(["HasMedia"])
+    pub fn get_implements(&self) -> Vec<String> {
+        if let Some(implements_attr) = self.get_attribute("implements")
+            && let Some(AttributeArg::Positional(Value::Array(interfaces))) = implements_attr.args.first()
+        {
+            return interfaces.iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Extract appended attributes from @@appends(["full_name"])
+    pub fn get_appends(&self) -> Vec<String> {
+        if let Some(appends_attr) = self.get_attribute("appends")
+            && let Some(AttributeArg::Positional(Value::Array(attributes))) = appends_attr.args.first()
+        {
+            return attributes.iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Extract query scope names from @@scopes(["active", "published"])
+    pub fn get_scopes(&self) -> Vec<String> {
+        if let Some(scopes_attr) = self.get_attribute("scopes")
+            && let Some(AttributeArg::Positional(Value::Array(scopes))) = scopes_attr.args.first()
+        {
+            return scopes.iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Extract the date storage format from @@dateFormat("Y-m-d H:i:s")
+    pub fn get_date_format(&self) -> Option<String> {
+        if let Some(format_attr) = self.get_attribute("dateFormat")
+            && let Some(AttributeArg::Positional(Value::String(format))) = format_attr.args.first()
+        {
+            return Some(format.clone());
+        }
+        None
+    }
+
+    /// Read a flag attribute: `@@timestamps` turns it on, `@@timestamps(false)` off
+    pub fn get_flag(&self, attribute: &str) -> Option<bool> {
+        let flag_attr = self.get_attribute(attribute)?;
+        match flag_attr.args.first() {
+            Some(AttributeArg::Positional(Value::Boolean(enabled))) => Some(*enabled),
+            _ => Some(true),
+        }
+    }
+
+    /// Extract a class name override such as @@dtoName("UserData")
+    /// Extract the seeder row count from @@seedCount(25)
+    pub fn get_seed_count(&self) -> Option<i64> {
+        match self.get_attribute("seedCount")?.args.first()? {
+            AttributeArg::Positional(Value::Integer(count)) => Some(*count),
+            _ => None,
+        }
+    }
+
+    pub fn get_class_name_override(&self, attribute: &str) -> Option<String> {
+        if let Some(name_attr) = self.get_attribute(attribute)
+            && let Some(AttributeArg::Positional(Value::String(name))) = name_attr.args.first()
+        {
+            return Some(name.clone());
+        }
+        None
+    }
+
     /// Extract fields from the @@index block attributes: e.g., @@index([field1, field2])
     pub fn get_indexes(&self) -> Vec<Vec<String>> {
-        self.attributes.iter()
-            .filter(|a| a.name == "index")
-            .filter_map(|attr| {
-                if let Some(AttributeArg::Positional(Value::Array(fields))) = attr.args.first() {
-                    let extracted: Vec<String> = fields.iter().filter_map(|v| match v {
-                        Value::String(s) => Some(s.clone()),
-                        Value::FieldReference(s) => Some(s.clone()),
-                        _ => None,
-                    }).collect();
-                    if !extracted.is_empty() {
-                        return Some(extracted);
-                    }
-                }
-                None
-            })
-            .collect()
+        self.get_field_lists("index")
     }
 
     /// Extract fields from the @@unique block attributes: e.g., @@unique([field1, field2])
     pub fn get_compound_uniques(&self) -> Vec<Vec<String>> {
+        self.get_field_lists("unique")
+    }
+
+    /// Extract fields from the @@fulltext block attributes: e.g., @@fulltext([title, body])
+    pub fn get_fulltext_indexes(&self) -> Vec<Vec<String>> {
+        self.get_field_lists("fulltext")
+    }
+
+    /// Field lists of every block attribute with the given name
+    fn get_field_lists(&self, attribute: &str) -> Vec<Vec<String>> {
         self.attributes.iter()
-            .filter(|a| a.name == "unique")
+            .filter(|a| a.name == attribute)
             .filter_map(|attr| {
                 if let Some(AttributeArg::Positional(Value::Array(fields))) = attr.args.first() {
                     let extracted: Vec<String> = fields.iter().filter_map(|v| match v {
@@ -316,6 +425,42 @@ impl Field {
         None
     }
 
+    /// Extract the date cast format from @castFormat("Y-m-d")
+    /// JSON cast variant from @jsonCastAs("collection")
+    pub fn get_json_cast_as(&self) -> Option<String> {
+        match self.get_attribute("jsonCastAs")?.args.first()? {
+            AttributeArg::Positional(Value::String(cast) | Value::FieldReference(cast)) => Some(cast.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_cast_format(&self) -> Option<String> {
+        if let Some(format_attr) = self.get_attribute("castFormat")
+            && let Some(AttributeArg::Positional(Value::String(format))) = format_attr.args.first()
+        {
+            return Some(format.clone());
+        }
+        None
+    }
+
+    /// Extract the raw JSON key list from @jsonSchema("street: string, zip: ?int")
+    pub fn get_json_schema(&self) -> Option<String> {
+        if let Some(schema_attr) = self.get_attribute("jsonSchema")
+            && let Some(AttributeArg::Positional(Value::String(schema))) = schema_attr.args.first()
+        {
+            return Some(schema.clone());
+        }
+        None
+    }
+
+    /// Column named by @after(email) or @after("email")
+    pub fn get_after(&self) -> Option<String> {
+        match self.get_attribute("after")?.args.first()? {
+            AttributeArg::Positional(Value::FieldReference(column) | Value::String(column)) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
     pub fn get_map_name(&self) -> String {
         if let Some(map_attr) = self.get_attribute("map") {
             if let Some(AttributeArg::Positional(Value::String(column_name))) = map_attr.args.first() {
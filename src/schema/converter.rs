@@ -1,5 +1,5 @@
 use crate::schema::ast;
-use crate::types::{Config, ModelDefinition, Field, FieldType, FillableGuarded, ValidationRule};
+use crate::types::{AccessorStyle, CastsStyle, ColumnDefault, Config, DatetimeCast, JsonCast, JsonKey, MigrationMode, RouteCase, RouteStyle, ModelDefinition, Field, FieldType, FillableGuarded, ValidationRule};
 
 /// Converts schema AST to internal types used by generators
 pub struct SchemaConverter;
@@ -7,6 +7,131 @@ pub struct SchemaConverter;
 impl SchemaConverter {
     pub fn convert_to_config(schema: ast::Schema) -> Result<Config, String> {
         let mut config = Config::default();
+
+        // Apply generator block settings
+        for generator in &schema.generators {
+            if let Some(ast::Value::String(style)) = generator.properties.get("accessorStyle") {
+                config.accessor_style = match style.as_str() {
+                    "attribute" => AccessorStyle::Attribute,
+                    "legacy" => AccessorStyle::Legacy,
+                    other => return Err(format!(
+                        "Unknown accessorStyle '{}' (expected \"attribute\" or \"legacy\")", other
+                    )),
+                };
+            }
+            if let Some(ast::Value::String(style)) = generator.properties.get("castsStyle") {
+                config.casts_style = match style.as_str() {
+                    "property" => CastsStyle::Property,
+                    "method" => CastsStyle::Method,
+                    other => return Err(format!(
+                        "Unknown castsStyle '{}' (expected \"property\" or \"method\")", other
+                    )),
+                };
+            }
+            if let Some(ast::Value::String(case)) = generator.properties.get("routeCase") {
+                config.route_case = match case.as_str() {
+                    "kebab" => RouteCase::Kebab,
+                    "snake" => RouteCase::Snake,
+                    other => return Err(format!(
+                        "Unknown routeCase '{}' (expected \"kebab\" or \"snake\")", other
+                    )),
+                };
+            }
+            if let Some(ast::Value::String(style)) = generator.properties.get("routeStyle") {
+                config.route_style = match style.as_str() {
+                    "apiResource" => RouteStyle::ApiResource,
+                    "explicit" => RouteStyle::Explicit,
+                    other => return Err(format!(
+                        "Unknown routeStyle '{}' (expected \"apiResource\" or \"explicit\")", other
+                    )),
+                };
+            }
+            if let Some(ast::Value::String(mode)) = generator.properties.get("migrationMode") {
+                config.migration_mode = match mode.as_str() {
+                    "create" => MigrationMode::Create,
+                    "alter" => MigrationMode::Alter,
+                    other => return Err(format!(
+                        "Unknown migrationMode '{}' (expected \"create\" or \"alter\")", other
+                    )),
+                };
+            }
+            if let Some(ast::Value::String(cast)) = generator.properties.get("datetimeCast") {
+                config.datetime_cast = match cast.as_str() {
+                    "datetime" => DatetimeCast::Datetime,
+                    "immutable_datetime" => DatetimeCast::ImmutableDatetime,
+                    other => return Err(format!(
+                        "Unknown datetimeCast '{}' (expected \"datetime\" or \"immutable_datetime\")", other
+                    )),
+                };
+            }
+            if let Some(ast::Value::String(engine)) = generator.properties.get("databaseEngine") {
+                config.database_engine = engine.to_lowercase();
+            }
+            if let Some(ast::Value::String(engine)) = generator.properties.get("tableEngine") {
+                config.table_engine = Some(engine.clone());
+            }
+            if let Some(ast::Value::String(charset)) = generator.properties.get("charset") {
+                config.charset = Some(charset.clone());
+            }
+            if let Some(ast::Value::String(collation)) = generator.properties.get("collation") {
+                config.collation = Some(collation.clone());
+            }
+            if let Some(ast::Value::String(prefix)) = generator.properties.get("tablePrefix") {
+                config.table_prefix = prefix.clone();
+            }
+            if let Some(ast::Value::String(suffix)) = generator.properties.get("tableSuffix") {
+                config.table_suffix = suffix.clone();
+            }
+            if let Some(ast::Value::String(base_model)) = generator.properties.get("baseModel") {
+                config.base_model = base_model.clone();
+            }
+            if let Some(ast::Value::Integer(count)) = generator.properties.get("seedCount") {
+                config.seed_count = Self::seed_count(*count)
+                    .ok_or_else(|| format!("seedCount must be a positive number, got {}", count))?;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("dtoResponses") {
+                config.dto_responses = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("dtoArrayable") {
+                config.dto_arrayable = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("dtoJsonSerializable") {
+                config.dto_json_serializable = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("autoForeignIds") {
+                config.auto_foreign_ids = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("autoIndexForeignKeys") {
+                config.auto_index_foreign_keys = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("namedIndexes") {
+                config.named_indexes = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("hasUuids") {
+                config.has_uuids = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("defaultTimestamps") {
+                config.default_timestamps = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("defaultSoftDeletes") {
+                config.default_soft_deletes = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("resourceIsoTimestamps") {
+                config.resource_iso_timestamps = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("resourceIncludeDeletedAt") {
+                config.resource_include_deleted_at = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("includeComments") {
+                config.include_comments = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("sanitizeFieldNames") {
+                config.sanitize_field_names = *enabled;
+            }
+            if let Some(ast::Value::Boolean(enabled)) = generator.properties.get("unsignedMinRule") {
+                config.unsigned_min_rule = *enabled;
+            }
+        }
         
         // Convert models
         for ast_model in schema.models {
@@ -21,8 +146,9 @@ impl SchemaConverter {
         let mut model = ModelDefinition {
             name: ast_model.name.clone(),
             table: ast_model.get_table_name(),
-            timestamps: ast_model.has_timestamps(),
-            soft_deletes: ast_model.has_soft_deletes(),
+            // @@timestamps / @@softDeletes or the matching columns set the flags; otherwise the config default applies
+            timestamps: ast_model.get_flag("timestamps").or(ast_model.has_timestamps().then_some(true)),
+            soft_deletes: ast_model.get_flag("softDeletes").or(ast_model.has_soft_deletes().then_some(true)),
             fields: Vec::new(),
             relationships: Vec::new(),
             pivot_tables: Vec::new(),
@@ -31,6 +157,18 @@ impl SchemaConverter {
             validation_rules: Vec::new(),
             compound_indexes: ast_model.get_indexes(),
             compound_uniques: ast_model.get_compound_uniques(),
+            fulltext_indexes: ast_model.get_fulltext_indexes(),
+            with: ast_model.get_with(),
+            touches: ast_model.get_touches(),
+            implements: ast_model.get_implements(),
+            appends: ast_model.get_appends(),
+            scopes: ast_model.get_scopes(),
+            date_format: ast_model.get_date_format(),
+            resource_name: ast_model.get_class_name_override("resourceName"),
+            dto_name: ast_model.get_class_name_override("dtoName"),
+            factory_name: ast_model.get_class_name_override("factoryName"),
+            module: ast_model.get_class_name_override("module"),
+            seed_count: Self::extract_seed_count(&ast_model)?,
         };
         
         // Convert fields
@@ -68,6 +206,8 @@ impl SchemaConverter {
     
     fn convert_field(ast_field: &ast::Field) -> Result<Field, String> {
         let field_type = Self::convert_field_type(&ast_field.field_type)?;
+        // Only integer keys auto-increment; a UUID or string `@id` is filled in by the app
+        let auto_increment = ast_field.is_id() && field_type.is_integer();
 
         let field = Field {
             name: ast_field.name.clone(),
@@ -80,11 +220,18 @@ impl SchemaConverter {
             enum_values: Vec::new(),
             decimal_precision: None,
             unsigned: false,
-            auto_increment: ast_field.is_id(),
+            auto_increment,
             primary: ast_field.is_id(),
             comment: None,
             validation_rules: Self::extract_validation_rules(ast_field),
             cast_type: None,
+            cast_format: ast_field.get_cast_format(),
+            json_schema: Self::extract_json_schema(ast_field)?,
+            after: ast_field.get_after(),
+            is_new: ast_field.get_attribute("new").is_some(),
+            hashed: ast_field.get_attribute("hashed").is_some(),
+            json_cast_as: Self::extract_json_cast(ast_field)?,
+            encrypted: ast_field.get_attribute("encrypted").is_some(),
         };
 
         Ok(field)
@@ -132,22 +279,70 @@ impl SchemaConverter {
         }
     }
     
-    fn extract_default(ast_field: &ast::Field) -> Option<String> {
+    /// Parses `"street: string, zip: ?int"` into documented JSON keys
+    fn extract_json_schema(ast_field: &ast::Field) -> Result<Vec<JsonKey>, String> {
+        let Some(schema) = ast_field.get_json_schema() else {
+            return Ok(Vec::new());
+        };
+
+        schema.split(',')
+            .map(|entry| match entry.split_once(':') {
+                Some((name, php_type)) => Ok(JsonKey {
+                    name: name.trim().to_string(),
+                    php_type: php_type.trim().to_string(),
+                }),
+                None => Err(format!(
+                    "Invalid @jsonSchema entry '{}' on field '{}' (expected \"key: type\")", entry.trim(), ast_field.name
+                )),
+            })
+            .collect()
+    }
+
+    fn extract_seed_count(ast_model: &ast::Model) -> Result<Option<u32>, String> {
+        let Some(count) = ast_model.get_seed_count() else {
+            return Ok(None);
+        };
+        Self::seed_count(count)
+            .map(Some)
+            .ok_or_else(|| format!("@@seedCount on model '{}' must be a positive number, got {}", ast_model.name, count))
+    }
+
+    fn seed_count(count: i64) -> Option<u32> {
+        u32::try_from(count).ok().filter(|count| *count > 0)
+    }
+
+    fn extract_json_cast(ast_field: &ast::Field) -> Result<Option<JsonCast>, String> {
+        let Some(cast) = ast_field.get_json_cast_as() else {
+            return Ok(None);
+        };
+        match cast.as_str() {
+            "array" => Ok(Some(JsonCast::Array)),
+            "collection" => Ok(Some(JsonCast::Collection)),
+            "object" => Ok(Some(JsonCast::Object)),
+            other => Err(format!(
+                "Unknown @jsonCastAs '{}' on field '{}' (expected \"array\", \"collection\" or \"object\")", other, ast_field.name
+            )),
+        }
+    }
+
+    fn extract_default(ast_field: &ast::Field) -> Option<ColumnDefault> {
         if let Some(default_value) = ast_field.get_default() {
-            match default_value {
-                ast::Value::String(s) => Some(s.clone()),
-                ast::Value::Integer(i) => Some(i.to_string()),
-                ast::Value::Boolean(b) => Some(b.to_string()),
+            let value = match default_value {
+                ast::Value::String(s) => s.clone(),
+                ast::Value::Integer(i) => i.to_string(),
+                ast::Value::Boolean(b) => b.to_string(),
+                ast::Value::FieldReference(name) if name == "null" => return Some(ColumnDefault::Null),
                 ast::Value::Function { name, .. } => {
                     // Handle special functions
                     match name.as_str() {
-                        "autoincrement" => None, // Handled by migration
-                        "now" => Some("CURRENT_TIMESTAMP".to_string()),
-                        _ => Some(format!("{}()", name)),
+                        "autoincrement" => return None, // Handled by migration
+                        "now" => "CURRENT_TIMESTAMP".to_string(),
+                        _ => format!("{}()", name),
                     }
                 }
-                _ => None,
-            }
+                _ => return None,
+            };
+            Some(ColumnDefault::Value(value))
         } else {
             None
         }
@@ -252,5 +447,251 @@ mod tests {
         assert_eq!(config.models[0].name, "User");
         assert_eq!(config.models[0].fields.len(), 2);
     }
+
+    #[test]
+    fn test_convert_string_id_does_not_auto_increment() {
+        let schema = crate::schema::parse_schema(r#"
+model Country {
+  code String @id
+  name String
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        let code = &config.models[0].fields[0];
+        assert!(code.primary);
+        assert!(!code.auto_increment);
+        assert!(crate::validation::Validator::validate_model(&config.models[0]).is_ok());
+    }
+
+    #[test]
+    fn test_convert_accessor_style_from_generator_block() {
+        let mut schema = ast::Schema::new();
+        schema.generators.push(ast::Generator {
+            name: "laravel".to_string(),
+            properties: [("accessorStyle".to_string(), ast::Value::String("legacy".to_string()))].into(),
+        });
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.accessor_style, AccessorStyle::Legacy);
+    }
+
+    #[test]
+    fn test_convert_after() {
+        let schema = crate::schema::parse_schema(r#"
+model User {
+  id       Int    @id
+  email    String
+  nickname String @after(email)
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.models[0].fields[2].after.as_deref(), Some("email"));
+        assert_eq!(config.models[0].fields[1].after, None);
+    }
+
+    #[test]
+    fn test_convert_alter_migration_mode() {
+        let schema = crate::schema::parse_schema(r#"
+generator laravel {
+  migrationMode = "alter"
+}
+
+model User {
+  id       Int    @id
+  nickname String @new
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.migration_mode, MigrationMode::Alter);
+        assert!(!config.models[0].fields[0].is_new);
+        assert!(config.models[0].fields[1].is_new);
+    }
+
+    #[test]
+    fn test_convert_hashed_field() {
+        let schema = crate::schema::parse_schema(r#"
+model User {
+  id       Int    @id
+  password String @hashed
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert!(!config.models[0].fields[0].hashed);
+        assert!(config.models[0].fields[1].hashed);
+    }
+
+    #[test]
+    fn test_convert_implements() {
+        let schema = crate::schema::parse_schema(r#"
+model Post {
+  id Int @id
+
+  @@implements(["Spatie\MediaLibrary\HasMedia", "Auditable"])
 }
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.models[0].implements, vec!["Spatie\\MediaLibrary\\HasMedia", "Auditable"]);
+    }
+
+    #[test]
+    fn test_convert_encrypted_field() {
+        let schema = crate::schema::parse_schema(r#"
+model Patient {
+  id  Int    @id
+  ssn String @encrypted
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert!(!config.models[0].fields[0].encrypted);
+        assert!(config.models[0].fields[1].encrypted);
+    }
+
+    #[test]
+    fn test_convert_json_cast_as() {
+        let schema = crate::schema::parse_schema(r#"
+model Post {
+  id   Int  @id
+  tags Json @jsonCastAs("collection")
+  meta Json @jsonCastAs("object")
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.models[0].fields[1].json_cast_as, Some(JsonCast::Collection));
+        assert_eq!(config.models[0].fields[2].json_cast_as, Some(JsonCast::Object));
+
+        let schema = crate::schema::parse_schema(r#"
+model Post {
+  id   Int  @id
+  tags Json @jsonCastAs("set")
+}
+"#).unwrap();
+        assert!(SchemaConverter::convert_to_config(schema).unwrap_err().contains("Unknown @jsonCastAs 'set'"));
+    }
+
+    #[test]
+    fn test_convert_seed_counts() {
+        let schema = crate::schema::parse_schema(r#"
+generator laravel {
+  seedCount = 50
+}
+
+model User {
+  id Int @id
+
+  @@seedCount(5)
+}
+
+model Post {
+  id Int @id
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.seed_count, 50);
+        assert_eq!(config.models[0].seed_count(&config), 5);
+        assert_eq!(config.models[1].seed_count(&config), 50);
+
+        let schema = crate::schema::parse_schema(r#"
+model User {
+  id Int @id
+
+  @@seedCount(0)
+}
+"#).unwrap();
+        assert!(SchemaConverter::convert_to_config(schema).unwrap_err().contains("must be a positive number"));
+    }
 
+    #[test]
+    fn test_convert_module() {
+        let schema = crate::schema::parse_schema(r#"
+model Invoice {
+  id Int @id
+
+  @@module("App\Billing")
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.models[0].module.as_deref(), Some("App\\Billing"));
+    }
+
+    #[test]
+    fn test_convert_null_default() {
+        let schema = crate::schema::parse_schema(r#"
+model User {
+  id       Int     @id
+  nickname String? @default(null)
+  bio      String?
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert!(config.models[0].fields[1].has_null_default());
+        assert_eq!(config.models[0].fields[2].default, None);
+    }
+
+    #[test]
+    fn test_convert_default_timestamps() {
+        let schema = crate::schema::parse_schema(r#"
+generator laravel {
+  defaultTimestamps = true
+}
+
+model Post {
+  id    Int    @id
+  title String
+}
+
+model Tag {
+  id   Int    @id
+  name String
+
+  @@timestamps(false)
+  @@softDeletes
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert!(config.default_timestamps);
+        assert_eq!(config.models[0].timestamps, None);
+        assert!(config.models[0].has_timestamps(&config));
+        assert_eq!(config.models[1].timestamps, Some(false));
+        assert!(!config.models[1].has_timestamps(&config));
+        assert_eq!(config.models[1].soft_deletes, Some(true));
+    }
+
+    #[test]
+    fn test_convert_fulltext_index() {
+        let schema = crate::schema::parse_schema(r#"
+model Post {
+  id    Int    @id
+  title String
+  body  Text
+
+  @@fulltext([title, body])
+}
+"#).unwrap();
+
+        let config = SchemaConverter::convert_to_config(schema).unwrap();
+        assert_eq!(config.models[0].fulltext_indexes, vec![vec!["title".to_string(), "body".to_string()]]);
+        assert!(config.models[0].compound_indexes.is_empty());
+    }
+
+    #[test]
+    fn test_convert_unknown_accessor_style() {
+        let mut schema = ast::Schema::new();
+        schema.generators.push(ast::Generator {
+            name: "laravel".to_string(),
+            properties: [("accessorStyle".to_string(), ast::Value::String("magic".to_string()))].into(),
+        });
+
+        assert!(SchemaConverter::convert_to_config(schema).is_err());
+    }
+}
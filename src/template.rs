@@ -1,5 +1,7 @@
 use crate::error::{GeneratorError, Result};
 use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::OnceLock;
 
 /// Template context for rendering templates with placeholders
 #[derive(Debug, Clone)]
@@ -49,31 +51,103 @@ impl Default for TemplateContext {
     }
 }
 
-/// Template renderer that replaces placeholders with context values
-pub struct TemplateRenderer;
+/// A piece of a compiled template
+#[derive(Debug, Clone)]
+enum Segment {
+    /// Literal text, as a byte range into the source
+    Text(Range<usize>),
+    /// A `{{name}}` placeholder, already validated
+    Placeholder(String),
+    /// A `{{#if name}}...{{/if}}` block, kept when `name` is set and non-empty
+    Conditional { name: String, body: Vec<Segment> },
+}
 
-impl TemplateRenderer {
-    /// Render a template with the given context
-    pub fn render(template: &str, context: &TemplateContext) -> Result<String> {
-        let mut result = template.to_string();
-        let mut missing_variables = Vec::new();
-        let mut used_variables = std::collections::HashSet::new();
+/// A template whose placeholders have been located once
+///
+/// Rendering only substitutes values, so a template used for many models is
+/// scanned a single time.
+///
+/// Besides `{{name}}` placeholders, templates may wrap optional output in
+/// `{{#if name}}...{{/if}}`. The block is rendered when `name` is in the context
+/// with a non-empty value and dropped otherwise; a missing condition variable is
+/// not an error. Conditionals cannot be nested.
+#[derive(Debug, Clone)]
+pub struct CompiledTemplate {
+    source: String,
+    segments: Vec<Segment>,
+}
 
-        // Find all placeholders in the template
-        let placeholders = Self::find_placeholders(template)?;
+impl CompiledTemplate {
+    /// Parse a template, validating every placeholder
+    pub fn compile(template: &str) -> Result<Self> {
+        let mut segments = Vec::new();
+        // The `{{#if}}` block being collected, if any
+        let mut open_block: Option<(String, Vec<Segment>)> = None;
+        let mut text_start = 0;
+
+        for span in TemplateRenderer::find_placeholders(template)? {
+            let target = match &mut open_block {
+                Some((_, body)) => body,
+                None => &mut segments,
+            };
+            if span.start > text_start {
+                target.push(Segment::Text(text_start..span.start));
+            }
+            text_start = span.end;
 
-        // Replace each placeholder
-        for placeholder in placeholders {
-            let var_name = Self::extract_variable_name(&placeholder)?;
-            
-            if let Some(value) = context.get(&var_name) {
-                result = result.replace(&placeholder, value);
-                used_variables.insert(var_name.clone());
+            let content = template[span.start + 2..span.end - 2].trim();
+            if let Some(condition) = content.strip_prefix("#if ") {
+                if open_block.is_some() {
+                    return Err(GeneratorError::Template(
+                        "Nested {{#if}} blocks are not supported".to_string()
+                    ));
+                }
+                let name = TemplateRenderer::extract_variable_name(&format!("{{{{{}}}}}", condition))?;
+                open_block = Some((name, Vec::new()));
+            } else if content == "/if" {
+                let Some((name, body)) = open_block.take() else {
+                    return Err(GeneratorError::Template(
+                        "Found {{/if}} without a matching {{#if}}".to_string()
+                    ));
+                };
+                segments.push(Segment::Conditional { name, body });
             } else {
-                missing_variables.push(var_name);
+                target.push(Segment::Placeholder(TemplateRenderer::extract_variable_name(&template[span])?));
             }
         }
 
+        if let Some((name, _)) = open_block {
+            return Err(GeneratorError::Template(
+                format!("Unclosed {{{{#if {}}}}} block", name)
+            ));
+        }
+        if text_start < template.len() {
+            segments.push(Segment::Text(text_start..template.len()));
+        }
+
+        Ok(Self {
+            source: template.to_string(),
+            segments,
+        })
+    }
+
+    /// Compile a built-in template on first use and reuse it afterwards
+    pub fn cached(cell: &'static OnceLock<CompiledTemplate>, template: &str) -> Result<&'static CompiledTemplate> {
+        if let Some(compiled) = cell.get() {
+            return Ok(compiled);
+        }
+        let compiled = Self::compile(template)?;
+        Ok(cell.get_or_init(|| compiled))
+    }
+
+    /// Substitute the context values into the template
+    pub fn render(&self, context: &TemplateContext) -> Result<String> {
+        let mut result = String::with_capacity(self.source.len());
+        let mut missing_variables: Vec<&str> = Vec::new();
+        let mut used_variables = std::collections::HashSet::new();
+
+        self.render_segments(&self.segments, context, &mut result, &mut missing_variables, &mut used_variables);
+
         // Check for missing variables
         if !missing_variables.is_empty() {
             return Err(GeneratorError::Template(
@@ -81,67 +155,94 @@ impl TemplateRenderer {
             ));
         }
 
-        // Warn about unused variables (in debug mode)
-        #[cfg(debug_assertions)]
-        {
+        // Report unused variables when debug logging is on
+        if log::log_enabled!(log::Level::Debug) {
             let unused_variables: Vec<_> = context.keys()
                 .into_iter()
-                .filter(|key| !used_variables.contains(*key))
+                .filter(|key| !used_variables.contains(key.as_str()))
                 .collect();
-            
+
             if !unused_variables.is_empty() {
-                eprintln!("Warning: Unused template variables: {:?}", unused_variables);
+                log::debug!("Unused template variables: {:?}", unused_variables);
             }
         }
 
         Ok(result)
     }
 
-    /// Find all placeholders in a template
-    fn find_placeholders(template: &str) -> Result<Vec<String>> {
-        let mut placeholders = Vec::new();
-        let chars = template.chars().collect::<Vec<_>>();
-        let mut i = 0;
-
-        while i < chars.len() {
-            if i < chars.len() - 1 && chars[i] == '{' && chars[i + 1] == '{' {
-                // Found start of placeholder
-                let start_pos = i;
-                i += 2; // Skip the opening {{
-
-                let mut placeholder_content = String::new();
-                let mut found_end = false;
-
-                // Look for closing }} but stop if we encounter another {{
-                while i < chars.len() - 1 {
-                    if chars[i] == '}' && chars[i + 1] == '}' {
-                        // Found closing }}
-                        i += 2; // Skip the closing }}
-                        found_end = true;
-                        break;
-                    } else if chars[i] == '{' && chars[i + 1] == '{' {
-                        // Found another opening {{ before closing the current one
-                        break;
-                    } else {
-                        placeholder_content.push(chars[i]);
-                        i += 1;
+    fn render_segments<'a>(
+        &self,
+        segments: &'a [Segment],
+        context: &TemplateContext,
+        result: &mut String,
+        missing_variables: &mut Vec<&'a str>,
+        used_variables: &mut std::collections::HashSet<&'a str>,
+    ) {
+        for segment in segments {
+            match segment {
+                Segment::Text(range) => result.push_str(&self.source[range.clone()]),
+                Segment::Placeholder(name) => {
+                    if let Some(value) = context.get(name) {
+                        result.push_str(value);
+                        used_variables.insert(name.as_str());
+                    } else if !missing_variables.contains(&name.as_str()) {
+                        missing_variables.push(name);
                     }
                 }
+                Segment::Conditional { name, body } => {
+                    used_variables.insert(name.as_str());
+                    if context.get(name).is_some_and(|value| !value.is_empty()) {
+                        self.render_segments(body, context, result, missing_variables, used_variables);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Render after checking that all required variables are present
+    pub fn render_with_required_vars(&self, context: &TemplateContext, required_vars: &[&str]) -> Result<String> {
+        TemplateRenderer::check_required_vars(context, required_vars)?;
+        self.render(context)
+    }
+}
+
+/// Template renderer that replaces placeholders with context values
+pub struct TemplateRenderer;
 
-                if !found_end {
+impl TemplateRenderer {
+    /// Render a template with the given context
+    ///
+    /// Compiles the template on every call; keep a `CompiledTemplate` when rendering it repeatedly.
+    #[allow(dead_code)] // library API; the bundled generators render cached templates
+    pub fn render(template: &str, context: &TemplateContext) -> Result<String> {
+        CompiledTemplate::compile(template)?.render(context)
+    }
+
+    /// Find the byte spans of all placeholders (including braces) in a template
+    fn find_placeholders(template: &str) -> Result<Vec<Range<usize>>> {
+        let mut spans = Vec::new();
+        let mut position = 0;
+
+        while let Some(offset) = template[position..].find("{{") {
+            let start = position + offset;
+            let content_start = start + 2;
+            let rest = &template[content_start..];
+
+            // Look for closing }} but stop if we encounter another {{
+            match (rest.find("}}"), rest.find("{{")) {
+                (Some(close), reopen) if reopen.is_none_or(|reopen| close < reopen) => {
+                    position = content_start + close + 2;
+                    spans.push(start..position);
+                }
+                _ => {
                     return Err(GeneratorError::Template(
-                        format!("Unclosed placeholder starting at position {}", start_pos)
+                        format!("Unclosed placeholder starting at position {}", template[..start].chars().count())
                     ));
                 }
-
-                let full_placeholder = format!("{{{{{}}}}}", placeholder_content);
-                placeholders.push(full_placeholder);
-            } else {
-                i += 1;
             }
         }
 
-        Ok(placeholders)
+        Ok(spans)
     }
 
     /// Extract variable name from a placeholder (e.g., "{{variable_name}}" -> "variable_name")
@@ -172,12 +273,18 @@ impl TemplateRenderer {
     }
 
     /// Render a template with validation of required variables
+    #[allow(dead_code)] // library API; the bundled generators render cached templates
     pub fn render_with_required_vars(
         template: &str, 
         context: &TemplateContext, 
         required_vars: &[&str]
     ) -> Result<String> {
-        // Check that all required variables are present
+        Self::check_required_vars(context, required_vars)?;
+        Self::render(template, context)
+    }
+
+    /// Check that all required variables are present
+    fn check_required_vars(context: &TemplateContext, required_vars: &[&str]) -> Result<()> {
         let missing_required: Vec<_> = required_vars
             .iter()
             .filter(|&var| !context.contains(var))
@@ -189,8 +296,7 @@ impl TemplateRenderer {
                        missing_required.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", "))
             ));
         }
-
-        Self::render(template, context)
+        Ok(())
     }
 }
 
@@ -258,12 +364,15 @@ mod tests {
     #[test]
     fn test_find_placeholders() {
         let template = "Hello {{name}}, you are {{age}} years old! {{greeting}}";
-        let placeholders = TemplateRenderer::find_placeholders(template).unwrap();
-        
+        let placeholders: Vec<&str> = TemplateRenderer::find_placeholders(template).unwrap()
+            .into_iter()
+            .map(|span| &template[span])
+            .collect();
+
         assert_eq!(placeholders.len(), 3);
-        assert!(placeholders.contains(&"{{name}}".to_string()));
-        assert!(placeholders.contains(&"{{age}}".to_string()));
-        assert!(placeholders.contains(&"{{greeting}}".to_string()));
+        assert!(placeholders.contains(&"{{name}}"));
+        assert!(placeholders.contains(&"{{age}}"));
+        assert!(placeholders.contains(&"{{greeting}}"));
     }
 
     #[test]
@@ -375,4 +484,131 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid variable name"));
     }
+
+    fn controller_template_context(model: &str) -> TemplateContext {
+        let template = include_str!("templates/controller.php.template");
+        let mut context = TemplateContext::new();
+        for span in TemplateRenderer::find_placeholders(template).unwrap() {
+            let name = TemplateRenderer::extract_variable_name(&template[span]).unwrap();
+            let value = format!("{}_{}", model, name);
+            context.set(name, value);
+        }
+        context
+    }
+
+    #[test]
+    fn test_compiled_matches_direct_rendering() {
+        let template = include_str!("templates/controller.php.template");
+        let compiled = CompiledTemplate::compile(template).unwrap();
+
+        for model in ["User", "BlogPost", "Category"] {
+            let context = controller_template_context(model);
+            assert_eq!(compiled.render(&context).unwrap(), TemplateRenderer::render(template, &context).unwrap());
+        }
+
+        let missing = TemplateContext::new().with("name", "John");
+        let compiled = CompiledTemplate::compile("Hello {{name}}, {{age}} and {{age}} again").unwrap();
+        assert!(compiled.render(&missing).unwrap_err().to_string().ends_with("Missing template variables: age"));
+    }
+
+    #[test]
+    fn test_compiled_rendering_skips_rescanning() {
+        let template = include_str!("templates/controller.php.template");
+        let context = controller_template_context("User");
+        let iterations = 2_000;
+
+        let started = std::time::Instant::now();
+        let mut direct = String::new();
+        for _ in 0..iterations {
+            direct = TemplateRenderer::render(template, &context).unwrap();
+        }
+        let direct_elapsed = started.elapsed();
+
+        let started = std::time::Instant::now();
+        let compiled_template = CompiledTemplate::compile(template).unwrap();
+        let mut compiled = String::new();
+        for _ in 0..iterations {
+            compiled = compiled_template.render(&context).unwrap();
+        }
+        let compiled_elapsed = started.elapsed();
+
+        assert_eq!(direct, compiled);
+        // Generous bound: substitution alone must not cost more than scanning plus substitution
+        assert!(compiled_elapsed <= direct_elapsed * 2, "compiled {:?} vs direct {:?}", compiled_elapsed, direct_elapsed);
+    }
+
+    #[test]
+    fn test_conditional_block_with_value() {
+        let template = "use Model;{{#if import}}\nuse {{import}};{{/if}}\n";
+        let context = TemplateContext::new().with("import", "App\\Rules\\Slug");
+
+        let result = TemplateRenderer::render(template, &context).unwrap();
+        assert_eq!(result, "use Model;\nuse App\\Rules\\Slug;\n");
+    }
+
+    #[test]
+    fn test_conditional_block_with_empty_value() {
+        let template = "use Model;{{#if import}}\nuse {{import}};{{/if}}\n";
+        let context = TemplateContext::new().with("import", "");
+
+        let result = TemplateRenderer::render(template, &context).unwrap();
+        assert_eq!(result, "use Model;\n");
+    }
+
+    #[test]
+    fn test_conditional_block_with_missing_variable() {
+        // Placeholders inside a dropped block are not required either
+        let template = "class {{name}}{{#if parent}} extends {{parent}}{{/if}}";
+        let context = TemplateContext::new().with("name", "User");
+
+        let result = TemplateRenderer::render(template, &context).unwrap();
+        assert_eq!(result, "class User");
+    }
+
+    #[test]
+    fn test_conditional_block_errors() {
+        let nested = TemplateRenderer::render("{{#if a}}{{#if b}}x{{/if}}{{/if}}", &TemplateContext::new());
+        assert!(nested.unwrap_err().to_string().contains("Nested {{#if}} blocks are not supported"));
+
+        let unclosed = TemplateRenderer::render("{{#if a}}x", &TemplateContext::new());
+        assert!(unclosed.unwrap_err().to_string().contains("Unclosed {{#if a}} block"));
+
+        let stray = TemplateRenderer::render("x{{/if}}", &TemplateContext::new());
+        assert!(stray.unwrap_err().to_string().contains("without a matching {{#if}}"));
+    }
+
+    /// Logger keeping every record so tests can inspect what was logged
+    struct CapturingLogger(std::sync::Mutex<Vec<(log::Level, String)>>);
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.0.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURED_LOGS: CapturingLogger = CapturingLogger(std::sync::Mutex::new(Vec::new()));
+
+    #[test]
+    fn test_unused_variables_reported_through_debug_log() {
+        log::set_logger(&CAPTURED_LOGS).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let context = TemplateContext::new()
+            .with("name", "John")
+            .with("leftover_marker_variable", "unused");
+        TemplateRenderer::render("Hello {{name}}", &context).unwrap();
+
+        let logs = CAPTURED_LOGS.0.lock().unwrap();
+        assert!(logs.iter().any(|(level, message)| {
+            *level == log::Level::Debug
+                && message.starts_with("Unused template variables")
+                && message.contains("leftover_marker_variable")
+        }));
+    }
 }
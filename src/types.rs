@@ -1,8 +1,10 @@
+use crate::generators::shared::MigrationRun;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub enum FieldType {
+    #[default]
     String,
     Text,
     Integer,
@@ -23,6 +25,11 @@ pub enum FieldType {
     MediumText,
     Binary,
     Inet,
+    Point,
+    Geometry,
+    Polygon,
+    Year,
+    Time,
 }
 
 impl FieldType {
@@ -48,13 +55,25 @@ impl FieldType {
             FieldType::MediumText => "mediumText",
             FieldType::Binary => "binary",
             FieldType::Inet => "ipAddress",
+            FieldType::Point => "point",
+            FieldType::Geometry => "geometry",
+            FieldType::Polygon => "polygon",
+            FieldType::Year => "year",
+            FieldType::Time => "time",
         }
     }
 
+    /// Whole-number column types, the ones that can auto-increment
+    pub fn is_integer(&self) -> bool {
+        matches!(self, FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger
+            | FieldType::SmallInteger | FieldType::MediumInteger)
+    }
+
     pub fn to_cast_type(&self) -> Option<&'static str> {
         match self {
             FieldType::Boolean => Some("boolean"),
-            FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger | FieldType::SmallInteger | FieldType::MediumInteger => Some("integer"),
+            FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger | FieldType::SmallInteger | FieldType::MediumInteger
+                | FieldType::Year => Some("integer"),
             FieldType::Float | FieldType::Decimal => Some("float"),
             FieldType::Json => Some("array"),
             FieldType::DateTime | FieldType::Timestamp => Some("datetime"),
@@ -72,6 +91,9 @@ pub struct StandardRelationship {
     pub foreign_key: Option<String>,
     #[serde(default)]
     pub local_key: Option<String>,
+    /// Column a belongsTo foreign key references on the parent table (defaults to `id`)
+    #[serde(default)]
+    pub owner_key: Option<String>,
     #[serde(default)]
     pub pivot_table: Option<String>,
     #[serde(default)]
@@ -82,6 +104,12 @@ pub struct StandardRelationship {
     pub on_update: Option<String>,
     #[serde(default)]
     pub with_timestamps: bool,
+    /// Optional belongsTo: the auto-created foreign key column is nullable
+    #[serde(default)]
+    pub nullable: bool,
+    /// Explicit Eloquent method name (e.g. `parent` for a self-referencing belongsTo)
+    #[serde(default)]
+    pub method_name: Option<String>,
 }
 
 // Polymorphic relationship for morphTo (no model field needed)
@@ -92,9 +120,12 @@ pub struct PolymorphicRelationship {
     pub foreign_key: Option<String>,
     #[serde(default)]
     pub local_key: Option<String>,
+    /// Create the morph columns as nullable (`nullableMorphs`)
+    #[serde(default)]
+    pub optional: bool,
 }
 
-// Polymorphic relationship for morphOne, morphMany, morphToMany
+// Polymorphic relationship for morphOne, morphMany, morphToMany, morphedByMany
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PolymorphicWithModelRelationship {
     pub model: String,
@@ -113,6 +144,9 @@ pub struct PolymorphicWithModelRelationship {
     pub on_update: Option<String>,
     #[serde(default)]
     pub with_timestamps: bool,
+    /// Explicit Eloquent method name, overriding the one derived from the model
+    #[serde(default)]
+    pub method_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -133,15 +167,17 @@ pub struct ValidationRule {
     pub parameters: Option<Vec<String>>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct ModelDefinition {
     pub name: String,
     pub table: String,
     pub fields: Vec<Field>,
+    /// `created_at` / `updated_at`; unset falls back to `Config::default_timestamps`
     #[serde(default)]
-    pub timestamps: bool,
+    pub timestamps: Option<bool>,
+    /// `deleted_at`; unset falls back to `Config::default_soft_deletes`
     #[serde(default)]
-    pub soft_deletes: bool,
+    pub soft_deletes: Option<bool>,
     #[serde(default)]
     pub relationships: Vec<Relationship>,
     #[serde(default)]
@@ -156,6 +192,105 @@ pub struct ModelDefinition {
     pub compound_indexes: Vec<Vec<String>>,
     #[serde(default)]
     pub compound_uniques: Vec<Vec<String>>,
+    /// Column groups indexed with `fullText()` (MySQL and PostgreSQL only)
+    #[serde(default)]
+    pub fulltext_indexes: Vec<Vec<String>>,
+    /// Relationship methods to eager-load by default (`protected $with`)
+    #[serde(default)]
+    pub with: Vec<String>,
+    /// belongsTo relationships whose parent timestamps update on save (`protected $touches`)
+    #[serde(default)]
+    pub touches: Vec<String>,
+    /// Interfaces the model class implements; namespaced ones (`Spatie\MediaLibrary\HasMedia`) are imported
+    #[serde(default)]
+    pub implements: Vec<String>,
+    /// Computed attributes appended to serialization (`protected $appends`)
+    #[serde(default)]
+    pub appends: Vec<String>,
+    /// Local query scopes stubbed as `scope{Name}` methods
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Storage format for the model's dates (`protected $dateFormat`)
+    #[serde(default)]
+    pub date_format: Option<String>,
+    /// Overrides the `{Model}Resource` class name
+    #[serde(default)]
+    pub resource_name: Option<String>,
+    /// Overrides the `{Model}DTO` class name
+    #[serde(default)]
+    pub dto_name: Option<String>,
+    /// Overrides the `{Model}Factory` class name
+    #[serde(default)]
+    pub factory_name: Option<String>,
+    /// Module the model's classes are grouped under (`App\Billing` -> `App\Billing\Models`,
+    /// `App\Billing\Controllers`, ...), overriding both `namespace` and the DDD layout.
+    /// Unlike the config-wide `namespace` it is a root, not the model's own namespace.
+    #[serde(default)]
+    pub module: Option<String>,
+    /// Rows the model's seeder creates, overriding the config-wide `seed_count`
+    #[serde(default)]
+    pub seed_count: Option<u32>,
+}
+
+impl ModelDefinition {
+    /// Whether the table has timestamps, applying the config-wide default when unset
+    pub fn has_timestamps(&self, config: &Config) -> bool {
+        self.timestamps.unwrap_or(config.default_timestamps)
+    }
+
+    /// Rows the model's seeder creates, applying the config-wide count when unset
+    pub fn seed_count(&self, config: &Config) -> u32 {
+        self.seed_count.unwrap_or(config.seed_count)
+    }
+
+    /// Table name with the config-wide prefix and suffix applied
+    pub fn table_name(&self, config: &Config) -> String {
+        config.table_name(&self.table)
+    }
+
+    /// Whether the model soft-deletes, applying the config-wide default when unset
+    pub fn has_soft_deletes(&self, config: &Config) -> bool {
+        self.soft_deletes.unwrap_or(config.default_soft_deletes)
+    }
+
+    /// Interfaces the model implements, without repeats
+    pub fn interfaces(&self) -> Vec<&str> {
+        let mut interfaces: Vec<&str> = Vec::new();
+        for interface in &self.implements {
+            if !interfaces.contains(&interface.as_str()) {
+                interfaces.push(interface);
+            }
+        }
+        interfaces
+    }
+
+    /// The field of a single-column primary key, `None` for the implicit `id` or a composite key
+    pub fn primary_key(&self) -> Option<&Field> {
+        let mut primary = self.fields.iter().filter(|field| field.primary);
+        match (primary.next(), primary.next()) {
+            (Some(field), None) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// The single primary field when it is a UUID
+    pub fn uuid_primary_key(&self) -> Option<&Field> {
+        self.primary_key().filter(|field| matches!(field.field_type, FieldType::Uuid))
+    }
+
+    /// The UUID key the `HasUuids` trait fills in, when `has_uuids` is on
+    pub fn has_uuids_key(&self, config: &Config) -> Option<&Field> {
+        self.uuid_primary_key().filter(|_| config.has_uuids)
+    }
+
+    /// Columns of a primary key spanning several fields, `None` for a single-column key
+    pub fn composite_primary_key(&self) -> Option<Vec<&str>> {
+        let columns: Vec<&str> = self.fields.iter()
+            .filter(|field| field.primary)
+            .map(|field| field.name.as_str())
+            .collect();
+        (columns.len() > 1).then_some(columns)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -166,7 +301,7 @@ pub enum FillableGuarded {
     All,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Field {
     pub name: String,
     #[serde(rename = "type")]
@@ -175,8 +310,9 @@ pub struct Field {
     pub nullable: bool,
     #[serde(default)]
     pub unique: bool,
-    #[serde(default)]
-    pub default: Option<String>,
+    /// Column default; `None` when the field declares none
+    #[serde(default, deserialize_with = "deserialize_default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<ColumnDefault>,
     #[serde(default)]
     pub length: Option<u32>,
     #[serde(default)]
@@ -197,6 +333,114 @@ pub struct Field {
     pub validation_rules: Vec<ValidationRule>,
     #[serde(default)]
     pub cast_type: Option<String>,
+    /// Format suffix for date casts (`'datetime:Y-m-d'`)
+    #[serde(default)]
+    pub cast_format: Option<String>,
+    /// Known sub-keys of a JSON column, documented on the DTO
+    #[serde(default)]
+    pub json_schema: Vec<JsonKey>,
+    /// Column this one is placed after (`->after('email')`, MySQL only)
+    #[serde(default)]
+    pub after: Option<String>,
+    /// Added since the table was created; these are the columns of an alter migration
+    #[serde(default, rename = "new")]
+    pub is_new: bool,
+    /// Stored as a one-way hash: cast to `hashed` and faked with `Hash::make`
+    #[serde(default)]
+    pub hashed: bool,
+    /// What a JSON column casts to (`array` when unset)
+    #[serde(default)]
+    pub json_cast_as: Option<JsonCast>,
+    /// Encrypted at rest: cast to `encrypted` (`encrypted:array` and friends for JSON)
+    #[serde(default)]
+    pub encrypted: bool,
+}
+
+/// Declared column default
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnDefault {
+    /// Explicit `NULL` default (`@default(null)`, `"default": null`)
+    Null,
+    /// Literal value, kept as written (`"draft"`, `"0"`, `"CURRENT_TIMESTAMP"`)
+    Value(String),
+}
+
+impl Serialize for ColumnDefault {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ColumnDefault::Null => serializer.serialize_none(),
+            ColumnDefault::Value(value) => serializer.serialize_str(value),
+        }
+    }
+}
+
+/// Keeps an explicit `"default": null` apart from an omitted default
+fn deserialize_default<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<ColumnDefault>, D::Error> {
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    Ok(Some(value.map_or(ColumnDefault::Null, ColumnDefault::Value)))
+}
+
+impl Field {
+    /// Non-null column with no default or other modifiers
+    pub fn new(name: &str, field_type: FieldType) -> Self {
+        Field { name: name.to_string(), field_type, ..Default::default() }
+    }
+
+    /// Whether the column explicitly defaults to `NULL`
+    pub fn has_null_default(&self) -> bool {
+        self.default == Some(ColumnDefault::Null)
+    }
+
+    /// The literal default value, `None` when there is none or it is `NULL`
+    pub fn default_value(&self) -> Option<&str> {
+        match &self.default {
+            Some(ColumnDefault::Value(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Eloquent cast for the field, keeping a decimal's declared scale (`decimal:2`)
+    pub fn to_cast_type(&self) -> Option<String> {
+        if self.hashed {
+            return Some("hashed".to_string());
+        }
+        if self.encrypted {
+            return Some(match (&self.field_type, self.json_cast_as) {
+                (FieldType::Json, Some(JsonCast::Collection)) => "encrypted:collection",
+                (FieldType::Json, Some(JsonCast::Object)) => "encrypted:object",
+                (FieldType::Json, _) => "encrypted:array",
+                _ => "encrypted",
+            }.to_string());
+        }
+        if let (FieldType::Json, Some(JsonCast::Object)) = (&self.field_type, self.json_cast_as) {
+            return Some("object".to_string());
+        }
+        match (&self.field_type, &self.decimal_precision) {
+            (FieldType::Decimal, Some(precision)) => Some(format!("decimal:{}", precision.scale)),
+            (field_type, _) => field_type.to_cast_type().map(str::to_string),
+        }
+    }
+}
+
+/// Model cast of a JSON column
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonCast {
+    /// `'array'`
+    Array,
+    /// `AsCollection::class`
+    Collection,
+    /// `'object'` (a `stdClass`)
+    Object,
+}
+
+/// One documented key inside a JSON column (`street: string`)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JsonKey {
+    pub name: String,
+    /// PHP type of the value (`string`, `?int`, `array`)
+    #[serde(rename = "type")]
+    pub php_type: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -210,9 +454,64 @@ pub enum Relationship {
     MorphOne(PolymorphicWithModelRelationship),
     MorphMany(PolymorphicWithModelRelationship),
     MorphToMany(PolymorphicWithModelRelationship),
+    /// Inverse of `MorphToMany` (a `Tag` morphed by many `Post`s)
+    MorphedByMany(PolymorphicWithModelRelationship),
+}
+
+/// How accessor stubs for appended attributes are written
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessorStyle {
+    /// `protected function fullName(): Attribute` (Laravel 9+)
+    #[default]
+    Attribute,
+    /// `public function getFullNameAttribute()`
+    Legacy,
+}
+
+/// How model casts are declared
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum CastsStyle {
+    /// `protected $casts = [...]`
+    #[default]
+    Property,
+    /// `protected function casts(): array` (Laravel 11+)
+    Method,
 }
 
+/// Word separator used in generated route URIs
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum RouteCase {
+    /// `blog-posts`
+    #[default]
+    Kebab,
+    /// `blog_posts`
+    Snake,
+}
+
+/// How each model's routes are registered
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum RouteStyle {
+    /// `Route::apiResource('posts', PostController::class)`
+    #[default]
+    ApiResource,
+    /// One `Route::get/post/put/delete` line per action, so middleware can differ per verb
+    Explicit,
+}
 
+/// Kind of migration generated for each model
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum MigrationMode {
+    /// `Schema::create` with every column
+    #[default]
+    Create,
+    /// `Schema::table` adding only the fields marked `new`
+    Alter,
+}
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PivotTable {
@@ -227,37 +526,107 @@ pub struct PivotTable {
     pub timestamps: bool,
 }
 
+/// Cast family used for date and datetime columns
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DatetimeCast {
+    /// `datetime` / `date`
+    #[default]
+    Datetime,
+    /// `immutable_datetime` / `immutable_date`
+    ImmutableDatetime,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct Config {
     pub models: Vec<ModelDefinition>,
-    #[serde(default)]
     pub output_dir: String,
-    #[serde(default)]
     pub namespace: String,
-    #[serde(default)]
     pub generate_models: bool,
-    #[serde(default)]
     pub generate_controllers: bool,
-    #[serde(default)]
     pub generate_resources: bool,
-    #[serde(default)]
     pub generate_factories: bool,
-    #[serde(default)]
     pub generate_migrations: bool,
-    #[serde(default)]
     pub generate_pivot_tables: bool,
-    #[serde(default)]
     pub generate_validation_rules: bool,
-    #[serde(default)]
     pub generate_requests: bool,
-    #[serde(default)]
     pub generate_dto: bool,
-    #[serde(default)]
+    /// Controllers return `{Model}DTO` JSON instead of API resources (needs `generate_dto`)
+    pub dto_responses: bool,
+    /// DTOs implement `Illuminate\Contracts\Support\Arrayable`
+    pub dto_arrayable: bool,
+    /// DTOs implement `JsonSerializable`, serializing through `toArray()`
+    pub dto_json_serializable: bool,
+    pub generate_graphql: bool,
+    pub generate_repositories: bool,
+    pub generate_services: bool,
+    /// `database/seeders/{Model}Seeder.php` classes creating rows through the model factory
+    pub generate_seeders: bool,
+    /// Rows each seeder creates, unless the model sets its own `seed_count`
+    pub seed_count: u32,
+    /// Backed enum classes for enum fields, used as the fields' model casts
+    pub generate_enums: bool,
+    /// `routes/api_generated.php` with a `Route::apiResource` line per model (needs controllers)
+    pub generate_routes: bool,
+    pub accessor_style: AccessorStyle,
+    pub casts_style: CastsStyle,
+    pub route_case: RouteCase,
+    pub route_style: RouteStyle,
+    pub migration_mode: MigrationMode,
+    pub datetime_cast: DatetimeCast,
+    pub auto_foreign_ids: bool,
+    /// Timestamps for models that don't set `timestamps` themselves
+    pub default_timestamps: bool,
+    /// Soft deletes for models that don't set `soft_deletes` themselves
+    pub default_soft_deletes: bool,
+    pub auto_index_foreign_keys: bool,
+    /// Unique and index constraints are separate `$table->unique('col', 'name')` calls with explicit names
+    pub named_indexes: bool,
+    /// Models keyed by a UUID primary field use Laravel's `HasUuids` trait
+    pub has_uuids: bool,
+    /// Unsigned numeric fields get a `min:0` validation rule
+    pub unsigned_min_rule: bool,
+    /// API resources format timestamps with `toIso8601String()`
+    pub resource_iso_timestamps: bool,
+    /// API resources of soft-deleting models return `deleted_at`
+    pub resource_include_deleted_at: bool,
+    /// Every generated file starts with the `@generated by schemly` header comment
+    pub include_comments: bool,
+    /// Invalid field names are renamed to valid identifiers instead of rejected
+    pub sanitize_field_names: bool,
     pub use_ddd_structure: bool,
-    #[serde(default)]
     pub database_engine: String,
-    #[serde(default)]
+    /// MySQL storage engine set on created tables (`InnoDB`)
+    pub table_engine: Option<String>,
+    /// MySQL default charset for created tables (`utf8mb4`)
+    pub charset: Option<String>,
+    /// MySQL default collation for created tables (`utf8mb4_unicode_ci`)
+    pub collation: Option<String>,
+    /// Prepended to every model table name (`tenant_`)
+    pub table_prefix: String,
+    /// Appended to every model table name
+    pub table_suffix: String,
+    /// Fully-qualified class every generated model extends (`App\Models\BaseModel`)
+    pub base_model: String,
     pub force_overwrite: bool,
+    /// Overwrite existing files only when they still carry the generated marker
+    pub update_generated: bool,
+    /// Start time and order shared by the migrations of the current render
+    #[serde(skip)]
+    pub migration_run: Option<MigrationRun>,
+}
+
+impl Config {
+    /// Copy of the config whose migrations are timestamped from now, in one shared order
+    pub fn for_migration_run(&self) -> Config {
+        Config { migration_run: Some(MigrationRun::new(&self.models)), ..self.clone() }
+    }
+
+    /// Applies `table_prefix` and `table_suffix` to a bare table name (`users` -> `tenant_users`)
+    pub fn table_name(&self, table: &str) -> String {
+        format!("{}{}{}", self.table_prefix, table, self.table_suffix)
+    }
 }
 
 impl Default for Config {
@@ -275,9 +644,44 @@ impl Default for Config {
             generate_validation_rules: true,
             generate_requests: false,
             generate_dto: false,
+            generate_graphql: false,
+            generate_repositories: false,
+            generate_services: false,
+            generate_seeders: false,
+            seed_count: 10,
+            generate_enums: false,
+            generate_routes: false,
+            accessor_style: AccessorStyle::default(),
+            casts_style: CastsStyle::default(),
+            route_case: RouteCase::default(),
+            route_style: RouteStyle::default(),
+            migration_mode: MigrationMode::default(),
+            datetime_cast: DatetimeCast::default(),
+            auto_foreign_ids: false,
+            default_timestamps: false,
+            default_soft_deletes: false,
+            dto_responses: false,
+            dto_arrayable: false,
+            dto_json_serializable: false,
+            auto_index_foreign_keys: true,
+            named_indexes: false,
+            has_uuids: true,
+            unsigned_min_rule: false,
+            resource_iso_timestamps: false,
+            resource_include_deleted_at: false,
+            include_comments: true,
+            sanitize_field_names: false,
             use_ddd_structure: false,
             database_engine: "mysql".to_string(),
+            table_engine: None,
+            charset: None,
+            collation: None,
+            table_prefix: String::new(),
+            table_suffix: String::new(),
+            base_model: "Illuminate\\Database\\Eloquent\\Model".to_string(),
             force_overwrite: false,
+            update_generated: false,
+            migration_run: None,
         }
     }
 }
\ No newline at end of file
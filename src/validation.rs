@@ -1,5 +1,10 @@
 use crate::error::{GeneratorError, Result};
-use crate::types::{ModelDefinition, Field, FieldType};
+use crate::generators::shared::{NameHelper, RelationshipHelper};
+use crate::types::{ModelDefinition, Field, FieldType, DecimalPrecision, FillableGuarded, Relationship};
+use serde_json::Value;
+
+/// JSON Schema describing JSON and YAML config files, also usable for editor completion
+pub const CONFIG_JSON_SCHEMA: &str = include_str!("../schemas/schemly.schema.json");
 
 /// Validates and sanitizes input for code generation
 pub struct Validator;
@@ -14,7 +19,8 @@ impl Validator {
         Self::validate_table_name(&model.table)?;
         
         // Validate that model has at least one field or timestamps
-        if model.fields.is_empty() && !model.timestamps {
+        // (unset timestamps may still come from `Config::default_timestamps`)
+        if model.fields.is_empty() && model.timestamps == Some(false) {
             return Err(GeneratorError::ModelValidation(
                 format!("Model '{}' must have at least one field or timestamps enabled", model.name)
             ));
@@ -35,9 +41,377 @@ impl Validator {
             }
         }
 
+        // A table has at most one auto-incrementing column
+        let auto_increment: Vec<&str> = model.fields.iter()
+            .filter(|field| field.auto_increment)
+            .map(|field| field.name.as_str())
+            .collect();
+        if auto_increment.len() > 1 {
+            return Err(GeneratorError::ModelValidation(
+                format!("Model '{}' has more than one auto_increment field: {}", model.name, auto_increment.join(", "))
+            ));
+        }
+
+        // `after` must name another column of the same table
+        for field in &model.fields {
+            if let Some(column) = &field.after
+                && (column == &field.name || (column != "id" && !model.fields.iter().any(|f| &f.name == column))) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Field '{}' in model '{}' is placed after '{}', which is not another column of the table",
+                           field.name, model.name, column)
+                ));
+            }
+        }
+
+        // Interfaces may be namespaced; two of them must not share a short name
+        let mut interface_names: Vec<&str> = Vec::new();
+        for interface in model.interfaces() {
+            Self::validate_qualified_name(interface, "Interface")?;
+            let name = interface.rsplit('\\').next().unwrap_or(interface);
+            if interface_names.contains(&name) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' implements two interfaces named '{}'", model.name, name)
+                ));
+            }
+            interface_names.push(name);
+        }
+
+        for attribute in &model.appends {
+            Self::validate_identifier(attribute, "Appended attribute")?;
+        }
+
+        for scope in &model.scopes {
+            Self::validate_identifier(scope, "Scope name")?;
+        }
+
+        for (class_name, label) in [
+            (&model.resource_name, "Resource class name"),
+            (&model.dto_name, "DTO class name"),
+            (&model.factory_name, "Factory class name"),
+        ] {
+            if let Some(class_name) = class_name {
+                Self::validate_identifier(class_name, label)?;
+            }
+        }
+
+        if let Some(module) = &model.module {
+            Self::validate_namespace(module)?;
+        }
+
+        if let Some(count) = model.seed_count {
+            Self::validate_seed_count(count, &format!("Model '{}'", model.name))?;
+        }
+
+        if let Some(format) = &model.date_format
+            && format.trim().is_empty() {
+            return Err(GeneratorError::ModelValidation(
+                format!("Model '{}' has an empty date format", model.name)
+            ));
+        }
+
+        for relationship in &model.relationships {
+            if let Some(method_name) = RelationshipHelper::explicit_method_name(relationship) {
+                Self::validate_identifier(method_name, "Relationship method name")?;
+            }
+        }
+        Self::validate_relationship_method_names(model)?;
+        Self::validate_referential_actions(model)?;
+        Self::validate_belongs_to_many(model)?;
+
+        // Eager-loaded and touched relationships must match a declared relationship method
+        let relationship_names: Vec<String> = model.relationships.iter()
+            .map(RelationshipHelper::method_name)
+            .collect();
+        for relation in &model.with {
+            if !relationship_names.contains(relation) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' eager-loads unknown relationship '{}' in $with", model.name, relation)
+                ));
+            }
+        }
+        for relation in &model.touches {
+            if !relationship_names.contains(relation) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' touches unknown relationship '{}' in $touches", model.name, relation)
+                ));
+            }
+        }
+
         Ok(())
     }
     
+    /// Rejects relationship methods that would clash with PHP, Eloquent or the model's own attributes
+    ///
+    /// Derived names (`list` for a belongsTo `List`) are not otherwise checked, and a
+    /// method sharing a column or accessor name makes `$model->name` ambiguous.
+    fn validate_relationship_method_names(model: &ModelDefinition) -> Result<()> {
+        // Public `Illuminate\Database\Eloquent\Model` methods a relationship would override
+        const ELOQUENT_MODEL_METHODS: &[&str] = &[
+            "save", "delete", "update", "push", "fill", "query", "fresh", "refresh",
+            "replicate", "load", "touch", "increment", "decrement", "toarray", "tojson",
+            "getkey", "gettable", "getattribute", "setattribute", "getattributes",
+            "relationloaded", "setrelation", "newquery", "newinstance", "forcedelete",
+            "restore", "trashed", "exists", "attributes", "relations",
+        ];
+
+        let taken_names: Vec<String> = model.fields.iter()
+            .map(|field| field.name.as_str())
+            .chain(model.appends.iter().map(String::as_str))
+            .flat_map(|name| [name.to_lowercase(), NameHelper::studly_case(name).to_lowercase()])
+            .collect();
+
+        for relationship in &model.relationships {
+            let method_name = RelationshipHelper::method_name(relationship);
+            let lowercase = method_name.to_lowercase();
+
+            let problem = if Self::is_php_reserved_word(&method_name) {
+                "is a PHP reserved word"
+            } else if ELOQUENT_MODEL_METHODS.contains(&lowercase.as_str()) {
+                "would override an Eloquent Model method"
+            } else if taken_names.contains(&lowercase) {
+                "collides with a field or appended attribute of the same name"
+            } else {
+                continue;
+            };
+
+            return Err(GeneratorError::ModelValidation(
+                format!("Model '{}' relationship method '{}' {}; set method_name to rename it",
+                       model.name, method_name, problem)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates that a self-referencing belongsToMany names its pivot table
+    ///
+    /// The conventional `{model}_{model}` name would join the table to itself through two
+    /// identical keys, so schemly does not derive one.
+    fn validate_belongs_to_many(model: &ModelDefinition) -> Result<()> {
+        for relationship in &model.relationships {
+            if let Relationship::BelongsToMany(rel) = relationship
+                && rel.model == model.name
+                && rel.pivot_table.is_none() {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' belongsToMany itself through '{}' and needs an explicit pivot_table",
+                           model.name, RelationshipHelper::method_name(relationship))
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates that relationship `on_delete`/`on_update` values are actions Laravel supports
+    fn validate_referential_actions(model: &ModelDefinition) -> Result<()> {
+        const ACTIONS: &[&str] = &["cascade", "restrict", "set null", "no action"];
+
+        for relationship in &model.relationships {
+            let (on_delete, on_update) = match relationship {
+                Relationship::BelongsTo(rel) | Relationship::HasOne(rel) |
+                Relationship::HasMany(rel) | Relationship::BelongsToMany(rel) => (&rel.on_delete, &rel.on_update),
+                Relationship::MorphOne(rel) | Relationship::MorphMany(rel) |
+                Relationship::MorphToMany(rel) | Relationship::MorphedByMany(rel) => (&rel.on_delete, &rel.on_update),
+                Relationship::MorphTo(_) => continue,
+            };
+            for action in [on_delete, on_update].into_iter().flatten() {
+                if !ACTIONS.contains(&action.to_lowercase().as_str()) {
+                    return Err(GeneratorError::ModelValidation(
+                        format!("Model '{}' relationship '{}' has unsupported referential action '{}' (expected one of: {})",
+                               model.name, RelationshipHelper::method_name(relationship), action, ACTIONS.join(", "))
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates that every belongsTo foreign key names an existing column, and that an owner key comes with one
+    ///
+    /// A missing column is allowed when `auto_foreign_ids` is enabled, since the
+    /// migration then creates it with `foreignId()->constrained()`.
+    pub fn validate_foreign_keys(model: &ModelDefinition, auto_foreign_ids: bool) -> Result<()> {
+        for relationship in &model.relationships {
+            // Laravel only takes the owner key after the foreign key, and the constraint needs both
+            if let Relationship::BelongsTo(rel) = relationship
+                && let (None, Some(owner_key)) = (&rel.foreign_key, &rel.owner_key) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' belongsTo '{}' sets owner_key '{}' without a foreign_key (name the referencing column too)",
+                           model.name, rel.model, owner_key)
+                ));
+            }
+
+            if let Relationship::BelongsTo(rel) = relationship
+                && let Some(foreign_key) = &rel.foreign_key
+                && !auto_foreign_ids
+                && !model.fields.iter().any(|f| &f.name == foreign_key) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' belongsTo '{}' uses foreign key '{}', which is not a field (declare it or enable autoForeignIds)",
+                           model.name, rel.model, foreign_key)
+                ));
+            }
+
+            // An optional belongsTo falls back to `set null`, which needs a nullable column
+            if let Relationship::BelongsTo(rel) = relationship
+                && rel.nullable
+                && let Some(foreign_key) = &rel.foreign_key
+                && model.fields.iter().any(|f| &f.name == foreign_key && !f.nullable) {
+                return Err(GeneratorError::ModelValidation(
+                    format!("Model '{}' belongsTo '{}' is nullable, but field '{}' is not (add '?' to the field)",
+                           model.name, rel.model, foreign_key)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects belongsTo chains that loop, since no migration order would create each referenced table first
+    pub fn validate_migration_order(models: &[ModelDefinition]) -> Result<()> {
+        RelationshipHelper::migration_order(models).map(|_| ()).map_err(|cycle| {
+            GeneratorError::ModelValidation(format!(
+                "Foreign keys form a cycle ({}); no migration order creates every referenced table first",
+                cycle.join(" -> ")
+            ))
+        })
+    }
+
+    /// Rejects a seeder that would create no rows
+    pub fn validate_seed_count(count: u32, owner: &str) -> Result<()> {
+        if count == 0 {
+            return Err(GeneratorError::Configuration(
+                format!("{} has seed_count 0; seeders need a positive row count", owner)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates that a model's pivot tables join existing models through valid keys
+    pub fn validate_pivot_tables(model: &ModelDefinition, models: &[ModelDefinition]) -> Result<()> {
+        for pivot_table in &model.pivot_tables {
+            Self::validate_identifier(&pivot_table.name, "Pivot table name")?;
+
+            for related in [&pivot_table.model1, &pivot_table.model2] {
+                if !models.iter().any(|m| &m.name == related) {
+                    return Err(GeneratorError::ModelValidation(
+                        format!("Pivot table '{}' references undefined model '{}'", pivot_table.name, related)
+                    ));
+                }
+            }
+
+            let context = format!("Pivot table '{}' foreign key", pivot_table.name);
+            Self::validate_identifier(&pivot_table.foreign_key1, &context)?;
+            Self::validate_identifier(&pivot_table.foreign_key2, &context)?;
+        }
+        Ok(())
+    }
+
+    /// Rejects keys in a JSON config that its structs don't declare
+    ///
+    /// Serde defaults otherwise silently ignore typos such as `timestamp` for `timestamps`.
+    /// Models, fields, relationships, pivot tables and their nested objects are all checked.
+    pub fn validate_known_keys(config: &Value) -> Result<()> {
+        Self::check_known_keys(config, known_keys::CONFIG, "config")?;
+        for (model, model_location) in Self::entries(config, "models", "") {
+            Self::check_known_keys(model, known_keys::MODEL, &model_location)?;
+            Self::check_fields(model, "fields", &model_location)?;
+            Self::check_rules(model, &model_location)?;
+
+            for (relationship, location) in Self::entries(model, "relationships", &model_location) {
+                let keys = match relationship.get("type").and_then(Value::as_str) {
+                    Some("morphTo") => known_keys::MORPH_TO,
+                    Some("morphOne" | "morphMany" | "morphToMany" | "morphedByMany") => known_keys::MORPH_WITH_MODEL,
+                    _ => known_keys::STANDARD_RELATIONSHIP,
+                };
+                Self::check_known_keys(relationship, keys, &location)?;
+            }
+
+            for (pivot_table, location) in Self::entries(model, "pivot_tables", &model_location) {
+                Self::check_known_keys(pivot_table, known_keys::PIVOT_TABLE, &location)?;
+                Self::check_fields(pivot_table, "additional_fields", &location)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_fields(owner: &Value, key: &str, owner_location: &str) -> Result<()> {
+        for (field, location) in Self::entries(owner, key, owner_location) {
+            Self::check_known_keys(field, known_keys::FIELD, &location)?;
+            Self::check_rules(field, &location)?;
+            for (enum_value, enum_location) in Self::entries(field, "enum_values", &location) {
+                Self::check_known_keys(enum_value, known_keys::ENUM_VALUE, &enum_location)?;
+            }
+            for (json_key, json_location) in Self::entries(field, "json_schema", &location) {
+                Self::check_known_keys(json_key, known_keys::JSON_KEY, &json_location)?;
+            }
+            if let Some(precision) = field.get("decimal_precision") {
+                Self::check_known_keys(precision, known_keys::DECIMAL_PRECISION, &format!("{}.decimal_precision", location))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_rules(owner: &Value, owner_location: &str) -> Result<()> {
+        for (rule, location) in Self::entries(owner, "validation_rules", owner_location) {
+            Self::check_known_keys(rule, known_keys::VALIDATION_RULE, &location)?;
+        }
+        Ok(())
+    }
+
+    /// Items of the array at `owner[key]`, each paired with its location (`models[0].fields[2]`)
+    fn entries<'a>(owner: &'a Value, key: &str, owner_location: &str) -> Vec<(&'a Value, String)> {
+        let prefix = if owner_location.is_empty() { String::new() } else { format!("{}.", owner_location) };
+        owner.get(key).and_then(Value::as_array).into_iter().flatten()
+            .enumerate()
+            .map(|(index, item)| (item, format!("{}{}[{}]", prefix, key, index)))
+            .collect()
+    }
+
+    fn check_known_keys(value: &Value, known: &[&str], location: &str) -> Result<()> {
+        if let Value::Object(map) = value {
+            for key in map.keys() {
+                if !known.contains(&key.as_str()) {
+                    return Err(GeneratorError::Configuration(
+                        format!("Unknown key '{}' in {}", key, location)
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks a raw JSON or YAML config document against [`CONFIG_JSON_SCHEMA`]
+    ///
+    /// Each problem is reported with the JSON pointer of the offending value
+    /// (`/models/0/fields/1/type: "strnig" is not one of ...`).
+    pub fn json_schema_errors(document: &Value) -> Vec<GeneratorError> {
+        let schema: Value = serde_json::from_str(CONFIG_JSON_SCHEMA)
+            .expect("bundled config JSON Schema is valid JSON");
+        let validator = jsonschema::validator_for(&schema)
+            .expect("bundled config JSON Schema compiles");
+
+        validator.iter_errors(document)
+            .map(|error| {
+                let path = error.instance_path.to_string();
+                let path = if path.is_empty() { "/".to_string() } else { path };
+                GeneratorError::Configuration(format!("{}: {}", path, error))
+            })
+            .collect()
+    }
+
+    /// Largest length (or integer display width) a column type accepts, with a hint for the error
+    fn max_length(field_type: &FieldType) -> Option<(u32, &'static str)> {
+        match field_type {
+            FieldType::String => Some((65_535, "; use `text` for longer values")),
+            FieldType::Text => Some((65_535, "; use `mediumText` for longer values")),
+            FieldType::MediumText => Some((16_777_215, "; use `longText` for longer values")),
+            FieldType::TinyInteger => Some((4, " (display width)")),
+            FieldType::SmallInteger => Some((6, " (display width)")),
+            FieldType::MediumInteger => Some((9, " (display width)")),
+            FieldType::Integer => Some((11, " (display width)")),
+            FieldType::BigInteger => Some((20, " (display width)")),
+            _ => None,
+        }
+    }
+
     /// Validates a single field definition
     pub fn validate_field(field: &Field) -> Result<()> {
         // Validate field name
@@ -45,12 +419,11 @@ impl Validator {
         
         // Validate field type specific constraints
         match field.field_type {
-            FieldType::String | FieldType::Text | FieldType::LongText | FieldType::MediumText => {
-                if field.length.is_some() && field.length.unwrap() == 0 {
-                    return Err(GeneratorError::FieldValidation(
-                        format!("String field '{}' cannot have zero length", field.name)
-                    ));
-                }
+            FieldType::String | FieldType::Text | FieldType::LongText | FieldType::MediumText
+                if field.length == Some(0) => {
+                return Err(GeneratorError::FieldValidation(
+                    format!("String field '{}' cannot have zero length", field.name)
+                ));
             }
             FieldType::Decimal => {
                 if field.decimal_precision.is_none() {
@@ -73,25 +446,87 @@ impl Validator {
                     ));
                 }
                 // Validate enum values
+                let mut seen_values = std::collections::HashSet::new();
                 for enum_value in &field.enum_values {
                     if enum_value.value.is_empty() {
                         return Err(GeneratorError::FieldValidation(
                             format!("Enum field '{}' has empty enum value", field.name)
                         ));
                     }
+                    if !seen_values.insert(&enum_value.value) {
+                        return Err(GeneratorError::FieldValidation(
+                            format!("Enum field '{}' has duplicate enum value '{}'", field.name, enum_value.value)
+                        ));
+                    }
+                }
+                // Validate that the default is one of the declared values
+                if let Some(default) = field.default_value()
+                    && !field.enum_values.iter().any(|v| v.value == default) {
+                    let allowed: Vec<&str> = field.enum_values.iter().map(|v| v.value.as_str()).collect();
+                    return Err(GeneratorError::FieldValidation(
+                        format!("Default value '{}' for enum field '{}' must be one of: {}",
+                               default, field.name, allowed.join(", "))
+                    ));
                 }
             }
+            FieldType::Point | FieldType::Geometry | FieldType::Polygon if field.default.is_some() => {
+                return Err(GeneratorError::FieldValidation(
+                    format!("Spatial field '{}' cannot have a default value", field.name)
+                ));
+            }
             _ => {} // Other types don't need special validation
         }
+
+        // Validate the length against the column type's maximum
+        if let Some(length) = field.length
+            && let Some((max, hint)) = Self::max_length(&field.field_type)
+            && length > max {
+            return Err(GeneratorError::FieldValidation(
+                format!("Field '{}' has length {}, but {:?} allows at most {}{}",
+                       field.name, length, field.field_type, max, hint)
+            ));
+        }
         
+        // Validate that only text columns hold a hash
+        if field.hashed && !matches!(field.field_type,
+            FieldType::String | FieldType::Text | FieldType::LongText | FieldType::MediumText) {
+            return Err(GeneratorError::FieldValidation(
+                format!("Field '{}' cannot be hashed with type {:?}; use a string column", field.name, field.field_type)
+            ));
+        }
+
+        // Validate that encryption targets a column wide enough for the ciphertext
+        if field.encrypted && !matches!(field.field_type,
+            FieldType::String | FieldType::Text | FieldType::LongText | FieldType::MediumText | FieldType::Json) {
+            return Err(GeneratorError::FieldValidation(
+                format!("Field '{}' cannot be encrypted with type {:?}; use a string, text or json column", field.name, field.field_type)
+            ));
+        }
+        if field.encrypted && field.hashed {
+            return Err(GeneratorError::FieldValidation(
+                format!("Field '{}' cannot be both hashed and encrypted", field.name)
+            ));
+        }
+
+        if field.json_cast_as.is_some() && !matches!(field.field_type, FieldType::Json) {
+            return Err(GeneratorError::FieldValidation(
+                format!("Field '{}' sets json_cast_as but has type {:?}; only json fields take it", field.name, field.field_type)
+            ));
+        }
+
         // Validate that auto_increment is only used with integer types
-        if field.auto_increment && !matches!(field.field_type, 
-            FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger | 
-            FieldType::SmallInteger | FieldType::MediumInteger) {
+        if field.auto_increment && !field.field_type.is_integer() {
             return Err(GeneratorError::FieldValidation(
                 format!("Field '{}' cannot use auto_increment with type {:?}", field.name, field.field_type)
             ));
         }
+
+        // Databases only auto-increment a key column
+        if field.auto_increment && !field.primary {
+            return Err(GeneratorError::FieldValidation(
+                format!("Field '{}' uses auto_increment but is not the primary key", field.name)
+            ));
+        }
         
         // Validate that primary key fields are not nullable
         if field.primary && field.nullable {
@@ -99,10 +534,109 @@ impl Validator {
                 format!("Primary key field '{}' cannot be nullable", field.name)
             ));
         }
+
+        // Validate that a cast format is non-empty and only set on date columns
+        if let Some(format) = &field.cast_format {
+            if format.trim().is_empty() {
+                return Err(GeneratorError::FieldValidation(
+                    format!("Field '{}' has an empty cast format", field.name)
+                ));
+            }
+            if !matches!(field.field_type, FieldType::Date | FieldType::DateTime | FieldType::Timestamp) {
+                return Err(GeneratorError::FieldValidation(
+                    format!("Cast format on field '{}' requires a date or datetime type, got {:?}", field.name, field.field_type)
+                ));
+            }
+        }
+
+        // Validate documented JSON keys
+        if !field.json_schema.is_empty() {
+            if !matches!(field.field_type, FieldType::Json) {
+                return Err(GeneratorError::FieldValidation(
+                    format!("JSON schema on field '{}' requires a Json type, got {:?}", field.name, field.field_type)
+                ));
+            }
+            for key in &field.json_schema {
+                Self::validate_identifier(&key.name, "JSON key")?;
+                if key.php_type.trim().is_empty() {
+                    return Err(GeneratorError::FieldValidation(
+                        format!("JSON key '{}' on field '{}' has no type", key.name, field.name)
+                    ));
+                }
+            }
+        }
+
+        // Validate that the default value fits the column type
+        if field.has_null_default() {
+            if !field.nullable {
+                return Err(GeneratorError::FieldValidation(
+                    format!("Field '{}' defaults to null but is not nullable", field.name)
+                ));
+            }
+        } else if let Some(default) = field.default_value() {
+            Self::validate_default_value(field, default)?;
+        }
         
         Ok(())
     }
+
+    /// Validates that a field's default value can be stored in its column type
+    fn validate_default_value(field: &Field, default: &str) -> Result<()> {
+        let (is_valid, expected) = match field.field_type {
+            FieldType::Integer | FieldType::BigInteger | FieldType::TinyInteger |
+            FieldType::SmallInteger | FieldType::MediumInteger | FieldType::Year => {
+                (default.parse::<i64>().is_ok(), "a valid integer".to_string())
+            }
+            FieldType::Boolean => {
+                let is_valid = matches!(default.to_lowercase().as_str(), "true" | "false" | "0" | "1");
+                (is_valid, "one of true, false, 0 or 1".to_string())
+            }
+            FieldType::Float => (default.parse::<f64>().is_ok(), "a valid number".to_string()),
+            FieldType::Decimal => match &field.decimal_precision {
+                Some(precision) => (
+                    Self::fits_decimal_precision(default, precision),
+                    format!("a number within precision={}, scale={}", precision.precision, precision.scale),
+                ),
+                None => (default.parse::<f64>().is_ok(), "a valid number".to_string()),
+            },
+            FieldType::String => match field.length {
+                Some(length) => (default.chars().count() <= length as usize, format!("at most {} characters long", length)),
+                None => (true, String::new()),
+            },
+            _ => (true, String::new()), // Other types accept free-form defaults (e.g. CURRENT_TIMESTAMP)
+        };
+
+        if !is_valid {
+            return Err(GeneratorError::FieldValidation(
+                format!("Default value '{}' for field '{}' must be {}", default, field.name, expected)
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a numeric literal fits a DECIMAL(precision, scale) column
+    fn fits_decimal_precision(value: &str, precision: &DecimalPrecision) -> bool {
+        if value.parse::<f64>().is_err() {
+            return false;
+        }
+
+        let unsigned = value.trim_start_matches(['-', '+']);
+        let (integer_part, fraction_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let integer_digits = integer_part.trim_start_matches('0').len();
+        let max_integer_digits = precision.precision.saturating_sub(precision.scale) as usize;
+
+        integer_digits <= max_integer_digits && fraction_part.len() <= precision.scale as usize
+    }
     
+    /// Validates a class name that may be namespaced (`App\Models\BaseModel`)
+    pub fn validate_qualified_name(name: &str, context: &str) -> Result<()> {
+        for segment in name.split('\\') {
+            Self::validate_identifier(segment, &format!("{} '{}'", context, name))?;
+        }
+        Ok(())
+    }
+
     /// Validates and sanitizes PHP/Laravel identifiers (class names, field names, etc.)
     pub fn validate_identifier(name: &str, context: &str) -> Result<()> {
         if name.is_empty() {
@@ -143,6 +677,40 @@ impl Validator {
         Ok(())
     }
     
+    /// Validates a PHP namespace such as `App` or `App\Domain\Billing`
+    pub fn validate_namespace(namespace: &str) -> Result<()> {
+        if namespace.is_empty() {
+            return Err(GeneratorError::InvalidIdentifier(
+                "Namespace cannot be empty".to_string()
+            ));
+        }
+
+        for segment in namespace.split('\\') {
+            let first_char = match segment.chars().next() {
+                Some(c) => c,
+                None => {
+                    return Err(GeneratorError::InvalidIdentifier(
+                        format!("Namespace '{}' contains an empty segment", namespace)
+                    ));
+                }
+            };
+
+            if !first_char.is_ascii_alphabetic() && first_char != '_' {
+                return Err(GeneratorError::InvalidIdentifier(
+                    format!("Namespace '{}' is invalid: segment '{}' must start with a letter or underscore", namespace, segment)
+                ));
+            }
+
+            if !segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(GeneratorError::InvalidIdentifier(
+                    format!("Namespace '{}' is invalid: segment '{}' contains invalid characters (only letters, numbers, and underscores allowed)", namespace, segment)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validates table names (similar to identifiers but with different rules)
     pub fn validate_table_name(name: &str) -> Result<()> {
         if name.is_empty() {
@@ -168,6 +736,68 @@ impl Validator {
         Ok(())
     }
 
+    /// Turns an arbitrary field name into a valid identifier (`invalid-name` -> `invalid_name`)
+    ///
+    /// Invalid characters become underscores, a leading digit gets a `field_` prefix
+    /// and PHP reserved words a `_field` suffix. Valid names are returned unchanged.
+    pub fn sanitize_field_name(name: &str) -> String {
+        let mut sanitized: String = name.trim().chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+            .collect();
+
+        if sanitized.is_empty() {
+            sanitized.push_str("field");
+        } else if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+            sanitized.insert_str(0, "field_");
+        }
+        if Self::is_php_reserved_word(&sanitized) {
+            sanitized.push_str("_field");
+        }
+        sanitized.truncate(64);
+        sanitized
+    }
+
+    /// Sanitizes every field name of a model, renaming the references in indexes,
+    /// fillable/guarded lists and relationship keys to match
+    ///
+    /// Returns the `(original, sanitized)` pairs of the fields that changed.
+    pub fn sanitize_model_fields(model: &mut ModelDefinition) -> Vec<(String, String)> {
+        let renames: Vec<(String, String)> = model.fields.iter()
+            .map(|field| (field.name.clone(), Self::sanitize_field_name(&field.name)))
+            .filter(|(original, sanitized)| original != sanitized)
+            .collect();
+        if renames.is_empty() {
+            return renames;
+        }
+
+        let rename = |name: &mut String| {
+            if let Some((_, sanitized)) = renames.iter().find(|(original, _)| original == name) {
+                *name = sanitized.clone();
+            }
+        };
+
+        for field in &mut model.fields {
+            rename(&mut field.name);
+            field.after.iter_mut().for_each(rename);
+        }
+        for columns in model.compound_indexes.iter_mut()
+            .chain(model.compound_uniques.iter_mut())
+            .chain(model.fulltext_indexes.iter_mut())
+        {
+            columns.iter_mut().for_each(rename);
+        }
+        if let FillableGuarded::Fillable(names) | FillableGuarded::Guarded(names) = &mut model.fillable_guarded {
+            names.iter_mut().for_each(rename);
+        }
+        for relationship in &mut model.relationships {
+            if let Relationship::BelongsTo(rel) | Relationship::HasOne(rel) | Relationship::HasMany(rel) = relationship {
+                rel.foreign_key.iter_mut().chain(rel.local_key.iter_mut()).for_each(rename);
+            }
+        }
+
+        renames
+    }
+
     /// Checks if a string is a PHP reserved word
     fn is_php_reserved_word(word: &str) -> bool {
         const PHP_RESERVED_WORDS: &[&str] = &[
@@ -187,28 +817,85 @@ impl Validator {
     }
 }
 
+/// Keys each config object accepts, mirroring the serde names in `types`
+mod known_keys {
+    /// `Config`
+    pub const CONFIG: &[&str] = &[
+        "models", "output_dir", "namespace", "generate_models", "generate_controllers",
+        "generate_resources", "generate_factories", "generate_migrations", "generate_pivot_tables",
+        "generate_validation_rules", "generate_requests", "generate_dto", "dto_responses",
+        "dto_arrayable", "dto_json_serializable", "generate_graphql", "generate_repositories",
+        "generate_services", "generate_seeders", "seed_count", "generate_enums", "generate_routes",
+        "accessor_style", "casts_style", "route_case", "route_style", "migration_mode",
+        "datetime_cast", "auto_foreign_ids", "default_timestamps", "default_soft_deletes",
+        "auto_index_foreign_keys", "named_indexes", "has_uuids", "unsigned_min_rule",
+        "resource_iso_timestamps", "resource_include_deleted_at", "include_comments",
+        "sanitize_field_names", "use_ddd_structure", "database_engine", "table_engine", "charset",
+        "collation", "table_prefix", "table_suffix", "base_model", "force_overwrite",
+        "update_generated",
+    ];
+
+    /// `ModelDefinition`
+    pub const MODEL: &[&str] = &[
+        "name", "table", "fields", "timestamps", "soft_deletes", "relationships", "pivot_tables",
+        "validation_rules", "traits", "fillable_guarded", "compound_indexes", "compound_uniques",
+        "fulltext_indexes", "with", "touches", "implements", "appends", "scopes", "date_format",
+        "resource_name", "dto_name", "factory_name", "module", "seed_count",
+    ];
+
+    /// `Field`
+    pub const FIELD: &[&str] = &[
+        "name", "type", "nullable", "unique", "default", "length", "index", "enum_values",
+        "decimal_precision", "unsigned", "auto_increment", "primary", "comment", "validation_rules",
+        "cast_type", "cast_format", "json_schema", "after", "new", "hashed", "encrypted",
+        "json_cast_as",
+    ];
+
+    /// `belongsTo`, `hasMany`, `hasOne` and `belongsToMany` relationships
+    pub const STANDARD_RELATIONSHIP: &[&str] = &[
+        "type", "model", "foreign_key", "local_key", "owner_key", "pivot_table", "pivot_fields",
+        "on_delete", "on_update", "with_timestamps", "nullable", "method_name",
+    ];
+
+    /// `morphTo` relationships
+    pub const MORPH_TO: &[&str] = &["type", "morph_name", "foreign_key", "local_key", "optional"];
+
+    /// `morphOne`, `morphMany`, `morphToMany` and `morphedByMany` relationships
+    pub const MORPH_WITH_MODEL: &[&str] = &[
+        "type", "model", "morph_name", "foreign_key", "local_key", "pivot_table", "pivot_fields",
+        "on_delete", "on_update", "with_timestamps", "method_name",
+    ];
+
+    /// `PivotTable`
+    pub const PIVOT_TABLE: &[&str] = &[
+        "name", "model1", "model2", "foreign_key1", "foreign_key2", "additional_fields", "timestamps",
+    ];
+
+    /// `ValidationRule`
+    pub const VALIDATION_RULE: &[&str] = &["rule", "parameters"];
+
+    /// `EnumValue`
+    pub const ENUM_VALUE: &[&str] = &["value", "label"];
+
+    /// `DecimalPrecision`
+    pub const DECIMAL_PRECISION: &[&str] = &["precision", "scale"];
+
+    /// `JsonKey`
+    pub const JSON_KEY: &[&str] = &["name", "type"];
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{FillableGuarded, DecimalPrecision, EnumValue};
+    use crate::types::{ColumnDefault, Config, EnumValue, JsonCast, PivotTable, Relationship, StandardRelationship};
+    use serde_json::json;
 
     fn create_valid_field() -> Field {
         Field {
             name: "test_field".to_string(),
             field_type: FieldType::String,
-            nullable: false,
-            unique: false,
-            default: None,
             length: Some(255),
-            index: false,
-            enum_values: vec![],
-            decimal_precision: None,
-            unsigned: false,
-            auto_increment: false,
-            primary: false,
-            comment: None,
-            validation_rules: vec![],
-            cast_type: None,
+            ..Default::default()
         }
     }
 
@@ -217,15 +904,9 @@ mod tests {
             name: "TestModel".to_string(),
             table: "test_models".to_string(),
             fields: vec![create_valid_field()],
-            timestamps: true,
-            soft_deletes: false,
-            relationships: vec![],
-            pivot_tables: vec![],
-            validation_rules: vec![],
-            traits: vec![],
-            fillable_guarded: FillableGuarded::All,
-            compound_indexes: vec![],
-            compound_uniques: vec![],
+            timestamps: Some(true),
+            soft_deletes: Some(false),
+            ..Default::default()
         }
     }
 
@@ -246,7 +927,7 @@ mod tests {
     fn test_validate_model_with_no_fields_no_timestamps() {
         let mut model = create_valid_model();
         model.fields = vec![];
-        model.timestamps = false;
+        model.timestamps = Some(false);
         assert!(Validator::validate_model(&model).is_err());
     }
 
@@ -257,8 +938,423 @@ mod tests {
         assert!(Validator::validate_model(&model).is_err());
     }
 
-
-
+    fn create_relationship(model: &str) -> StandardRelationship {
+        StandardRelationship {
+            model: model.to_string(),
+            foreign_key: None,
+            local_key: None,
+            owner_key: None,
+            pivot_table: None,
+            pivot_fields: vec![],
+            on_delete: None,
+            on_update: None,
+            with_timestamps: false,
+            nullable: false,
+            method_name: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_with_matches_relationships() {
+        let mut model = create_valid_model();
+        model.relationships = vec![
+            Relationship::BelongsTo(create_relationship("Author")),
+            Relationship::BelongsToMany(create_relationship("Tag")),
+        ];
+        model.with = vec!["author".to_string(), "tags".to_string()];
+        assert!(Validator::validate_model(&model).is_ok());
+    }
+
+    #[test]
+    fn test_validate_with_unknown_relationship() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::BelongsTo(create_relationship("Author"))];
+        model.with = vec!["comments".to_string()];
+
+        let result = Validator::validate_model(&model);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("unknown relationship 'comments'"));
+    }
+
+    #[test]
+    fn test_validate_touches_matches_relationships() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::BelongsTo(create_relationship("Post"))];
+        model.touches = vec!["post".to_string()];
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.touches = vec!["author".to_string()];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("touches unknown relationship 'author'"));
+    }
+
+    #[test]
+    fn test_validate_implements() {
+        let mut model = create_valid_model();
+        model.implements = vec!["App\\Contracts\\Auditable".to_string(), "App\\Contracts\\Auditable".to_string()];
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.implements = vec!["App\\Contracts\\Has-Media".to_string()];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("Interface 'App\\Contracts\\Has-Media'"));
+
+        model.implements = vec!["App\\Contracts\\Auditable".to_string(), "OwenIt\\Auditing\\Contracts\\Auditable".to_string()];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("two interfaces named 'Auditable'"));
+    }
+
+    #[test]
+    fn test_validate_seed_count_positive() {
+        let mut model = create_valid_model();
+        model.seed_count = Some(25);
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.seed_count = Some(0);
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("Model 'TestModel' has seed_count 0"));
+        assert!(Validator::validate_seed_count(0, "The config").is_err());
+    }
+
+    #[test]
+    fn test_validate_relationship_method_name() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            method_name: Some("parent".to_string()),
+            ..create_relationship("TestModel")
+        })];
+        model.with = vec!["parent".to_string()];
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            method_name: Some("parent-category".to_string()),
+            ..create_relationship("TestModel")
+        })];
+        model.with = vec![];
+        assert!(Validator::validate_model(&model).is_err());
+    }
+
+    #[test]
+    fn test_validate_reserved_relationship_method_name() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::BelongsTo(create_relationship("List"))];
+
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("relationship method 'list' is a PHP reserved word"));
+
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            method_name: Some("checklist".to_string()),
+            ..create_relationship("List")
+        })];
+        assert!(Validator::validate_model(&model).is_ok());
+    }
+
+    #[test]
+    fn test_validate_relationship_method_collisions() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::HasOne(create_relationship("TestField"))];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("collides with a field"));
+
+        model.relationships = vec![Relationship::HasMany(StandardRelationship {
+            method_name: Some("update".to_string()),
+            ..create_relationship("Revision")
+        })];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("would override an Eloquent Model method"));
+    }
+
+    #[test]
+    fn test_validate_after_references_existing_column() {
+        let mut model = create_valid_model();
+        let mut email = create_valid_field();
+        email.name = "email".to_string();
+        email.after = Some("test_field".to_string());
+        model.fields.push(email);
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.fields[1].after = Some("missing".to_string());
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("placed after 'missing'"));
+
+        model.fields[1].after = Some("email".to_string());
+        assert!(Validator::validate_model(&model).is_err());
+    }
+
+    #[test]
+    fn test_validate_self_referencing_belongs_to_many_needs_pivot() {
+        let mut model = create_valid_model();
+        let mut relationship = create_relationship("TestModel");
+        model.relationships = vec![Relationship::BelongsToMany(relationship.clone())];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("needs an explicit pivot_table"));
+
+        relationship.pivot_table = Some("friendships".to_string());
+        model.relationships = vec![Relationship::BelongsToMany(relationship)];
+        assert!(Validator::validate_model(&model).is_ok());
+    }
+
+    #[test]
+    fn test_validate_referential_actions() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            on_delete: Some("set null".to_string()),
+            on_update: Some("CASCADE".to_string()),
+            ..create_relationship("Author")
+        })];
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            on_delete: Some("nullify".to_string()),
+            ..create_relationship("Author")
+        })];
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("unsupported referential action 'nullify'"));
+    }
+
+    fn create_belongs_to(foreign_key: &str) -> Relationship {
+        Relationship::BelongsTo(StandardRelationship {
+            foreign_key: Some(foreign_key.to_string()),
+            ..create_relationship("Author")
+        })
+    }
+
+    #[test]
+    fn test_validate_foreign_key_present() {
+        let mut model = create_valid_model();
+        let mut author_id = create_valid_field();
+        author_id.name = "author_id".to_string();
+        model.fields.push(author_id);
+        model.relationships = vec![create_belongs_to("author_id")];
+
+        assert!(Validator::validate_foreign_keys(&model, false).is_ok());
+    }
+
+    #[test]
+    fn test_validate_foreign_key_missing() {
+        let mut model = create_valid_model();
+        model.relationships = vec![create_belongs_to("author_id")];
+
+        let result = Validator::validate_foreign_keys(&model, false);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("foreign key 'author_id', which is not a field"));
+    }
+
+    #[test]
+    fn test_validate_nullable_foreign_key_column() {
+        let mut model = create_valid_model();
+        let mut author_id = create_valid_field();
+        author_id.name = "author_id".to_string();
+        model.fields.push(author_id);
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            foreign_key: Some("author_id".to_string()),
+            nullable: true,
+            ..create_relationship("Author")
+        })];
+
+        let result = Validator::validate_foreign_keys(&model, false);
+        assert!(result.unwrap_err().to_string().contains("is nullable, but field 'author_id' is not"));
+
+        model.fields[1].nullable = true;
+        assert!(Validator::validate_foreign_keys(&model, false).is_ok());
+    }
+
+    #[test]
+    fn test_validate_owner_key_without_foreign_key() {
+        let mut model = create_valid_model();
+        model.relationships = vec![Relationship::BelongsTo(StandardRelationship {
+            owner_key: Some("uuid".to_string()),
+            ..create_relationship("Author")
+        })];
+
+        let result = Validator::validate_foreign_keys(&model, true);
+        assert!(result.unwrap_err().to_string().contains("sets owner_key 'uuid' without a foreign_key"));
+    }
+
+    #[test]
+    fn test_validate_foreign_key_missing_with_auto_create() {
+        let mut model = create_valid_model();
+        model.relationships = vec![create_belongs_to("author_id")];
+
+        assert!(Validator::validate_foreign_keys(&model, true).is_ok());
+    }
+
+    fn create_pivot_models(model2: &str) -> Vec<ModelDefinition> {
+        let mut role = create_valid_model();
+        role.name = "Role".to_string();
+        let mut user = create_valid_model();
+        user.name = "User".to_string();
+        user.pivot_tables = vec![PivotTable {
+            name: "role_user".to_string(),
+            model1: "Role".to_string(),
+            model2: model2.to_string(),
+            foreign_key1: "role_id".to_string(),
+            foreign_key2: "user_id".to_string(),
+            additional_fields: vec![],
+            timestamps: false,
+        }];
+        vec![role, user]
+    }
+
+    #[test]
+    fn test_validate_pivot_table() {
+        let models = create_pivot_models("User");
+        assert!(Validator::validate_pivot_tables(&models[1], &models).is_ok());
+    }
+
+    #[test]
+    fn test_validate_pivot_table_undefined_model() {
+        let models = create_pivot_models("Member");
+        let result = Validator::validate_pivot_tables(&models[1], &models);
+        assert!(result.unwrap_err().to_string().contains("Pivot table 'role_user' references undefined model 'Member'"));
+    }
+
+    #[test]
+    fn test_validate_pivot_table_invalid_foreign_key() {
+        let mut models = create_pivot_models("User");
+        models[1].pivot_tables[0].foreign_key2 = "user-id".to_string();
+        let result = Validator::validate_pivot_tables(&models[1], &models);
+        assert!(result.unwrap_err().to_string().contains("Pivot table 'role_user' foreign key 'user-id'"));
+    }
+
+
+
+    #[test]
+    fn test_validate_empty_date_format() {
+        let mut model = create_valid_model();
+        model.date_format = Some(String::new());
+        assert!(Validator::validate_model(&model).is_err());
+    }
+
+    #[test]
+    fn test_validate_class_name_overrides() {
+        let mut model = create_valid_model();
+        model.dto_name = Some("UserData".to_string());
+        assert!(Validator::validate_model(&model).is_ok());
+
+        model.resource_name = Some("User-Resource".to_string());
+        assert!(Validator::validate_model(&model).is_err());
+    }
+
+    #[test]
+    fn test_validate_known_keys() {
+        let config = json!({
+            "namespace": "App\\Models",
+            "models": [{ "name": "User", "table": "users", "timestamps": true, "fields": [
+                { "name": "name", "type": "string", "default": "Anonymous" }
+            ] }]
+        });
+        assert!(Validator::validate_known_keys(&config).is_ok());
+
+        let config = json!({
+            "models": [{ "name": "User", "table": "users", "timestamp": true, "fields": [] }]
+        });
+        let result = Validator::validate_known_keys(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unknown key 'timestamp' in models[0]"));
+    }
+
+    #[test]
+    fn test_validate_known_keys_in_nested_objects() {
+        let config = json!({
+            "models": [{ "name": "Post", "table": "posts", "fields": [],
+                "relationships": [
+                    { "type": "belongsTo", "model": "User", "owner_key": "uuid" },
+                    { "type": "morphTo", "morph_name": "commentable", "optional": true }
+                ],
+                "pivot_tables": [{ "name": "post_tag", "model1": "Post", "model2": "Tag",
+                    "foreign_key1": "post_id", "foreign_key2": "tag_id",
+                    "additional_fields": [{ "name": "status", "type": "enum", "enum_values": [{ "value": "draft" }] }]
+                }]
+            }]
+        });
+        assert!(Validator::validate_known_keys(&config).is_ok());
+
+        for (pointer, key, location) in [
+            ("/models/0/relationships/0", "morph_name", "models[0].relationships[0]"),
+            ("/models/0/relationships/1", "model", "models[0].relationships[1]"),
+            ("/models/0/pivot_tables/0", "timestamp", "models[0].pivot_tables[0]"),
+            ("/models/0/pivot_tables/0/additional_fields/0", "nulable", "models[0].pivot_tables[0].additional_fields[0]"),
+            ("/models/0/pivot_tables/0/additional_fields/0/enum_values/0", "title", "additional_fields[0].enum_values[0]"),
+        ] {
+            let mut config = config.clone();
+            config.pointer_mut(pointer).unwrap()[key] = json!("x");
+            let error = Validator::validate_known_keys(&config).unwrap_err().to_string();
+            assert!(error.contains(&format!("Unknown key '{}' in", key)), "{}", error);
+            assert!(error.contains(location), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_json_schema_errors() {
+        let config = json!({
+            "namespace": "App\\Models",
+            "route_case": "snake",
+            "models": [{
+                "name": "Post", "table": "posts", "timestamps": true,
+                "fillable_guarded": { "Fillable": ["title"] },
+                "relationships": [{ "type": "belongsTo", "model": "User", "foreign_key": "user_id" }],
+                "fields": [
+                    { "name": "title", "type": "string", "length": 120 },
+                    { "name": "body", "type": "text", "default": null, "nullable": true }
+                ]
+            }]
+        });
+        assert!(Validator::json_schema_errors(&config).is_empty());
+
+        let mut config = config;
+        config["models"][0]["fields"][1]["type"] = json!("txt");
+        let errors = Validator::json_schema_errors(&config);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("/models/0/fields/1/type: \"txt\" is not one of"));
+    }
+
+    #[test]
+    fn test_known_keys_match_json_schema() {
+        let schema: Value = serde_json::from_str(CONFIG_JSON_SCHEMA).unwrap();
+        let mut relationship_keys = [known_keys::STANDARD_RELATIONSHIP, known_keys::MORPH_TO, known_keys::MORPH_WITH_MODEL].concat();
+        relationship_keys.sort();
+        relationship_keys.dedup();
+
+        for (mut keys, properties) in [
+            (known_keys::CONFIG.to_vec(), &schema["properties"]),
+            (known_keys::MODEL.to_vec(), &schema["$defs"]["model"]["properties"]),
+            (known_keys::FIELD.to_vec(), &schema["$defs"]["field"]["properties"]),
+            (relationship_keys, &schema["$defs"]["relationship"]["properties"]),
+            (known_keys::PIVOT_TABLE.to_vec(), &schema["$defs"]["pivotTable"]["properties"]),
+            (known_keys::VALIDATION_RULE.to_vec(), &schema["$defs"]["validationRule"]["properties"]),
+        ] {
+            let mut documented: Vec<&str> = properties.as_object().unwrap().keys().map(String::as_str).collect();
+            documented.sort();
+            keys.sort();
+            assert_eq!(documented, keys);
+        }
+    }
+
+    #[test]
+    fn test_known_keys_cover_every_serialized_key() {
+        let field = Field { default: Some(ColumnDefault::Value("draft".to_string())), ..Field::new("status", FieldType::String) };
+
+        for (value, known) in [
+            (serde_json::to_value(Config::default()).unwrap(), known_keys::CONFIG),
+            (serde_json::to_value(ModelDefinition::default()).unwrap(), known_keys::MODEL),
+            (serde_json::to_value(field).unwrap(), known_keys::FIELD),
+        ] {
+            for key in value.as_object().unwrap().keys() {
+                assert!(known.contains(&key.as_str()), "'{}' is missing from the known keys", key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_qualified_name() {
+        assert!(Validator::validate_qualified_name("App\\Models\\BaseModel", "Base model").is_ok());
+        assert!(Validator::validate_qualified_name("BaseModel", "Base model").is_ok());
+
+        let result = Validator::validate_qualified_name("App\\\\BaseModel", "Base model");
+        assert!(result.unwrap_err().to_string().contains("Base model 'App\\\\BaseModel' cannot be empty"));
+        assert!(Validator::validate_qualified_name("App\\Base-Model", "Base model").is_err());
+    }
+
     #[test]
     fn test_validate_identifier_valid() {
         assert!(Validator::validate_identifier("valid_name", "Test").is_ok());
@@ -274,8 +1370,37 @@ mod tests {
         assert!(Validator::validate_identifier("class", "Test").is_err()); // PHP reserved word
     }
 
+    #[test]
+    fn test_sanitize_field_name() {
+        assert_eq!(Validator::sanitize_field_name("invalid-name"), "invalid_name");
+        assert_eq!(Validator::sanitize_field_name("123invalid"), "field_123invalid");
+        assert_eq!(Validator::sanitize_field_name("class"), "class_field");
+        assert_eq!(Validator::sanitize_field_name("valid_name"), "valid_name");
+
+        for name in ["invalid-name", "123invalid", "class", "first name", ""] {
+            assert!(Validator::validate_identifier(&Validator::sanitize_field_name(name), "Test").is_ok());
+        }
+    }
+
 
 
+    #[test]
+    fn test_validate_namespace_valid() {
+        assert!(Validator::validate_namespace("App").is_ok());
+        assert!(Validator::validate_namespace("App\\Domain\\Billing").is_ok());
+    }
+
+    #[test]
+    fn test_validate_namespace_invalid() {
+        let result = Validator::validate_namespace("App\\123Bad");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("segment '123Bad' must start with a letter"));
+
+        assert!(Validator::validate_namespace("").is_err());
+        assert!(Validator::validate_namespace("App\\").is_err());
+        assert!(Validator::validate_namespace("App\\My-Domain").is_err());
+    }
+
     #[test]
     fn test_validate_decimal_field() {
         let mut field = create_valid_field();
@@ -290,6 +1415,79 @@ mod tests {
         assert!(Validator::validate_field(&field).is_err());
     }
 
+    #[test]
+    fn test_validate_spatial_field() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Point;
+        assert!(Validator::validate_field(&field).is_ok());
+
+        field.default = Some(ColumnDefault::Value("POINT(0 0)".to_string()));
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("cannot have a default value"));
+    }
+
+    #[test]
+    fn test_validate_hashed_field() {
+        let mut field = create_valid_field();
+        field.hashed = true;
+        assert!(Validator::validate_field(&field).is_ok());
+
+        field.field_type = FieldType::Integer;
+        field.length = None;
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("cannot be hashed"));
+    }
+
+    #[test]
+    fn test_validate_encrypted_field() {
+        let mut field = create_valid_field();
+        field.encrypted = true;
+        assert!(Validator::validate_field(&field).is_ok());
+
+        field.hashed = true;
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("both hashed and encrypted"));
+
+        field.hashed = false;
+        field.field_type = FieldType::Integer;
+        field.length = None;
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("cannot be encrypted"));
+
+        field.field_type = FieldType::Json;
+        assert!(Validator::validate_field(&field).is_ok());
+    }
+
+    #[test]
+    fn test_validate_json_cast_as() {
+        let mut field = create_valid_field();
+        field.length = None;
+        field.json_cast_as = Some(JsonCast::Collection);
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("only json fields"));
+
+        field.field_type = FieldType::Json;
+        assert!(Validator::validate_field(&field).is_ok());
+    }
+
+    #[test]
+    fn test_validate_length_bounds() {
+        let mut field = create_valid_field();
+        field.length = Some(65_535);
+        assert!(Validator::validate_field(&field).is_ok());
+
+        field.length = Some(70_000);
+        let error = Validator::validate_field(&field).unwrap_err().to_string();
+        assert!(error.contains("at most 65535"));
+        assert!(error.contains("use `text`"));
+
+        field.field_type = FieldType::TinyInteger;
+        field.length = Some(3);
+        assert!(Validator::validate_field(&field).is_ok());
+        field.length = Some(5);
+        assert!(Validator::validate_field(&field).is_err());
+    }
+
     #[test]
     fn test_validate_enum_field() {
         let mut field = create_valid_field();
@@ -300,4 +1498,157 @@ mod tests {
         field.enum_values = vec![EnumValue { value: "active".to_string(), label: None }];
         assert!(Validator::validate_field(&field).is_ok());
     }
+
+    #[test]
+    fn test_validate_duplicate_enum_values() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Enum;
+        field.enum_values = vec![
+            EnumValue { value: "active".to_string(), label: None },
+            EnumValue { value: "active".to_string(), label: None },
+        ];
+
+        let result = Validator::validate_field(&field);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("duplicate enum value 'active'"));
+    }
+
+    #[test]
+    fn test_validate_enum_default_matches_value() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Enum;
+        field.enum_values = vec![
+            EnumValue { value: "active".to_string(), label: None },
+            EnumValue { value: "inactive".to_string(), label: None },
+        ];
+        field.default = Some(ColumnDefault::Value("inactive".to_string()));
+        assert!(Validator::validate_field(&field).is_ok());
+    }
+
+    #[test]
+    fn test_validate_null_default_requires_nullable() {
+        let mut field = create_valid_field();
+        field.default = Some(ColumnDefault::Null);
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("defaults to null but is not nullable"));
+
+        field.nullable = true;
+        assert!(Validator::validate_field(&field).is_ok());
+
+        // A string that happens to read "NULL" is an ordinary value
+        field.nullable = false;
+        field.default = Some(ColumnDefault::Value("NULL".to_string()));
+        assert!(Validator::validate_field(&field).is_ok());
+    }
+
+    #[test]
+    fn test_validate_auto_increment_requires_primary() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::BigInteger;
+        field.length = None;
+        field.auto_increment = true;
+        let result = Validator::validate_field(&field);
+        assert!(result.unwrap_err().to_string().contains("uses auto_increment but is not the primary key"));
+
+        field.primary = true;
+        assert!(Validator::validate_field(&field).is_ok());
+    }
+
+    #[test]
+    fn test_validate_single_auto_increment_field() {
+        let key = |name: &str| Field {
+            name: name.to_string(),
+            field_type: FieldType::BigInteger,
+            length: None,
+            primary: true,
+            auto_increment: true,
+            ..create_valid_field()
+        };
+        let mut model = create_valid_model();
+        model.fields = vec![key("order_id"), key("line_id")];
+
+        let result = Validator::validate_model(&model);
+        assert!(result.unwrap_err().to_string().contains("more than one auto_increment field: order_id, line_id"));
+
+        model.fields[1].auto_increment = false;
+        assert!(Validator::validate_model(&model).is_ok());
+    }
+
+    #[test]
+    fn test_validate_enum_default_not_in_values() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Enum;
+        field.enum_values = vec![
+            EnumValue { value: "active".to_string(), label: None },
+            EnumValue { value: "inactive".to_string(), label: None },
+        ];
+        field.default = Some(ColumnDefault::Value("banned".to_string()));
+
+        let result = Validator::validate_field(&field);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be one of: active, inactive"));
+    }
+
+    #[test]
+    fn test_validate_cast_format() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::DateTime;
+        field.length = None;
+        field.cast_format = Some("Y-m-d".to_string());
+        assert!(Validator::validate_field(&field).is_ok());
+
+        field.cast_format = Some(" ".to_string());
+        assert!(Validator::validate_field(&field).is_err());
+
+        field.field_type = FieldType::String;
+        field.cast_format = Some("Y-m-d".to_string());
+        assert!(Validator::validate_field(&field).is_err());
+    }
+
+    #[test]
+    fn test_validate_invalid_integer_default() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Integer;
+        field.length = None;
+        field.default = Some(ColumnDefault::Value("not-a-number".to_string()));
+
+        let result = Validator::validate_field(&field);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be a valid integer"));
+
+        field.default = Some(ColumnDefault::Value("42".to_string()));
+        assert!(Validator::validate_field(&field).is_ok());
+    }
+
+    #[test]
+    fn test_validate_boolean_default() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Boolean;
+        field.length = None;
+
+        for value in ["true", "false", "0", "1"] {
+            field.default = Some(ColumnDefault::Value(value.to_string()));
+            assert!(Validator::validate_field(&field).is_ok());
+        }
+
+        field.default = Some(ColumnDefault::Value("yes".to_string()));
+        assert!(Validator::validate_field(&field).is_err());
+    }
+
+    #[test]
+    fn test_validate_decimal_default_precision() {
+        let mut field = create_valid_field();
+        field.field_type = FieldType::Decimal;
+        field.length = None;
+        field.decimal_precision = Some(DecimalPrecision { precision: 5, scale: 2 });
+
+        field.default = Some(ColumnDefault::Value("999.99".to_string()));
+        assert!(Validator::validate_field(&field).is_ok());
+
+        field.default = Some(ColumnDefault::Value("1000.00".to_string()));
+        assert!(Validator::validate_field(&field).is_err());
+
+        field.default = Some(ColumnDefault::Value("1.234".to_string()));
+        assert!(Validator::validate_field(&field).is_err());
+    }
 }
@@ -1,2 +1,74 @@
-// CLI tests removed - all tests were using obsolete YAML syntax
-// TODO: Add new CLI tests using Prisma-like schema syntax
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+const SCHEMA: &str = r#"
+model User {
+  id        Int      @id @default(autoincrement())
+  name      String   @db.VarChar(255)
+  email     String   @unique @db.VarChar(255)
+  createdAt DateTime @default(now()) @map("created_at")
+  updatedAt DateTime @updatedAt @map("updated_at")
+
+  @@map("users")
+}
+
+model Post {
+  id        Int      @id @default(autoincrement())
+  title     String   @db.VarChar(255)
+  createdAt DateTime @default(now()) @map("created_at")
+  updatedAt DateTime @updatedAt @map("updated_at")
+
+  @@map("posts")
+}
+"#;
+
+fn write_schema(dir: &TempDir) -> String {
+    let schema_path = dir.path().join("schema.schemly");
+    fs::write(&schema_path, SCHEMA).unwrap();
+    schema_path.to_str().unwrap().to_string()
+}
+
+fn schemly() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_schemly"))
+}
+
+#[test]
+fn test_generate_stdout_prints_single_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let schema_path = write_schema(&temp_dir);
+    let output_dir = temp_dir.path().join("out");
+
+    let output = schemly()
+        .args(["--file", &schema_path, "generate", "--output", output_dir.to_str().unwrap()])
+        .args(["--only", "models", "--model", "User", "--stdout"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("<?php"));
+    assert!(stdout.contains("class User extends Model"));
+    assert!(!stdout.contains("class Post extends Model"));
+
+    // Nothing should be written to disk
+    assert!(!output_dir.exists());
+}
+
+#[test]
+fn test_generate_stdout_rejects_multiple_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let schema_path = write_schema(&temp_dir);
+    let output_dir = temp_dir.path().join("out");
+
+    let output = schemly()
+        .args(["--file", &schema_path, "generate", "--output", output_dir.to_str().unwrap()])
+        .args(["--only", "models", "--stdout"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("exactly one file"));
+    assert!(!output_dir.exists());
+}
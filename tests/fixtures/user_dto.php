@@ -0,0 +1,70 @@
+<?php
+
+// @generated by schemly v2.0.0. Do not edit: changes are lost on regeneration (remove this line to keep them).
+
+namespace App\DTOs;
+
+use App\Models\User;
+
+class UserDTO {
+
+    public function __construct
+    (
+        public int $id,
+        public string $name,
+        public string $email,
+        public ?int $age,
+        public bool $is_active,
+        public ?string $created_at,
+        public ?string $updated_at,
+        public ?string $deleted_at
+    )
+    {
+    }
+
+
+     public static function fromArray(array $data): self
+     {
+            return new self(
+                $data['id'],
+                $data['name'],
+                $data['email'],
+                $data['age'],
+                $data['is_active'],
+                $data['created_at'],
+                $data['updated_at'],
+                $data['deleted_at']
+            );
+     }
+
+
+    public static function fromModel(User $model): self
+    {
+        return new self(
+            $model->id,
+            $model->name,
+            $model->email,
+            $model->age,
+            $model->is_active,
+            $model->created_at?->toDateTimeString(),
+            $model->updated_at?->toDateTimeString(),
+            $model->deleted_at?->toDateTimeString()
+        );
+    }
+
+
+    public function toArray(): array
+    {
+        return [
+            'id' => $this->id,
+            'name' => $this->name,
+            'email' => $this->email,
+            'age' => $this->age,
+            'is_active' => $this->is_active,
+            'created_at' => $this->created_at,
+            'updated_at' => $this->updated_at,
+            'deleted_at' => $this->deleted_at
+        ];
+    }
+
+}
\ No newline at end of file
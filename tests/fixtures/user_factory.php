@@ -0,0 +1,28 @@
+<?php
+
+// @generated by schemly v2.0.0. Do not edit: changes are lost on regeneration (remove this line to keep them).
+
+namespace Database\Factories;
+
+use Illuminate\Database\Eloquent\Factories\Factory;
+use App\Models\User;
+
+class UserFactory extends Factory
+{
+    protected $model = User::class;
+
+    public function definition(): array
+    {
+        return [
+            'name' => fake()->name(),
+            'email' => fake()->email(),
+            'age' => fake()->numberBetween(1, 100),
+            'is_active' => fake()->boolean(),
+        ];
+    }
+
+    public function trashed(): static
+    {
+        return $this->state(fn () => ['deleted_at' => now()]);
+    }
+}
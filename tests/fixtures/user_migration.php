@@ -0,0 +1,33 @@
+<?php
+
+// @generated by schemly v2.0.0. Do not edit: changes are lost on regeneration (remove this line to keep them).
+
+use Illuminate\Database\Migrations\Migration;
+use Illuminate\Database\Schema\Blueprint;
+use Illuminate\Support\Facades\Schema;
+
+return new class extends Migration
+{
+    public function up(): void
+    {
+        Schema::create('users', function (Blueprint $table) {
+            $table->id();
+
+                        $table->string('name', 255);
+            $table->string('email', 255)->unique();
+            $table->integer('age')->nullable();
+            $table->boolean('is_active')->default('true');
+
+            $table->timestamps();
+            $table->softDeletes();
+            
+        });
+
+        
+    }
+
+    public function down(): void
+    {
+        Schema::dropIfExists('users');
+    }
+};
@@ -0,0 +1,36 @@
+<?php
+
+// @generated by schemly v2.0.0. Do not edit: changes are lost on regeneration (remove this line to keep them).
+
+namespace App\Models;
+
+use Illuminate\Database\Eloquent\Model;
+use Illuminate\Database\Eloquent\SoftDeletes;
+use Illuminate\Database\Eloquent\Factories\HasFactory;
+
+class User extends Model
+{
+    use HasFactory, SoftDeletes;
+
+    protected $table = 'users';
+
+    protected $fillable = [
+        'name',
+        'email',
+        'age',
+        'is_active'
+    ];
+
+    protected $with = ['posts'];
+
+    protected $casts = [
+        'age' => 'integer',
+        'is_active' => 'boolean',
+    ];
+
+    public function posts()
+    {
+        return $this->hasMany(Post::class, 'user_id');
+    }
+
+}
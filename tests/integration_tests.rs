@@ -1,4 +1,4 @@
-use schemly::types::{Config, ModelDefinition, Field, FieldType, FillableGuarded, DecimalPrecision};
+use schemly::types::{Config, ModelDefinition, Field, FieldType, DecimalPrecision};
 use schemly::generators::{Generator, dto_generator::DtoGenerator};
 use std::fs;
 use tempfile::TempDir;
@@ -8,67 +8,13 @@ fn create_test_model() -> ModelDefinition {
         name: "User".to_string(),
         table: "users".to_string(),
         fields: vec![
-            Field {
-                name: "name".to_string(),
-                field_type: FieldType::String,
-                nullable: false,
-                unique: false,
-                default: None,
-                length: Some(255),
-                index: false,
-                enum_values: vec![],
-                decimal_precision: None,
-                unsigned: false,
-                auto_increment: false,
-                primary: false,
-                comment: None,
-                validation_rules: vec![],
-                cast_type: None,
-            },
-            Field {
-                name: "email".to_string(),
-                field_type: FieldType::String,
-                nullable: false,
-                unique: true,
-                default: None,
-                length: Some(255),
-                index: false,
-                enum_values: vec![],
-                decimal_precision: None,
-                unsigned: false,
-                auto_increment: false,
-                primary: false,
-                comment: None,
-                validation_rules: vec![],
-                cast_type: None,
-            },
-            Field {
-                name: "age".to_string(),
-                field_type: FieldType::Integer,
-                nullable: true,
-                unique: false,
-                default: None,
-                length: None,
-                index: false,
-                enum_values: vec![],
-                decimal_precision: None,
-                unsigned: false,
-                auto_increment: false,
-                primary: false,
-                comment: None,
-                validation_rules: vec![],
-                cast_type: None,
-            },
+            Field { length: Some(255), ..Field::new("name", FieldType::String) },
+            Field { unique: true, length: Some(255), ..Field::new("email", FieldType::String) },
+            Field { nullable: true, ..Field::new("age", FieldType::Integer) },
         ],
-        timestamps: true,
-        soft_deletes: false,
-        relationships: vec![],
-        pivot_tables: vec![],
-        validation_rules: vec![],
-        traits: vec![],
-        fillable_guarded: FillableGuarded::All,
-        compound_indexes: vec![],
-        compound_uniques: vec![],
+        timestamps: Some(true),
+        soft_deletes: Some(false),
+        ..Default::default()
     }
 }
 
@@ -76,19 +22,9 @@ fn create_test_config(output_dir: &str, use_ddd: bool) -> Config {
     Config {
         models: vec![create_test_model()],
         output_dir: output_dir.to_string(),
-        namespace: "App\\Models".to_string(),
-        generate_models: true,
-        generate_controllers: true,
-        generate_resources: true,
-        generate_factories: true,
-        generate_migrations: true,
-        generate_pivot_tables: true,
-        generate_validation_rules: true,
-        generate_requests: false,
         generate_dto: true,
         use_ddd_structure: use_ddd,
-        database_engine: "mysql".to_string(),
-        force_overwrite: false,
+        ..Config::default()
     }
 }
 
@@ -203,7 +139,7 @@ fn test_dto_with_soft_deletes() {
     let output_path = temp_dir.path().to_str().unwrap();
     
     let mut model = create_test_model();
-    model.soft_deletes = true;
+    model.soft_deletes = Some(true);
     let config = create_test_config(output_path, false);
     
     let generator = DtoGenerator;
@@ -221,7 +157,7 @@ fn test_dto_without_timestamps() {
     let output_path = temp_dir.path().to_str().unwrap();
     
     let mut model = create_test_model();
-    model.timestamps = false;
+    model.timestamps = Some(false);
     let config = create_test_config(output_path, false);
     
     let generator = DtoGenerator;
@@ -242,53 +178,20 @@ fn test_different_field_types() {
         Field {
             name: "is_active".to_string(),
             field_type: FieldType::Boolean,
-            nullable: false,
-            unique: false,
-            default: None,
-            length: None,
-            index: false,
-            enum_values: vec![],
-            decimal_precision: None,
-            unsigned: false,
-            auto_increment: false,
-            primary: false,
-            comment: None,
-            validation_rules: vec![],
-            cast_type: None,
+            ..Default::default()
         },
         Field {
             name: "price".to_string(),
             field_type: FieldType::Decimal,
             nullable: true,
-            unique: false,
-            default: None,
-            length: None,
-            index: false,
-            enum_values: vec![],
             decimal_precision: Some(DecimalPrecision { precision: 8, scale: 2 }),
-            unsigned: false,
-            auto_increment: false,
-            primary: false,
-            comment: None,
-            validation_rules: vec![],
-            cast_type: None,
+            ..Default::default()
         },
         Field {
             name: "metadata".to_string(),
             field_type: FieldType::Json,
             nullable: true,
-            unique: false,
-            default: None,
-            length: None,
-            index: false,
-            enum_values: vec![],
-            decimal_precision: None,
-            unsigned: false,
-            auto_increment: false,
-            primary: false,
-            comment: None,
-            validation_rules: vec![],
-            cast_type: None,
+            ..Default::default()
         },
     ];
     
@@ -342,7 +245,7 @@ fn test_validation_error_integration_empty_model() {
 
     let mut model = create_test_model();
     model.fields = vec![]; // No fields
-    model.timestamps = false; // No timestamps
+    model.timestamps = Some(false); // No timestamps
     let config = create_test_config(output_path, false);
 
     let generator = DtoGenerator;
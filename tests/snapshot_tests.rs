@@ -0,0 +1,139 @@
+use schemly::types::{ColumnDefault, Config, ModelDefinition, Field, FieldType, PivotTable, Relationship, StandardRelationship};
+use std::fs;
+use std::path::Path;
+
+// Golden-file tests for every component. Set SCHEMLY_UPDATE_SNAPSHOTS=1 to rewrite
+// the fixtures under tests/fixtures after an intentional output change.
+
+fn field(name: &str, field_type: FieldType) -> Field {
+    Field {
+        name: name.to_string(),
+        field_type,
+        ..Default::default()
+    }
+}
+
+fn create_test_model() -> ModelDefinition {
+    let mut email = field("email", FieldType::String);
+    email.length = Some(255);
+    email.unique = true;
+
+    let mut age = field("age", FieldType::Integer);
+    age.nullable = true;
+
+    let mut is_active = field("is_active", FieldType::Boolean);
+    is_active.default = Some(ColumnDefault::Value("true".to_string()));
+
+    let mut name = field("name", FieldType::String);
+    name.length = Some(255);
+
+    ModelDefinition {
+        name: "User".to_string(),
+        table: "users".to_string(),
+        fields: vec![name, email, age, is_active],
+        timestamps: Some(true),
+        soft_deletes: Some(true),
+        relationships: vec![Relationship::HasMany(StandardRelationship {
+            model: "Post".to_string(),
+            foreign_key: Some("user_id".to_string()),
+            local_key: None,
+            owner_key: None,
+            pivot_table: None,
+            pivot_fields: vec![],
+            on_delete: None,
+            on_update: None,
+            with_timestamps: false,
+            nullable: false,
+            method_name: None,
+        })],
+        with: vec!["posts".to_string()],
+        ..Default::default()
+    }
+}
+
+fn create_test_config() -> Config {
+    Config {
+        models: vec![create_test_model()],
+        output_dir: "/tmp/snapshots".to_string(),
+        generate_requests: true,
+        generate_dto: true,
+        ..Config::default()
+    }
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name);
+
+    if std::env::var("SCHEMLY_UPDATE_SNAPSHOTS").is_ok() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Missing fixture {}; run with SCHEMLY_UPDATE_SNAPSHOTS=1", path.display()));
+    assert_eq!(actual, expected, "Generated output differs from fixture {}", name);
+}
+
+#[test]
+fn test_model_snapshot() {
+    let content = schemly::generate_model_string(&create_test_model(), &create_test_config()).unwrap();
+    assert_snapshot("user_model.php", &content);
+}
+
+#[test]
+fn test_migration_snapshot() {
+    let content = schemly::generate_migration_string(&create_test_model(), &create_test_config()).unwrap();
+    assert_snapshot("user_migration.php", &content);
+}
+
+#[test]
+fn test_controller_snapshot() {
+    let content = schemly::generate_controller_string(&create_test_model(), &create_test_config()).unwrap();
+    assert_snapshot("user_controller.php", &content);
+}
+
+#[test]
+fn test_resource_snapshot() {
+    let content = schemly::generate_resource_string(&create_test_model(), &create_test_config()).unwrap();
+    assert_snapshot("user_resource.php", &content);
+}
+
+#[test]
+fn test_factory_snapshot() {
+    let content = schemly::generate_factory_string(&create_test_model(), &create_test_config()).unwrap();
+    assert_snapshot("user_factory.php", &content);
+}
+
+#[test]
+fn test_dto_snapshot() {
+    let content = schemly::generate_dto_string(&create_test_model(), &create_test_config()).unwrap();
+    assert_snapshot("user_dto.php", &content);
+}
+
+#[test]
+fn test_request_snapshots() {
+    let config = create_test_config();
+    let model = create_test_model();
+
+    let store = schemly::generate_request_string(&model, &config, "store").unwrap();
+    assert_snapshot("user_store_request.php", &store);
+
+    let update = schemly::generate_request_string(&model, &config, "update").unwrap();
+    assert_snapshot("user_update_request.php", &update);
+}
+
+#[test]
+fn test_pivot_table_snapshot() {
+    let pivot_table = PivotTable {
+        name: "role_user".to_string(),
+        model1: "Role".to_string(),
+        model2: "User".to_string(),
+        foreign_key1: "role_id".to_string(),
+        foreign_key2: "user_id".to_string(),
+        additional_fields: vec![],
+        timestamps: true,
+    };
+
+    let content = schemly::generate_pivot_table_string(&pivot_table, &create_test_config()).unwrap();
+    assert_snapshot("role_user_pivot.php", &content);
+}